@@ -0,0 +1,29 @@
+-- Add parent/child hierarchy for subtasks
+ALTER TABLE tasks ADD COLUMN IF NOT EXISTS parent_task_id UUID REFERENCES tasks(id) ON DELETE CASCADE;
+ALTER TABLE tasks ADD COLUMN IF NOT EXISTS position INTEGER NOT NULL DEFAULT 0;
+
+-- A task can never be its own parent
+ALTER TABLE tasks ADD CONSTRAINT check_task_not_own_parent
+    CHECK (parent_task_id IS NULL OR parent_task_id <> id);
+
+-- Create index for listing children in manual order
+CREATE INDEX IF NOT EXISTS idx_tasks_parent_task_id ON tasks(parent_task_id, position);
+
+-- Access check shared by every task query: a user can access a task when they
+-- own it or are a member of it, or of any of its ancestors (subtasks inherit
+-- access from their parent's task_members).
+CREATE OR REPLACE FUNCTION user_has_task_access(p_task_id UUID, p_user_id UUID)
+RETURNS BOOLEAN AS $$
+    WITH RECURSIVE lineage AS (
+        SELECT id, parent_task_id, user_id FROM tasks WHERE id = p_task_id
+        UNION ALL
+        SELECT t.id, t.parent_task_id, t.user_id
+        FROM tasks t
+        JOIN lineage l ON t.id = l.parent_task_id
+    )
+    SELECT EXISTS (
+        SELECT 1 FROM lineage l
+        LEFT JOIN task_members tm ON tm.task_id = l.id AND tm.user_id = p_user_id
+        WHERE l.user_id = p_user_id OR tm.user_id IS NOT NULL
+    );
+$$ LANGUAGE sql STABLE;
@@ -0,0 +1,12 @@
+-- Create task_dependencies table: task_id is blocked by blocked_by_task_id
+CREATE TABLE IF NOT EXISTS task_dependencies (
+    task_id UUID NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    blocked_by_task_id UUID NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (task_id, blocked_by_task_id),
+    CONSTRAINT check_task_not_blocking_itself CHECK (task_id <> blocked_by_task_id)
+);
+
+-- Create index for looking up the tasks a blocker is holding up
+CREATE INDEX IF NOT EXISTS idx_task_dependencies_blocked_by ON task_dependencies(blocked_by_task_id);
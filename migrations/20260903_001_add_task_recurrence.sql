@@ -0,0 +1,9 @@
+-- Add recurrence columns to tasks. Every occurrence of a recurring task is its
+-- own row; rows of the same series share recurrence_series_id and are numbered
+-- by recurrence_index (starting at 1).
+ALTER TABLE tasks ADD COLUMN IF NOT EXISTS recurrence_rule JSONB;
+ALTER TABLE tasks ADD COLUMN IF NOT EXISTS recurrence_series_id UUID;
+ALTER TABLE tasks ADD COLUMN IF NOT EXISTS recurrence_index INTEGER;
+
+-- Each occurrence is generated at most once, whether by completion or by the scheduler
+CREATE UNIQUE INDEX IF NOT EXISTS idx_tasks_recurrence_occurrence ON tasks(recurrence_series_id, recurrence_index);
@@ -0,0 +1,60 @@
+-- Create projects table
+CREATE TABLE IF NOT EXISTS projects (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    name VARCHAR(255) NOT NULL,
+    description TEXT,
+    owner_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+-- Create project_members table (same owner/collaborator roles as task_members)
+CREATE TABLE IF NOT EXISTS project_members (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    role VARCHAR(50) NOT NULL DEFAULT 'collaborator',
+    added_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    added_by UUID REFERENCES users(id),
+    CONSTRAINT unique_project_member UNIQUE (project_id, user_id)
+);
+
+-- Create project_columns table: Kanban columns, each mapped onto a task status
+CREATE TABLE IF NOT EXISTS project_columns (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    status VARCHAR(50) NOT NULL,
+    position INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    CONSTRAINT check_project_column_status CHECK (status IN ('Pending', 'InProgress', 'Completed', 'Archived'))
+);
+
+-- Tasks belong to a project, sit in one of its columns and keep a manual rank within it
+ALTER TABLE tasks ADD COLUMN IF NOT EXISTS project_id UUID REFERENCES projects(id) ON DELETE CASCADE;
+ALTER TABLE tasks ADD COLUMN IF NOT EXISTS column_id UUID REFERENCES project_columns(id) ON DELETE SET NULL;
+ALTER TABLE tasks ADD COLUMN IF NOT EXISTS board_rank DOUBLE PRECISION NOT NULL DEFAULT 0;
+
+-- Create indexes
+CREATE INDEX IF NOT EXISTS idx_project_members_user_id ON project_members(user_id);
+CREATE INDEX IF NOT EXISTS idx_project_columns_project_id ON project_columns(project_id, position);
+CREATE INDEX IF NOT EXISTS idx_tasks_project_id ON tasks(project_id);
+CREATE INDEX IF NOT EXISTS idx_tasks_column_rank ON tasks(column_id, board_rank);
+
+-- Project members can access every task in the project (and its subtasks)
+CREATE OR REPLACE FUNCTION user_has_task_access(p_task_id UUID, p_user_id UUID)
+RETURNS BOOLEAN AS $$
+    WITH RECURSIVE lineage AS (
+        SELECT id, parent_task_id, user_id, project_id FROM tasks WHERE id = p_task_id
+        UNION ALL
+        SELECT t.id, t.parent_task_id, t.user_id, t.project_id
+        FROM tasks t
+        JOIN lineage l ON t.id = l.parent_task_id
+    )
+    SELECT EXISTS (
+        SELECT 1 FROM lineage l
+        LEFT JOIN task_members tm ON tm.task_id = l.id AND tm.user_id = p_user_id
+        LEFT JOIN project_members pm ON pm.project_id = l.project_id AND pm.user_id = p_user_id
+        WHERE l.user_id = p_user_id OR tm.user_id IS NOT NULL OR pm.user_id IS NOT NULL
+    );
+$$ LANGUAGE sql STABLE;
@@ -0,0 +1,18 @@
+-- Create task_comments table: threaded discussion on a task
+CREATE TABLE IF NOT EXISTS task_comments (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    task_id UUID NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    user_id UUID REFERENCES users(id) ON DELETE SET NULL,
+    parent_comment_id UUID REFERENCES task_comments(id) ON DELETE CASCADE,
+    content TEXT NOT NULL,
+    is_edited BOOLEAN NOT NULL DEFAULT FALSE,
+    -- Deleted comments keep their row so replies stay attached to the thread
+    deleted_at TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+-- Create indexes for listing top-level comments and replies in order
+CREATE INDEX IF NOT EXISTS idx_task_comments_task_id ON task_comments(task_id, created_at)
+    WHERE parent_comment_id IS NULL;
+CREATE INDEX IF NOT EXISTS idx_task_comments_parent_id ON task_comments(parent_comment_id, created_at);
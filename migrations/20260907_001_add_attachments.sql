@@ -0,0 +1,20 @@
+-- Create attachments table: uploaded files linked to a task or a message
+CREATE TABLE IF NOT EXISTS attachments (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    -- Uploader; their total size_bytes counts towards their storage quota
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    task_id UUID REFERENCES tasks(id) ON DELETE CASCADE,
+    message_id UUID REFERENCES messages(id) ON DELETE CASCADE,
+    file_name VARCHAR(255) NOT NULL,
+    content_type VARCHAR(255) NOT NULL,
+    size_bytes BIGINT NOT NULL CHECK (size_bytes >= 0),
+    -- Object key in the configured storage backend
+    storage_key TEXT NOT NULL UNIQUE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    CONSTRAINT attachment_single_parent CHECK (num_nonnulls(task_id, message_id) = 1)
+);
+
+-- Create indexes for listing attachments and computing quota usage
+CREATE INDEX IF NOT EXISTS idx_attachments_task_id ON attachments(task_id) WHERE task_id IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_attachments_message_id ON attachments(message_id) WHERE message_id IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_attachments_user_id ON attachments(user_id);
@@ -0,0 +1,26 @@
+-- Add effort estimate to tasks
+ALTER TABLE tasks ADD COLUMN IF NOT EXISTS estimate_minutes INTEGER
+    CHECK (estimate_minutes IS NULL OR estimate_minutes > 0);
+
+-- Create time_entries table: running timers (ended_at IS NULL) and logged work
+CREATE TABLE IF NOT EXISTS time_entries (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    task_id UUID NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    started_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    ended_at TIMESTAMP WITH TIME ZONE,
+    note TEXT,
+    -- FALSE for timer entries, TRUE for worklogs entered by hand
+    is_manual BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    CONSTRAINT time_entry_valid_range CHECK (ended_at IS NULL OR ended_at >= started_at)
+);
+
+-- At most one running timer per user
+CREATE UNIQUE INDEX IF NOT EXISTS idx_time_entries_one_running_timer
+    ON time_entries(user_id) WHERE ended_at IS NULL;
+
+-- Create indexes for per-task and per-user totals
+CREATE INDEX IF NOT EXISTS idx_time_entries_task_id ON time_entries(task_id, started_at);
+CREATE INDEX IF NOT EXISTS idx_time_entries_user_id ON time_entries(user_id, started_at);
@@ -0,0 +1,24 @@
+-- Deleted tasks go to the trash first; a scheduled job purges them after the retention period.
+-- Deleting a task trashes its subtasks with the same deleted_at, so they are restored together.
+ALTER TABLE tasks ADD COLUMN IF NOT EXISTS deleted_at TIMESTAMP WITH TIME ZONE;
+ALTER TABLE tasks ADD COLUMN IF NOT EXISTS deleted_by UUID REFERENCES users(id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS idx_tasks_deleted_at ON tasks(deleted_at) WHERE deleted_at IS NOT NULL;
+
+-- Nobody has access to a task in the trash
+CREATE OR REPLACE FUNCTION user_has_task_access(p_task_id UUID, p_user_id UUID)
+RETURNS BOOLEAN AS $$
+    WITH RECURSIVE lineage AS (
+        SELECT id, parent_task_id, user_id, project_id FROM tasks WHERE id = p_task_id AND deleted_at IS NULL
+        UNION ALL
+        SELECT t.id, t.parent_task_id, t.user_id, t.project_id
+        FROM tasks t
+        JOIN lineage l ON t.id = l.parent_task_id
+    )
+    SELECT EXISTS (
+        SELECT 1 FROM lineage l
+        LEFT JOIN task_members tm ON tm.task_id = l.id AND tm.user_id = p_user_id
+        LEFT JOIN project_members pm ON pm.project_id = l.project_id AND pm.user_id = p_user_id
+        WHERE l.user_id = p_user_id OR tm.user_id IS NOT NULL OR pm.user_id IS NOT NULL
+    );
+$$ LANGUAGE sql STABLE;
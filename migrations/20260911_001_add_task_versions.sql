@@ -0,0 +1,30 @@
+-- Create task_versions table: a snapshot of a task's editable fields after every change
+CREATE TABLE IF NOT EXISTS task_versions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    task_id UUID NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    version INTEGER NOT NULL,
+    snapshot JSONB NOT NULL,
+    -- What produced the version: created, updated, status_updated, reverted, ...
+    action VARCHAR(100) NOT NULL,
+    -- NULL for changes made by the system, e.g. recurring occurrences
+    changed_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    CONSTRAINT unique_task_version UNIQUE (task_id, version)
+);
+
+-- Existing tasks start their history from their current state
+INSERT INTO task_versions (task_id, version, snapshot, action, changed_by)
+SELECT id, 1,
+       jsonb_build_object(
+           'title', title,
+           'description', description,
+           'status', status,
+           'priority', priority,
+           'due_date', due_date,
+           'reminder_time', reminder_time,
+           'estimate_minutes', estimate_minutes,
+           'recurrence_rule', recurrence_rule
+       ),
+       'baseline', NULL
+FROM tasks
+ON CONFLICT (task_id, version) DO NOTHING;
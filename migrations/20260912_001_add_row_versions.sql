@@ -0,0 +1,34 @@
+-- Version counters for optimistic concurrency control. Clients read them as
+-- ETags and send them back in If-Match; a write against an older value is rejected.
+ALTER TABLE tasks ADD COLUMN IF NOT EXISTS row_version INTEGER NOT NULL DEFAULT 1;
+ALTER TABLE groups ADD COLUMN IF NOT EXISTS row_version INTEGER NOT NULL DEFAULT 1;
+ALTER TABLE messages ADD COLUMN IF NOT EXISTS row_version INTEGER NOT NULL DEFAULT 1;
+
+CREATE OR REPLACE FUNCTION bump_row_version()
+RETURNS TRIGGER AS $$
+BEGIN
+    NEW.row_version = OLD.row_version + 1;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS bump_tasks_row_version ON tasks;
+CREATE TRIGGER bump_tasks_row_version
+    BEFORE UPDATE ON tasks
+    FOR EACH ROW
+    EXECUTE FUNCTION bump_row_version();
+
+DROP TRIGGER IF EXISTS bump_groups_row_version ON groups;
+CREATE TRIGGER bump_groups_row_version
+    BEFORE UPDATE ON groups
+    FOR EACH ROW
+    EXECUTE FUNCTION bump_row_version();
+
+-- Only edits count for messages, so the recipient reading a message
+-- doesn't invalidate the sender's copy
+DROP TRIGGER IF EXISTS bump_messages_row_version ON messages;
+CREATE TRIGGER bump_messages_row_version
+    BEFORE UPDATE ON messages
+    FOR EACH ROW
+    WHEN (OLD.content IS DISTINCT FROM NEW.content OR OLD.image_url IS DISTINCT FROM NEW.image_url)
+    EXECUTE FUNCTION bump_row_version();
@@ -0,0 +1,42 @@
+-- Task members get one of four roles: owner, admin (manages members), editor, viewer (read-only).
+-- Existing collaborators could edit everything but not share, which is what editors do.
+ALTER TABLE task_members DROP CONSTRAINT IF EXISTS check_task_member_role;
+UPDATE task_members SET role = 'editor' WHERE role = 'collaborator';
+ALTER TABLE task_members ADD CONSTRAINT check_task_member_role
+    CHECK (role IN ('owner', 'admin', 'editor', 'viewer'));
+
+-- A user's effective role on a task: the highest role they hold on the task or any ancestor.
+-- Owning an ancestor, or owning the task's project, makes you an admin; other project
+-- members are editors. Returns NULL when the user has no access.
+CREATE OR REPLACE FUNCTION user_task_role(p_task_id UUID, p_user_id UUID)
+RETURNS TEXT AS $$
+    WITH RECURSIVE lineage AS (
+        SELECT id, parent_task_id, user_id, project_id, 0 AS depth FROM tasks WHERE id = p_task_id AND deleted_at IS NULL
+        UNION ALL
+        SELECT t.id, t.parent_task_id, t.user_id, t.project_id, l.depth + 1
+        FROM tasks t
+        JOIN lineage l ON t.id = l.parent_task_id
+    ),
+    grants AS (
+        SELECT CASE WHEN l.depth = 0 THEN 'owner' ELSE 'admin' END AS role
+        FROM lineage l
+        WHERE l.user_id = p_user_id
+        UNION ALL
+        SELECT CASE WHEN tm.role = 'owner' AND l.depth > 0 THEN 'admin' ELSE tm.role END
+        FROM lineage l
+        JOIN task_members tm ON tm.task_id = l.id AND tm.user_id = p_user_id
+        UNION ALL
+        SELECT CASE WHEN pm.role = 'owner' THEN 'admin' ELSE 'editor' END
+        FROM lineage l
+        JOIN project_members pm ON pm.project_id = l.project_id AND pm.user_id = p_user_id
+    )
+    SELECT role FROM grants
+    ORDER BY array_position(ARRAY['owner', 'admin', 'editor', 'viewer'], role)
+    LIMIT 1;
+$$ LANGUAGE sql STABLE;
+
+-- Any role gives read access
+CREATE OR REPLACE FUNCTION user_has_task_access(p_task_id UUID, p_user_id UUID)
+RETURNS BOOLEAN AS $$
+    SELECT user_task_role(p_task_id, p_user_id) IS NOT NULL;
+$$ LANGUAGE sql STABLE;
@@ -0,0 +1,21 @@
+-- Create task_share_links table: signed public links to a task for people without an account.
+-- The token itself is not stored; it is a JWT naming the link, so revoking the row kills it.
+CREATE TABLE IF NOT EXISTS task_share_links (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    task_id UUID NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    access TEXT NOT NULL DEFAULT 'read',
+    password_hash VARCHAR(255),
+    expires_at TIMESTAMP WITH TIME ZONE,
+    revoked_at TIMESTAMP WITH TIME ZONE,
+    last_accessed_at TIMESTAMP WITH TIME ZONE,
+    access_count INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    CONSTRAINT check_share_link_access CHECK (access IN ('read', 'comment'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_task_share_links_task_id ON task_share_links(task_id);
+
+-- Comments left through a commentable link have no user, only the name the guest gave
+ALTER TABLE task_comments ADD COLUMN IF NOT EXISTS guest_name VARCHAR(100);
+ALTER TABLE task_comments ADD COLUMN IF NOT EXISTS share_link_id UUID REFERENCES task_share_links(id) ON DELETE SET NULL;
@@ -0,0 +1,26 @@
+-- Create task_templates table: reusable task blueprints with due/reminder times relative to a base date
+CREATE TABLE IF NOT EXISTS task_templates (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    owner_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    name VARCHAR(255) NOT NULL,
+    title VARCHAR(500) NOT NULL,
+    description TEXT,
+    priority VARCHAR(50),
+    due_offset_minutes INTEGER,
+    reminder_offset_minutes INTEGER,
+    estimate_minutes INTEGER,
+    checklist TEXT[] NOT NULL DEFAULT '{}',
+    default_members JSONB NOT NULL DEFAULT '[]',
+    visibility TEXT NOT NULL DEFAULT 'private',
+    group_id UUID REFERENCES groups(id) ON DELETE CASCADE,
+    shared_with UUID[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    CONSTRAINT check_task_template_visibility CHECK (visibility IN ('private', 'shared', 'group')),
+    CONSTRAINT check_task_template_group CHECK ((visibility = 'group') = (group_id IS NOT NULL))
+);
+
+-- Create indexes for the three ways a user can reach a template
+CREATE INDEX IF NOT EXISTS idx_task_templates_owner_id ON task_templates(owner_id);
+CREATE INDEX IF NOT EXISTS idx_task_templates_group_id ON task_templates(group_id);
+CREATE INDEX IF NOT EXISTS idx_task_templates_shared_with ON task_templates USING GIN (shared_with);
@@ -0,0 +1,5 @@
+-- Indexes matching the (created_at, id) keyset order used by cursor pagination
+CREATE INDEX IF NOT EXISTS idx_tasks_created_at_id ON tasks(created_at DESC, id DESC);
+CREATE INDEX IF NOT EXISTS idx_messages_conversation_keyset ON messages(sender_id, receiver_id, created_at DESC, id DESC);
+CREATE INDEX IF NOT EXISTS idx_messages_group_keyset ON messages(group_id, created_at DESC, id DESC);
+CREATE INDEX IF NOT EXISTS idx_notifications_user_keyset ON notifications(user_id, created_at DESC, id DESC);
@@ -0,0 +1,9 @@
+-- Create calendar_feeds table: one iCalendar subscription per user.
+-- The token is not stored; it is a JWT naming the feed, so replacing or deleting the row kills it.
+CREATE TABLE IF NOT EXISTS calendar_feeds (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    last_accessed_at TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    CONSTRAINT unique_calendar_feed_per_user UNIQUE (user_id)
+);
@@ -0,0 +1,102 @@
+-- Task status and priority used to accept any text, so some rows hold values
+-- like 'done' or 'high' that status counts and board columns never match.
+-- Map the common spellings onto the real values, fall back to the defaults
+-- for anything else, and only accept the real values from now on.
+-- The aliases are the ones the CSV/JSON importer understands.
+CREATE OR REPLACE FUNCTION repair_task_status(value TEXT) RETURNS TEXT AS $$
+    SELECT CASE lower(regexp_replace(value, '[\s_-]', '', 'g'))
+        WHEN 'pending' THEN 'Pending'
+        WHEN 'todo' THEN 'Pending'
+        WHEN 'open' THEN 'Pending'
+        WHEN 'new' THEN 'Pending'
+        WHEN 'notstarted' THEN 'Pending'
+        WHEN 'backlog' THEN 'Pending'
+        WHEN 'inprogress' THEN 'InProgress'
+        WHEN 'doing' THEN 'InProgress'
+        WHEN 'started' THEN 'InProgress'
+        WHEN 'active' THEN 'InProgress'
+        WHEN 'inreview' THEN 'InProgress'
+        WHEN 'completed' THEN 'Completed'
+        WHEN 'complete' THEN 'Completed'
+        WHEN 'done' THEN 'Completed'
+        WHEN 'closed' THEN 'Completed'
+        WHEN 'finished' THEN 'Completed'
+        WHEN 'resolved' THEN 'Completed'
+        WHEN 'archived' THEN 'Archived'
+        ELSE 'Pending'
+    END;
+$$ LANGUAGE sql IMMUTABLE;
+
+CREATE OR REPLACE FUNCTION repair_task_priority(value TEXT) RETURNS TEXT AS $$
+    SELECT CASE lower(regexp_replace(value, '[\s_-]', '', 'g'))
+        WHEN 'low' THEN 'Low'
+        WHEN 'lowest' THEN 'Low'
+        WHEN 'minor' THEN 'Low'
+        WHEN 'medium' THEN 'Medium'
+        WHEN 'normal' THEN 'Medium'
+        WHEN 'default' THEN 'Medium'
+        WHEN 'high' THEN 'High'
+        WHEN 'major' THEN 'High'
+        WHEN 'important' THEN 'High'
+        WHEN 'urgent' THEN 'Urgent'
+        WHEN 'highest' THEN 'Urgent'
+        WHEN 'critical' THEN 'Urgent'
+        WHEN 'blocker' THEN 'Urgent'
+        ELSE 'Medium'
+    END;
+$$ LANGUAGE sql IMMUTABLE;
+
+UPDATE tasks SET status = repair_task_status(status)
+WHERE status NOT IN ('Pending', 'InProgress', 'Completed', 'Archived');
+
+UPDATE tasks SET priority = repair_task_priority(priority)
+WHERE priority NOT IN ('Low', 'Medium', 'High', 'Urgent');
+
+UPDATE task_templates SET priority = repair_task_priority(priority)
+WHERE priority NOT IN ('Low', 'Medium', 'High', 'Urgent');
+
+-- Old versions are restored field by field, so their snapshots need the same repair
+UPDATE task_versions SET snapshot = snapshot || jsonb_build_object('status', repair_task_status(snapshot->>'status'))
+WHERE snapshot->>'status' NOT IN ('Pending', 'InProgress', 'Completed', 'Archived');
+
+UPDATE task_versions SET snapshot = snapshot || jsonb_build_object('priority', repair_task_priority(snapshot->>'priority'))
+WHERE snapshot->>'priority' NOT IN ('Low', 'Medium', 'High', 'Urgent');
+
+-- Saved view filters are parsed into the same enums when a view is applied
+UPDATE saved_views SET filters = filters || jsonb_build_object('status', repair_task_status(filters->>'status'))
+WHERE jsonb_typeof(filters->'status') = 'string'
+    AND filters->>'status' NOT IN ('Pending', 'InProgress', 'Completed', 'Archived');
+
+UPDATE saved_views SET filters = filters || jsonb_build_object('priority', repair_task_priority(filters->>'priority'))
+WHERE jsonb_typeof(filters->'priority') = 'string'
+    AND filters->>'priority' NOT IN ('Low', 'Medium', 'High', 'Urgent');
+
+UPDATE saved_views SET filters = jsonb_set(filters, '{statuses}', (
+    SELECT COALESCE(jsonb_agg(DISTINCT repair_task_status(value)), '[]'::jsonb)
+    FROM jsonb_array_elements_text(filters->'statuses')
+))
+WHERE jsonb_typeof(filters->'statuses') = 'array';
+
+UPDATE saved_views SET filters = jsonb_set(filters, '{priorities}', (
+    SELECT COALESCE(jsonb_agg(DISTINCT repair_task_priority(value)), '[]'::jsonb)
+    FROM jsonb_array_elements_text(filters->'priorities')
+))
+WHERE jsonb_typeof(filters->'priorities') = 'array';
+
+DROP FUNCTION repair_task_status(TEXT);
+DROP FUNCTION repair_task_priority(TEXT);
+
+-- TEXT, like other enum columns, so the values decode straight into the Rust enums
+ALTER TABLE tasks ALTER COLUMN status TYPE TEXT, ALTER COLUMN priority TYPE TEXT;
+ALTER TABLE task_templates ALTER COLUMN priority TYPE TEXT;
+ALTER TABLE project_columns ALTER COLUMN status TYPE TEXT;
+
+ALTER TABLE tasks DROP CONSTRAINT IF EXISTS check_task_status;
+ALTER TABLE tasks ADD CONSTRAINT check_task_status
+    CHECK (status IN ('Pending', 'InProgress', 'Completed', 'Archived'));
+ALTER TABLE tasks DROP CONSTRAINT IF EXISTS check_task_priority;
+ALTER TABLE tasks ADD CONSTRAINT check_task_priority
+    CHECK (priority IN ('Low', 'Medium', 'High', 'Urgent'));
+ALTER TABLE task_templates DROP CONSTRAINT IF EXISTS check_task_template_priority;
+ALTER TABLE task_templates ADD CONSTRAINT check_task_template_priority
+    CHECK (priority IN ('Low', 'Medium', 'High', 'Urgent'));
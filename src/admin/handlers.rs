@@ -8,10 +8,11 @@ use uuid::Uuid;
 use validator::Validate;
 use crate::{
     error::{Result, AppError},
+    middleware::{AppJson, AppQuery, AuthUser},
     state::AppState,
     task::{
         task_dto::PaginatedResponse,
-        task_models::Task,
+        task_models::{Task, TaskEventKind, TaskPriority, TaskStatus},
         task_repository::TaskFilters,
     },
     user::{
@@ -23,10 +24,10 @@ use crate::{
 
 #[derive(serde::Deserialize)]
 pub struct AdminTaskFilters {
-    pub status: Option<String>,
-    pub statuses: Option<Vec<String>>,
-    pub priority: Option<String>,
-    pub priorities: Option<Vec<String>>,
+    pub status: Option<TaskStatus>,
+    pub statuses: Option<Vec<TaskStatus>>,
+    pub priority: Option<TaskPriority>,
+    pub priorities: Option<Vec<TaskPriority>>,
     pub search: Option<String>,
     pub created_from: Option<chrono::DateTime<chrono::Utc>>,
     pub created_to: Option<chrono::DateTime<chrono::Utc>>,
@@ -45,11 +46,11 @@ pub struct AdminTaskFilters {
     path = "/api/admin/tasks",
     tag = "admin",
     params(
-        ("status" = Option<String>, Query, description = "Filter by status"),
-        ("statuses" = Option<[String]>, Query, description = "Multiple statuses"),
-        ("priority" = Option<String>, Query, description = "Filter by priority"),
-        ("priorities" = Option<[String]>, Query, description = "Multiple priorities"),
-        ("search" = Option<String>, Query, description = "Search by title"),
+        ("status" = Option<TaskStatus>, Query, description = "Filter by status"),
+        ("statuses" = Option<[TaskStatus]>, Query, description = "Multiple statuses"),
+        ("priority" = Option<TaskPriority>, Query, description = "Filter by priority"),
+        ("priorities" = Option<[TaskPriority]>, Query, description = "Multiple priorities"),
+        ("search" = Option<String>, Query, description = "Full-text search over title and description"),
         ("created_from" = Option<DateTime<Utc>>, Query, description = "Created from"),
         ("created_to" = Option<DateTime<Utc>>, Query, description = "Created to"),
         ("due_from" = Option<DateTime<Utc>>, Query, description = "Due from"),
@@ -61,7 +62,8 @@ pub struct AdminTaskFilters {
     responses(
         (status = 200, description = "Tasks retrieved successfully", body = PaginatedResponse<Task>),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required")
+        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 422, description = "Unknown status or priority; the error lists the allowed values")
     ),
     security(
         ("bearer_auth" = [])
@@ -69,7 +71,7 @@ pub struct AdminTaskFilters {
 )]
 pub async fn get_all_tasks(
     State(state): State<AppState>,
-    Query(filters): Query<AdminTaskFilters>,
+    AppQuery(filters): AppQuery<AdminTaskFilters>,
 ) -> Result<Json<PaginatedResponse<Task>>> {
     let page = filters.page.unwrap_or(1);
     let limit = filters.limit.unwrap_or(10);
@@ -89,6 +91,12 @@ pub async fn get_all_tasks(
         page: Some(page),
         limit: Some(limit),
         user_id: filters.user_id,
+        project_id: None,
+        include_subtasks: true,
+        labels: None,
+        labels_match_all: false,
+        assignee_id: None,
+        cursor: None,
     };
 
     let (tasks, total) = state.admin_service.list_tasks(repo_filters).await?;
@@ -100,6 +108,7 @@ pub async fn get_all_tasks(
         page,
         limit,
         total_pages,
+        next_cursor: None,
     }))
 }
 
@@ -110,11 +119,11 @@ pub async fn get_all_tasks(
     tag = "admin",
     params(
         ("user_id" = Uuid, Path, description = "User ID"),
-        ("status" = Option<String>, Query, description = "Filter by status"),
-        ("statuses" = Option<[String]>, Query, description = "Multiple statuses"),
-        ("priority" = Option<String>, Query, description = "Filter by priority"),
-        ("priorities" = Option<[String]>, Query, description = "Multiple priorities"),
-        ("search" = Option<String>, Query, description = "Search by title"),
+        ("status" = Option<TaskStatus>, Query, description = "Filter by status"),
+        ("statuses" = Option<[TaskStatus]>, Query, description = "Multiple statuses"),
+        ("priority" = Option<TaskPriority>, Query, description = "Filter by priority"),
+        ("priorities" = Option<[TaskPriority]>, Query, description = "Multiple priorities"),
+        ("search" = Option<String>, Query, description = "Full-text search over title and description"),
         ("created_from" = Option<DateTime<Utc>>, Query, description = "Created from"),
         ("created_to" = Option<DateTime<Utc>>, Query, description = "Created to"),
         ("due_from" = Option<DateTime<Utc>>, Query, description = "Due from"),
@@ -126,7 +135,8 @@ pub async fn get_all_tasks(
         (status = 200, description = "User tasks retrieved successfully", body = PaginatedResponse<Task>),
         (status = 401, description = "Unauthorized"),
         (status = 403, description = "Forbidden - Admin access required"),
-        (status = 404, description = "User not found")
+        (status = 404, description = "User not found"),
+        (status = 422, description = "Unknown status or priority; the error lists the allowed values")
     ),
     security(
         ("bearer_auth" = [])
@@ -135,7 +145,7 @@ pub async fn get_all_tasks(
 pub async fn get_user_tasks(
     State(state): State<AppState>,
     Path(user_id): Path<Uuid>,
-    Query(filters): Query<AdminTaskFilters>,
+    AppQuery(filters): AppQuery<AdminTaskFilters>,
 ) -> Result<Json<PaginatedResponse<Task>>> {
     // Verify user exists first
     if state.user_repository.find_by_id(user_id).await?.is_none() {
@@ -160,6 +170,12 @@ pub async fn get_user_tasks(
         page: Some(page),
         limit: Some(limit),
         user_id: Some(user_id),
+        project_id: None,
+        include_subtasks: true,
+        labels: None,
+        labels_match_all: false,
+        assignee_id: None,
+        cursor: None,
     };
 
     let (tasks, total) = state.admin_service.list_tasks(repo_filters).await?;
@@ -171,16 +187,23 @@ pub async fn get_user_tasks(
         page,
         limit,
         total_pages,
+        next_cursor: None,
     }))
 }
 
+#[derive(serde::Deserialize)]
+pub struct AdminDeleteTaskParams {
+    pub force: Option<bool>,
+}
+
 /// Delete task (admin only)
 #[utoipa::path(
     delete,
     path = "/api/admin/tasks/{task_id}",
     tag = "admin",
     params(
-        ("task_id" = Uuid, Path, description = "Task ID")
+        ("task_id" = Uuid, Path, description = "Task ID"),
+        ("force" = Option<bool>, Query, description = "Purge immediately instead of moving to the trash")
     ),
     responses(
         (status = 204, description = "Task deleted successfully"),
@@ -194,9 +217,24 @@ pub async fn get_user_tasks(
 )]
 pub async fn delete_task(
     State(state): State<AppState>,
+    AuthUser(admin_id): AuthUser,
     Path(task_id): Path<Uuid>,
+    Query(params): Query<AdminDeleteTaskParams>,
 ) -> Result<StatusCode> {
-    state.admin_service.delete_task(task_id).await?;
+    let force = params.force.unwrap_or(false);
+    let audience = state.task_events.audience(task_id).await;
+    let storage_keys = state.admin_service.delete_task(task_id, admin_id, force).await?;
+    state.attachment_service.remove_stored_files(&storage_keys).await;
+
+    state.task_events.publish_to(
+        audience,
+        TaskEventKind::Deleted,
+        task_id,
+        None,
+        Some(admin_id),
+        Some(serde_json::json!({"purged": force})),
+    );
+
     Ok(StatusCode::NO_CONTENT)
 }
 
@@ -219,7 +257,7 @@ pub async fn get_all_users(
     Query(params): Query<PaginationParams>,
 ) -> Result<impl IntoResponse> {
     let page = params.page.unwrap_or(1).max(1);
-    let limit = params.limit.unwrap_or(10).min(100).max(1);
+    let limit = params.limit.unwrap_or(10).clamp(1, 100);
     let offset = ((page - 1) * limit) as i64;
 
     let users = state
@@ -241,6 +279,7 @@ pub async fn get_all_users(
         page,
         limit,
         total_pages,
+        next_cursor: None,
     };
 
     Ok((StatusCode::OK, Json(response)))
@@ -300,7 +339,7 @@ pub async fn get_user_by_id(
 pub async fn admin_update_user(
     State(state): State<AppState>,
     Path(user_id): Path<Uuid>,
-    Json(payload): Json<AdminUpdateUserRequest>,
+    AppJson(payload): AppJson<AdminUpdateUserRequest>,
 ) -> Result<impl IntoResponse> {
     payload.validate()?;
 
@@ -350,7 +389,8 @@ pub async fn delete_user(
         .await?
         .ok_or(AppError::NotFound("User not found".to_string()))?;
 
-    state.admin_service.delete_user(user_id).await?;
+    let storage_keys = state.admin_service.delete_user(user_id).await?;
+    state.attachment_service.remove_stored_files(&storage_keys).await;
 
     Ok(StatusCode::NO_CONTENT)
 }
@@ -377,7 +417,7 @@ pub async fn delete_user(
 pub async fn update_user_status(
     State(state): State<AppState>,
     Path(user_id): Path<Uuid>,
-    Json(payload): Json<UpdateUserStatusRequest>,
+    AppJson(payload): AppJson<UpdateUserStatusRequest>,
 ) -> Result<impl IntoResponse> {
     let user = state
         .admin_service
@@ -409,7 +449,7 @@ pub async fn update_user_status(
 pub async fn update_admin_status(
     State(state): State<AppState>,
     Path(user_id): Path<Uuid>,
-    Json(payload): Json<UpdateAdminStatusRequest>,
+    AppJson(payload): AppJson<UpdateAdminStatusRequest>,
 ) -> Result<impl IntoResponse> {
     let user = state
         .admin_service
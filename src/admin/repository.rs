@@ -3,7 +3,8 @@ use sqlx::PgPool;
 use uuid::Uuid;
 use crate::user::user_models::User;
 use crate::task::task_models::Task;
-use crate::task::task_repository::TaskFilters;
+use crate::task::task_repository::{TaskFilters, PURGE_TASK_TREES, SOFT_DELETE_TASK_TREE};
+use crate::search::search_models::to_prefix_tsquery;
 
 #[derive(Clone)]
 pub struct AdminRepository {
@@ -62,15 +63,42 @@ impl AdminRepository {
         Ok(user)
     }
 
-    pub async fn delete_user(&self, user_id: Uuid) -> Result<()> {
-        sqlx::query("DELETE FROM users WHERE id = $1")
-            .bind(user_id)
-            .execute(&self.pool)
-            .await?;
+    /// Deletes the user along with everything that cascades from them: their
+    /// tasks and projects, their conversations and the groups they created.
+    /// Returns the storage keys of every attachment that goes with it; the
+    /// caller must remove the files.
+    pub async fn delete_user(&self, user_id: Uuid) -> Result<Vec<String>> {
+        let (storage_keys,): (Vec<String>,) = sqlx::query_as(
+            "WITH RECURSIVE doomed_tasks AS (
+                SELECT id FROM tasks
+                WHERE user_id = $1 OR project_id IN (SELECT id FROM projects WHERE owner_id = $1)
+                UNION
+                SELECT t.id FROM tasks t JOIN doomed_tasks d ON t.parent_task_id = d.id
+             ),
+             doomed_messages AS (
+                SELECT id FROM messages
+                WHERE sender_id = $1 OR receiver_id = $1
+                OR group_id IN (SELECT id FROM groups WHERE creator_id = $1)
+             ),
+             files AS (
+                SELECT storage_key FROM attachments
+                WHERE user_id = $1
+                OR task_id IN (SELECT id FROM doomed_tasks)
+                OR message_id IN (SELECT id FROM doomed_messages)
+             ),
+             deleted AS (
+                DELETE FROM users WHERE id = $1 RETURNING id
+             )
+             SELECT ARRAY(SELECT storage_key FROM files)"
+        )
+        .bind(user_id)
+        .fetch_one(&self.pool)
+        .await?;
 
-        Ok(())
+        Ok(storage_keys)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn admin_update_user(
         &self,
         user_id: Uuid,
@@ -126,7 +154,7 @@ impl AdminRepository {
             bindings.push("is_active".to_string());
         }
 
-        query.push_str(&format!(" WHERE id = $1 RETURNING *"));
+        query.push_str(" WHERE id = $1 RETURNING *");
 
         let mut q = sqlx::query_as::<_, User>(&query).bind(user_id);
 
@@ -151,9 +179,10 @@ impl AdminRepository {
 
     // Task management
     pub async fn find_all_tasks(&self, filters: TaskFilters) -> Result<(Vec<Task>, i64)> {
-        let mut query = "SELECT t.* FROM tasks t WHERE 1=1".to_string();
-        let mut count_query = "SELECT COUNT(*) FROM tasks t WHERE 1=1".to_string();
+        let mut query = "SELECT t.* FROM tasks t WHERE t.deleted_at IS NULL".to_string();
+        let mut count_query = "SELECT COUNT(*) FROM tasks t WHERE t.deleted_at IS NULL".to_string();
         let mut params_count: usize = 0;
+        let search = filters.search.as_deref().and_then(to_prefix_tsquery);
 
         if let Some(ref _user_id) = filters.user_id {
             params_count += 1;
@@ -195,9 +224,9 @@ impl AdminRepository {
         }
 
         // Search filter
-        if let Some(ref _search) = filters.search {
+        if search.is_some() {
             params_count += 1;
-            let filter = format!(" AND (t.title ILIKE ${} OR t.description ILIKE ${})", params_count, params_count);
+            let filter = format!(" AND t.search_vector @@ to_tsquery('english', ${})", params_count);
             query.push_str(&filter);
             count_query.push_str(&filter);
         }
@@ -246,8 +275,8 @@ impl AdminRepository {
             count_db_query = count_db_query.bind(priority);
         }
 
-        if let Some(search) = &filters.search {
-            count_db_query = count_db_query.bind(format!("%{}%", search));
+        if let Some(search) = &search {
+            count_db_query = count_db_query.bind(search);
         }
 
         if let Some(from) = filters.created_from { count_db_query = count_db_query.bind(from); }
@@ -293,8 +322,8 @@ impl AdminRepository {
             db_query = db_query.bind(priority);
         }
 
-        if let Some(search) = filters.search {
-            db_query = db_query.bind(format!("%{}%", search));
+        if let Some(search) = search {
+            db_query = db_query.bind(search);
         }
 
         if let Some(from) = filters.created_from { db_query = db_query.bind(from); }
@@ -306,12 +335,25 @@ impl AdminRepository {
         Ok((tasks, total_count))
     }
 
-    pub async fn delete_task_admin(&self, id: Uuid) -> Result<u64> {
-        let result = sqlx::query("DELETE FROM tasks WHERE id = $1")
+    /// Moves a task and its subtasks to the owner's trash, like an owner delete.
+    pub async fn delete_task_admin(&self, id: Uuid, admin_id: Uuid) -> Result<u64> {
+        let result = sqlx::query(SOFT_DELETE_TASK_TREE)
             .bind(id)
+            .bind(admin_id)
             .execute(&self.pool)
             .await?;
         
         Ok(result.rows_affected())
     }
+
+    /// Permanently deletes a task and its subtasks, whether or not they are in the trash.
+    /// Returns the number of rows removed and the storage keys of their attachments.
+    pub async fn purge_task_admin(&self, id: Uuid) -> Result<(i64, Vec<String>)> {
+        let purged: (i64, Vec<String>) = sqlx::query_as(PURGE_TASK_TREES)
+            .bind(vec![id])
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(purged)
+    }
 }
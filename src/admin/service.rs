@@ -19,12 +19,18 @@ impl AdminService {
         self.repository.find_all_tasks(filters).await
     }
 
-    pub async fn delete_task(&self, task_id: Uuid) -> Result<()> {
-        let rows = self.repository.delete_task_admin(task_id).await?;
+    /// Trashes a task, or with `force` purges it at once. Returns the storage
+    /// keys of any attachments that were purged with it.
+    pub async fn delete_task(&self, task_id: Uuid, admin_id: Uuid, force: bool) -> Result<Vec<String>> {
+        let (rows, storage_keys) = if force {
+            self.repository.purge_task_admin(task_id).await?
+        } else {
+            (self.repository.delete_task_admin(task_id, admin_id).await? as i64, Vec::new())
+        };
         if rows == 0 {
             return Err(crate::error::AppError::NotFound("Task not found".to_string()));
         }
-        Ok(())
+        Ok(storage_keys)
     }
 
     pub async fn list_users(&self, limit: i64, offset: i64) -> Result<Vec<User>> {
@@ -43,12 +49,12 @@ impl AdminService {
         self.repository.update_active_status(user_id, is_active).await
     }
 
-    pub async fn delete_user(&self, user_id: Uuid) -> Result<()> {
-        // Here you could add logic to delete user's tasks first if needed, 
-        // though many DBs use ON DELETE CASCADE.
+    /// Returns the storage keys of the attachments deleted with the user, which the caller must remove.
+    pub async fn delete_user(&self, user_id: Uuid) -> Result<Vec<String>> {
         self.repository.delete_user(user_id).await
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn admin_update_user(
         &self,
         user_id: Uuid,
@@ -0,0 +1,10 @@
+use utoipa::ToSchema;
+
+/// Multipart form for uploads; only documents the request body.
+#[derive(Debug, ToSchema)]
+#[allow(dead_code)]
+pub struct AttachmentUpload {
+    /// The file to upload
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+}
@@ -0,0 +1,310 @@
+use axum::{
+    extract::{multipart::MultipartError, Multipart, Path, State},
+    http::{header, HeaderValue, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::{
+    error::{AppError, Result},
+    middleware::AuthUser,
+    state::AppState,
+    attachment::{
+        attachment_service::FileUpload,
+        storage::uri_encode,
+    },
+};
+
+/// Reads the `file` part of a multipart upload.
+async fn read_file_field(state: &AppState, mut multipart: Multipart) -> Result<FileUpload> {
+    let too_large = |e: MultipartError| {
+        if e.status() == StatusCode::PAYLOAD_TOO_LARGE {
+            AppError::PayloadTooLarge(format!(
+                "Files can be at most {} bytes",
+                state.attachment_service.max_file_bytes()
+            ))
+        } else {
+            AppError::BadRequest(format!("Invalid multipart body: {}", e.body_text()))
+        }
+    };
+
+    while let Some(field) = multipart.next_field().await.map_err(too_large)? {
+        if field.name() != Some("file") {
+            continue;
+        }
+
+        let file_name = field.file_name().map(str::to_string);
+        let content_type = field.content_type().map(str::to_string);
+        let data = field.bytes().await.map_err(too_large)?;
+
+        return Ok(FileUpload { file_name, content_type, data });
+    }
+
+    Err(AppError::BadRequest("Missing multipart field 'file'".to_string()))
+}
+
+/// Upload a file to a task
+#[utoipa::path(
+    post,
+    path = "/api/tasks/{task_id}/attachments",
+    tag = "attachments",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID")
+    ),
+    request_body(content = AttachmentUpload, content_type = "multipart/form-data"),
+    responses(
+        (status = 201, description = "File uploaded successfully", body = Attachment),
+        (status = 400, description = "Missing or empty file"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Viewers cannot attach files"),
+        (status = 404, description = "Task not found"),
+        (status = 413, description = "File too large or storage quota exceeded"),
+        (status = 415, description = "File type not allowed")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn upload_task_attachment(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(task_id): Path<Uuid>,
+    multipart: Multipart,
+) -> Result<impl IntoResponse> {
+    let upload = read_file_field(&state, multipart).await?;
+
+    let attachment = state.attachment_service
+        .upload_to_task(task_id, user_id, upload)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(attachment)))
+}
+
+/// List the files attached to a task
+#[utoipa::path(
+    get,
+    path = "/api/tasks/{task_id}/attachments",
+    tag = "attachments",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID")
+    ),
+    responses(
+        (status = 200, description = "Attachments retrieved successfully", body = Vec<Attachment>),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Task not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_task_attachments(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(task_id): Path<Uuid>,
+) -> Result<impl IntoResponse> {
+    let attachments = state.attachment_service
+        .list_task_attachments(task_id, user_id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(attachments)))
+}
+
+/// Upload a file to one of your messages
+#[utoipa::path(
+    post,
+    path = "/api/messages/{message_id}/attachments",
+    tag = "attachments",
+    params(
+        ("message_id" = Uuid, Path, description = "Message ID")
+    ),
+    request_body(content = AttachmentUpload, content_type = "multipart/form-data"),
+    responses(
+        (status = 201, description = "File uploaded successfully", body = Attachment),
+        (status = 400, description = "Missing or empty file"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Not the sender"),
+        (status = 404, description = "Message not found"),
+        (status = 413, description = "File too large or storage quota exceeded"),
+        (status = 415, description = "File type not allowed")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn upload_message_attachment(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(message_id): Path<Uuid>,
+    multipart: Multipart,
+) -> Result<impl IntoResponse> {
+    let upload = read_file_field(&state, multipart).await?;
+
+    let attachment = state.attachment_service
+        .upload_to_message(message_id, user_id, upload)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(attachment)))
+}
+
+/// List the files attached to a message
+#[utoipa::path(
+    get,
+    path = "/api/messages/{message_id}/attachments",
+    tag = "attachments",
+    params(
+        ("message_id" = Uuid, Path, description = "Message ID")
+    ),
+    responses(
+        (status = 200, description = "Attachments retrieved successfully", body = Vec<Attachment>),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Message not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_message_attachments(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(message_id): Path<Uuid>,
+) -> Result<impl IntoResponse> {
+    let attachments = state.attachment_service
+        .list_message_attachments(message_id, user_id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(attachments)))
+}
+
+/// Get attachment metadata
+#[utoipa::path(
+    get,
+    path = "/api/attachments/{attachment_id}",
+    tag = "attachments",
+    params(
+        ("attachment_id" = Uuid, Path, description = "Attachment ID")
+    ),
+    responses(
+        (status = 200, description = "Attachment retrieved successfully", body = Attachment),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Attachment not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_attachment(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(attachment_id): Path<Uuid>,
+) -> Result<impl IntoResponse> {
+    let attachment = state.attachment_service
+        .get_attachment(attachment_id, user_id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(attachment)))
+}
+
+/// Download an attachment
+#[utoipa::path(
+    get,
+    path = "/api/attachments/{attachment_id}/download",
+    tag = "attachments",
+    params(
+        ("attachment_id" = Uuid, Path, description = "Attachment ID")
+    ),
+    responses(
+        (status = 200, description = "File contents", content_type = "application/octet-stream"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Attachment not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn download_attachment(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(attachment_id): Path<Uuid>,
+) -> Result<impl IntoResponse> {
+    let (attachment, data) = state.attachment_service
+        .download(attachment_id, user_id)
+        .await?;
+
+    // ASCII fallback name plus the RFC 5987 UTF-8 form for non-ASCII names
+    let ascii_name: String = attachment.file_name
+        .chars()
+        .map(|c| if c.is_ascii_graphic() && c != '"' && c != '\\' || c == ' ' { c } else { '_' })
+        .collect();
+    let disposition = format!(
+        "attachment; filename=\"{}\"; filename*=UTF-8''{}",
+        ascii_name,
+        uri_encode(&attachment.file_name, true)
+    );
+
+    let content_type = HeaderValue::from_str(&attachment.content_type)
+        .unwrap_or_else(|_| HeaderValue::from_static("application/octet-stream"));
+    let disposition = HeaderValue::from_str(&disposition)
+        .unwrap_or_else(|_| HeaderValue::from_static("attachment"));
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, content_type),
+            (header::CONTENT_DISPOSITION, disposition),
+            (header::X_CONTENT_TYPE_OPTIONS, HeaderValue::from_static("nosniff")),
+        ],
+        data,
+    ))
+}
+
+/// Delete an attachment (uploader or task owner)
+#[utoipa::path(
+    delete,
+    path = "/api/attachments/{attachment_id}",
+    tag = "attachments",
+    params(
+        ("attachment_id" = Uuid, Path, description = "Attachment ID")
+    ),
+    responses(
+        (status = 204, description = "Attachment deleted successfully"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Not the uploader or task owner"),
+        (status = 404, description = "Attachment not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_attachment(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(attachment_id): Path<Uuid>,
+) -> Result<impl IntoResponse> {
+    state.attachment_service
+        .delete_attachment(attachment_id, user_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Get the authenticated user's storage usage and limits
+#[utoipa::path(
+    get,
+    path = "/api/attachments/usage",
+    tag = "attachments",
+    responses(
+        (status = 200, description = "Storage usage", body = StorageUsage),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_storage_usage(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+) -> Result<impl IntoResponse> {
+    let usage = state.attachment_service.get_usage(user_id).await?;
+
+    Ok((StatusCode::OK, Json(usage)))
+}
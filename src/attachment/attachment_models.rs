@@ -0,0 +1,167 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct Attachment {
+    pub id: Uuid,
+    /// Uploader; the file counts towards their storage quota
+    pub user_id: Uuid,
+    pub task_id: Option<Uuid>,
+    pub message_id: Option<Uuid>,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    #[serde(skip_serializing)]
+    pub storage_key: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct StorageUsage {
+    pub used_bytes: i64,
+    pub quota_bytes: i64,
+    pub max_file_bytes: i64,
+}
+
+/// Content types accepted for upload.
+pub const ALLOWED_CONTENT_TYPES: &[&str] = &[
+    "image/png",
+    "image/jpeg",
+    "image/gif",
+    "image/webp",
+    "application/pdf",
+    "text/plain",
+    "text/csv",
+    "text/markdown",
+    "application/json",
+    "application/zip",
+    "application/msword",
+    "application/vnd.ms-excel",
+    "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+    "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+];
+
+/// Office Open XML documents are ZIP archives on disk.
+const ZIP_BASED_CONTENT_TYPES: &[&str] = &[
+    "application/zip",
+    "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+    "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+];
+
+/// Detects well-known binary formats from their leading bytes.
+pub fn sniff_content_type(data: &[u8]) -> Option<&'static str> {
+    if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some("image/png")
+    } else if data.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        Some("image/jpeg")
+    } else if data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a") {
+        Some("image/gif")
+    } else if data.len() >= 12 && &data[..4] == b"RIFF" && &data[8..12] == b"WEBP" {
+        Some("image/webp")
+    } else if data.starts_with(b"%PDF-") {
+        Some("application/pdf")
+    } else if data.starts_with(b"PK\x03\x04") {
+        Some("application/zip")
+    } else {
+        None
+    }
+}
+
+/// Resolves the content type to store for an upload.
+///
+/// The declared type (from the multipart part) must be on the allowlist, and
+/// whenever the bytes carry a recognisable signature it has to agree with it,
+/// so an HTML page can't be uploaded as `image/png` or a PNG as `text/plain`.
+/// A missing or `application/octet-stream` type falls back to the sniffed one.
+pub fn resolve_content_type(declared: Option<&str>, data: &[u8]) -> Result<String, String> {
+    let declared = declared
+        .map(|ct| ct.split(';').next().unwrap_or_default().trim().to_ascii_lowercase())
+        .filter(|ct| !ct.is_empty() && ct != "application/octet-stream");
+    let sniffed = sniff_content_type(data);
+
+    let content_type = match (declared, sniffed) {
+        (Some(declared), _) => declared,
+        (None, Some(sniffed)) => sniffed.to_string(),
+        (None, None) => return Err("Could not determine the file type".to_string()),
+    };
+
+    if !ALLOWED_CONTENT_TYPES.contains(&content_type.as_str()) {
+        return Err(format!("Files of type '{}' are not allowed", content_type));
+    }
+
+    let matches_signature = match sniffed {
+        Some("application/zip") => ZIP_BASED_CONTENT_TYPES.contains(&content_type.as_str()),
+        Some(sniffed) => sniffed == content_type,
+        None => !sniff_signature_required(&content_type),
+    };
+    if !matches_signature {
+        return Err(format!("File contents do not match the declared type '{}'", content_type));
+    }
+
+    Ok(content_type)
+}
+
+/// Types whose files always start with a known signature.
+fn sniff_signature_required(content_type: &str) -> bool {
+    content_type.starts_with("image/")
+        || content_type == "application/pdf"
+        || ZIP_BASED_CONTENT_TYPES.contains(&content_type)
+}
+
+/// Keeps only the last path segment of a client-supplied file name and drops
+/// control characters.
+pub fn sanitize_file_name(file_name: Option<&str>) -> String {
+    let base = file_name
+        .unwrap_or_default()
+        .rsplit(['/', '\\'])
+        .next()
+        .unwrap_or_default();
+    let cleaned: String = base.chars().filter(|c| !c.is_control()).take(255).collect();
+    let cleaned = cleaned.trim();
+
+    if cleaned.is_empty() || cleaned == "." || cleaned == ".." {
+        "file".to_string()
+    } else {
+        cleaned.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PNG: &[u8] = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+
+    #[test]
+    fn resolve_content_type_checks_allowlist_and_signature() {
+        assert_eq!(resolve_content_type(Some("image/png"), PNG).unwrap(), "image/png");
+        assert_eq!(resolve_content_type(Some("text/plain; charset=utf-8"), b"hello").unwrap(), "text/plain");
+        assert_eq!(resolve_content_type(None, b"%PDF-1.7").unwrap(), "application/pdf");
+        assert_eq!(
+            resolve_content_type(
+                Some("application/vnd.openxmlformats-officedocument.wordprocessingml.document"),
+                b"PK\x03\x04rest"
+            )
+            .unwrap(),
+            "application/vnd.openxmlformats-officedocument.wordprocessingml.document"
+        );
+
+        assert!(resolve_content_type(Some("text/html"), b"<html>").is_err());
+        assert!(resolve_content_type(Some("image/png"), b"<html>").is_err());
+        assert!(resolve_content_type(Some("text/plain"), PNG).is_err());
+        assert!(resolve_content_type(Some("application/octet-stream"), b"\0\x01").is_err());
+    }
+
+    #[test]
+    fn sanitize_file_name_strips_paths() {
+        assert_eq!(sanitize_file_name(Some("../../etc/passwd")), "passwd");
+        assert_eq!(sanitize_file_name(Some("C:\\Users\\me\\report.pdf")), "report.pdf");
+        assert_eq!(sanitize_file_name(Some("..")), "file");
+        assert_eq!(sanitize_file_name(None), "file");
+    }
+}
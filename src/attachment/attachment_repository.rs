@@ -0,0 +1,122 @@
+use crate::error::Result;
+use sqlx::PgPool;
+use uuid::Uuid;
+use super::attachment_models::Attachment;
+
+/// Where a new attachment is linked to.
+#[derive(Debug, Clone, Copy)]
+pub enum AttachmentParent {
+    Task(Uuid),
+    Message(Uuid),
+}
+
+pub struct NewAttachment<'a> {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub parent: AttachmentParent,
+    pub file_name: &'a str,
+    pub content_type: &'a str,
+    pub size_bytes: i64,
+    pub storage_key: &'a str,
+}
+
+#[derive(Clone)]
+pub struct AttachmentRepository {
+    pool: PgPool,
+}
+
+impl AttachmentRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Inserts the attachment unless it would push the uploader past `quota_bytes`.
+    /// Uploads by the same user are serialized so concurrent requests can't
+    /// overshoot the quota together. Returns `None` when the quota is exceeded.
+    pub async fn create_within_quota(&self, new: NewAttachment<'_>, quota_bytes: i64) -> Result<Option<Attachment>> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("SELECT pg_advisory_xact_lock(hashtext('attachment_quota:' || $1::text))")
+            .bind(new.user_id)
+            .execute(&mut *tx)
+            .await?;
+
+        let (task_id, message_id) = match new.parent {
+            AttachmentParent::Task(task_id) => (Some(task_id), None),
+            AttachmentParent::Message(message_id) => (None, Some(message_id)),
+        };
+
+        let attachment = sqlx::query_as::<_, Attachment>(
+            "INSERT INTO attachments (id, user_id, task_id, message_id, file_name, content_type, size_bytes, storage_key)
+             SELECT $1, $2, $3, $4, $5, $6, $7, $8
+             WHERE (SELECT COALESCE(SUM(size_bytes), 0) FROM attachments WHERE user_id = $2) + $7 <= $9
+             RETURNING *"
+        )
+        .bind(new.id)
+        .bind(new.user_id)
+        .bind(task_id)
+        .bind(message_id)
+        .bind(new.file_name)
+        .bind(new.content_type)
+        .bind(new.size_bytes)
+        .bind(new.storage_key)
+        .bind(quota_bytes)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(attachment)
+    }
+
+    pub async fn find_by_id(&self, id: Uuid) -> Result<Option<Attachment>> {
+        let attachment = sqlx::query_as::<_, Attachment>("SELECT * FROM attachments WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(attachment)
+    }
+
+    pub async fn find_for_task(&self, task_id: Uuid) -> Result<Vec<Attachment>> {
+        let attachments = sqlx::query_as::<_, Attachment>(
+            "SELECT * FROM attachments WHERE task_id = $1 ORDER BY created_at ASC"
+        )
+        .bind(task_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(attachments)
+    }
+
+    pub async fn find_for_message(&self, message_id: Uuid) -> Result<Vec<Attachment>> {
+        let attachments = sqlx::query_as::<_, Attachment>(
+            "SELECT * FROM attachments WHERE message_id = $1 ORDER BY created_at ASC"
+        )
+        .bind(message_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(attachments)
+    }
+
+    pub async fn used_bytes(&self, user_id: Uuid) -> Result<i64> {
+        let used: i64 = sqlx::query_scalar(
+            "SELECT COALESCE(SUM(size_bytes), 0)::BIGINT FROM attachments WHERE user_id = $1"
+        )
+        .bind(user_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(used)
+    }
+
+    pub async fn delete(&self, id: Uuid) -> Result<()> {
+        sqlx::query("DELETE FROM attachments WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
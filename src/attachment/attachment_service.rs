@@ -0,0 +1,270 @@
+use crate::error::{AppError, Result};
+use crate::group::group_repository::GroupRepository;
+use crate::message::message_repository::MessageRepository;
+use crate::task::task_models::TaskPermission;
+use crate::task::task_repository::TaskRepository;
+use axum::body::Bytes;
+use std::sync::Arc;
+use uuid::Uuid;
+use super::attachment_models::{resolve_content_type, sanitize_file_name, Attachment, StorageUsage};
+use super::attachment_repository::{AttachmentParent, AttachmentRepository, NewAttachment};
+use super::storage::FileStorage;
+
+/// Upload limits, read from `MAX_UPLOAD_BYTES` and `USER_STORAGE_QUOTA_BYTES`.
+#[derive(Debug, Clone, Copy)]
+pub struct AttachmentLimits {
+    pub max_file_bytes: usize,
+    pub user_quota_bytes: i64,
+}
+
+impl AttachmentLimits {
+    pub fn from_env() -> Self {
+        Self {
+            max_file_bytes: std::env::var("MAX_UPLOAD_BYTES")
+                .unwrap_or_else(|_| (10 * 1024 * 1024).to_string())
+                .parse()
+                .expect("MAX_UPLOAD_BYTES must be a number"),
+            user_quota_bytes: std::env::var("USER_STORAGE_QUOTA_BYTES")
+                .unwrap_or_else(|_| (100 * 1024 * 1024).to_string())
+                .parse()
+                .expect("USER_STORAGE_QUOTA_BYTES must be a number"),
+        }
+    }
+}
+
+/// A file received from a multipart upload.
+pub struct FileUpload {
+    pub file_name: Option<String>,
+    pub content_type: Option<String>,
+    pub data: Bytes,
+}
+
+#[derive(Clone)]
+pub struct AttachmentService {
+    repo: AttachmentRepository,
+    task_repo: TaskRepository,
+    message_repo: MessageRepository,
+    group_repo: GroupRepository,
+    storage: Arc<dyn FileStorage>,
+    limits: AttachmentLimits,
+}
+
+impl AttachmentService {
+    pub fn new(
+        repo: AttachmentRepository,
+        task_repo: TaskRepository,
+        message_repo: MessageRepository,
+        group_repo: GroupRepository,
+        storage: Arc<dyn FileStorage>,
+        limits: AttachmentLimits,
+    ) -> Self {
+        Self {
+            repo,
+            task_repo,
+            message_repo,
+            group_repo,
+            storage,
+            limits,
+        }
+    }
+
+    pub fn max_file_bytes(&self) -> usize {
+        self.limits.max_file_bytes
+    }
+
+    /// Anyone who can edit the task can attach files to it.
+    pub async fn upload_to_task(&self, task_id: Uuid, user_id: Uuid, upload: FileUpload) -> Result<Attachment> {
+        match self.task_repo.get_task_role(task_id, user_id).await? {
+            Some(role) if role.can(TaskPermission::Edit) => {}
+            Some(role) => return Err(AppError::Forbidden(role.denied(TaskPermission::Edit))),
+            None => return Err(AppError::NotFound("Task not found".to_string())),
+        }
+
+        let attachment = self.store(user_id, AttachmentParent::Task(task_id), upload).await?;
+
+        let _ = self.task_repo.log_task_activity(
+            task_id,
+            user_id,
+            "attachment_added",
+            Some(serde_json::json!({"attachment_id": attachment.id, "file_name": attachment.file_name})),
+        ).await;
+
+        Ok(attachment)
+    }
+
+    /// Only the sender can attach files to a message.
+    pub async fn upload_to_message(&self, message_id: Uuid, user_id: Uuid, upload: FileUpload) -> Result<Attachment> {
+        let message = self.message_repo
+            .find_by_id(message_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Message not found".to_string()))?;
+
+        if message.sender_id != user_id {
+            return Err(AppError::Forbidden("You can only attach files to your own messages".to_string()));
+        }
+
+        self.store(user_id, AttachmentParent::Message(message_id), upload).await
+    }
+
+    pub async fn list_task_attachments(&self, task_id: Uuid, user_id: Uuid) -> Result<Vec<Attachment>> {
+        self.ensure_task_access(task_id, user_id).await?;
+        self.repo.find_for_task(task_id).await
+    }
+
+    pub async fn list_message_attachments(&self, message_id: Uuid, user_id: Uuid) -> Result<Vec<Attachment>> {
+        self.ensure_message_access(message_id, user_id).await?;
+        self.repo.find_for_message(message_id).await
+    }
+
+    pub async fn get_attachment(&self, attachment_id: Uuid, user_id: Uuid) -> Result<Attachment> {
+        let attachment = self.repo
+            .find_by_id(attachment_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Attachment not found".to_string()))?;
+
+        match (attachment.task_id, attachment.message_id) {
+            (Some(task_id), _) => self.ensure_task_access(task_id, user_id).await?,
+            (None, Some(message_id)) => self.ensure_message_access(message_id, user_id).await?,
+            (None, None) => return Err(AppError::NotFound("Attachment not found".to_string())),
+        }
+
+        Ok(attachment)
+    }
+
+    /// Returns the attachment with its contents, if the user can see what it is attached to.
+    pub async fn download(&self, attachment_id: Uuid, user_id: Uuid) -> Result<(Attachment, Bytes)> {
+        let attachment = self.get_attachment(attachment_id, user_id).await?;
+        let data = self.storage.get(&attachment.storage_key).await?;
+        Ok((attachment, data))
+    }
+
+    /// The uploader can delete an attachment; so can the owner of the task it is on.
+    pub async fn delete_attachment(&self, attachment_id: Uuid, user_id: Uuid) -> Result<()> {
+        let attachment = self.get_attachment(attachment_id, user_id).await?;
+
+        let is_task_owner = match attachment.task_id {
+            Some(task_id) => self.task_repo.is_task_owner(task_id, user_id).await?,
+            None => false,
+        };
+        if attachment.user_id != user_id && !is_task_owner {
+            return Err(AppError::Forbidden(
+                "Only the uploader or the task owner can delete this attachment".to_string(),
+            ));
+        }
+
+        self.repo.delete(attachment.id).await?;
+        if let Err(e) = self.storage.delete(&attachment.storage_key).await {
+            tracing::warn!("Failed to remove stored file {}: {:?}", attachment.storage_key, e);
+        }
+
+        if let Some(task_id) = attachment.task_id {
+            let _ = self.task_repo.log_task_activity(
+                task_id,
+                user_id,
+                "attachment_removed",
+                Some(serde_json::json!({"attachment_id": attachment.id, "file_name": attachment.file_name})),
+            ).await;
+        }
+
+        Ok(())
+    }
+
+    /// Removes files whose attachment rows are already gone, e.g. after a task purge.
+    /// Failures are only logged, since the rows cannot be brought back.
+    pub async fn remove_stored_files(&self, storage_keys: &[String]) {
+        for key in storage_keys {
+            if let Err(e) = self.storage.delete(key).await {
+                tracing::warn!("Failed to remove stored file {}: {:?}", key, e);
+            }
+        }
+    }
+
+    pub async fn get_usage(&self, user_id: Uuid) -> Result<StorageUsage> {
+        Ok(StorageUsage {
+            used_bytes: self.repo.used_bytes(user_id).await?,
+            quota_bytes: self.limits.user_quota_bytes,
+            max_file_bytes: self.limits.max_file_bytes as i64,
+        })
+    }
+
+    /// Validates the upload, writes it to storage and records it, rolling the
+    /// stored file back if the quota check fails.
+    async fn store(&self, user_id: Uuid, parent: AttachmentParent, upload: FileUpload) -> Result<Attachment> {
+        if upload.data.is_empty() {
+            return Err(AppError::BadRequest("The uploaded file is empty".to_string()));
+        }
+        if upload.data.len() > self.limits.max_file_bytes {
+            return Err(AppError::PayloadTooLarge(format!(
+                "Files can be at most {} bytes",
+                self.limits.max_file_bytes
+            )));
+        }
+
+        let content_type = resolve_content_type(upload.content_type.as_deref(), &upload.data)
+            .map_err(AppError::UnsupportedMediaType)?;
+        let file_name = sanitize_file_name(upload.file_name.as_deref());
+        let size_bytes = upload.data.len() as i64;
+
+        if self.repo.used_bytes(user_id).await? + size_bytes > self.limits.user_quota_bytes {
+            return Err(quota_exceeded(self.limits.user_quota_bytes));
+        }
+
+        let id = Uuid::new_v4();
+        let storage_key = format!("{}/{}", user_id, id);
+        self.storage.put(&storage_key, upload.data, &content_type).await?;
+
+        let created = self.repo.create_within_quota(
+            NewAttachment {
+                id,
+                user_id,
+                parent,
+                file_name: &file_name,
+                content_type: &content_type,
+                size_bytes,
+                storage_key: &storage_key,
+            },
+            self.limits.user_quota_bytes,
+        ).await;
+
+        match created {
+            Ok(Some(attachment)) => Ok(attachment),
+            other => {
+                if let Err(e) = self.storage.delete(&storage_key).await {
+                    tracing::warn!("Failed to remove stored file {}: {:?}", storage_key, e);
+                }
+                match other {
+                    Err(e) => Err(e),
+                    _ => Err(quota_exceeded(self.limits.user_quota_bytes)),
+                }
+            }
+        }
+    }
+
+    async fn ensure_task_access(&self, task_id: Uuid, user_id: Uuid) -> Result<()> {
+        if !self.task_repo.has_task_access(task_id, user_id).await? {
+            return Err(AppError::NotFound("Task not found".to_string()));
+        }
+        Ok(())
+    }
+
+    /// Participants of a direct conversation, or members of the group, can see a message.
+    async fn ensure_message_access(&self, message_id: Uuid, user_id: Uuid) -> Result<()> {
+        let message = self.message_repo
+            .find_by_id(message_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Message not found".to_string()))?;
+
+        let allowed = match message.group_id {
+            Some(group_id) => self.group_repo.is_member(group_id, user_id).await?,
+            None => message.sender_id == user_id || message.receiver_id == Some(user_id),
+        };
+        if !allowed {
+            return Err(AppError::NotFound("Message not found".to_string()));
+        }
+        Ok(())
+    }
+}
+
+fn quota_exceeded(quota_bytes: i64) -> AppError {
+    AppError::PayloadTooLarge(format!("Storage quota of {} bytes exceeded", quota_bytes))
+}
@@ -0,0 +1,7 @@
+// Declare submodules
+pub mod attachment_models;
+pub mod attachment_dto;
+pub mod attachment_repository;
+pub mod attachment_service;
+pub mod attachment_handlers;
+pub mod storage;
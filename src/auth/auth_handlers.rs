@@ -5,6 +5,7 @@ use crate::{
         oauth::GoogleUserInfo,
     },
     error::{AppError, Result},
+    middleware::AppJson,
     state::AppState,
 };
 use axum::{extract::{State, Query}, http::StatusCode, response::{IntoResponse, Redirect}, Json};
@@ -33,7 +34,7 @@ pub struct GoogleCallback {
 )]
 pub async fn register(
     State(state): State<AppState>,
-    Json(payload): Json<RegisterRequest>,
+    AppJson(payload): AppJson<RegisterRequest>,
 ) -> Result<impl IntoResponse> {
     payload.validate()
         .map_err(|e| AppError::Validation(e.to_string()))?;
@@ -77,7 +78,7 @@ pub async fn register(
 )]
 pub async fn login(
     State(state): State<AppState>,
-    Json(payload): Json<LoginRequest>,
+    AppJson(payload): AppJson<LoginRequest>,
 ) -> Result<impl IntoResponse> {
     payload.validate()
         .map_err(|e| AppError::Validation(e.to_string()))?;
@@ -107,7 +108,7 @@ pub async fn login(
 )]
 pub async fn refresh_token(
     State(state): State<AppState>,
-    Json(payload): Json<RefreshTokenRequest>,
+    AppJson(payload): AppJson<RefreshTokenRequest>,
 ) -> Result<impl IntoResponse> {
     // let (access_token, _refresh_token) = state.auth_service
     //     .refresh_access_token(&payload.refresh_token)
@@ -139,7 +140,7 @@ pub async fn refresh_token(
 )]
 pub async fn logout(
     State(state): State<AppState>,
-    Json(payload): Json<RefreshTokenRequest>,
+    AppJson(payload): AppJson<RefreshTokenRequest>,
 ) -> Result<impl IntoResponse> {
     state.auth_service.logout(&payload.refresh_token).await?;
     Ok(StatusCode::OK)
@@ -241,7 +242,7 @@ pub async fn google_callback(
 )]
 pub async fn register_admin(
     State(state): State<AppState>,
-    Json(payload): Json<RegisterRequest>,
+    AppJson(payload): AppJson<RegisterRequest>,
 ) -> Result<impl IntoResponse> {
     payload.validate()
         .map_err(|e| AppError::Validation(e.to_string()))?;
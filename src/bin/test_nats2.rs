@@ -27,9 +27,9 @@ SUADYN3HVZY4CEGZAIMARZBF6XHSZASLGJPYLSDW4NXSFBPHNF4RIW3XJU
     let res_unix = async_nats::ConnectOptions::with_credentials_file(&path_unix).await;
     println!("Unix (LF) parsed properly? {:?}", res_unix.is_ok());
     
-    if res_unix.is_ok() {
+    if let Ok(options) = res_unix {
         // Let's actually test connecting to the cloud to prove it works
-        let final_result = res_unix.unwrap().connect("tls://connect.ngs.global:4222").await;
+        let final_result = options.connect("tls://connect.ngs.global:4222").await;
         println!("Cloud connection result: {:?}", final_result.is_ok());
     }
 }
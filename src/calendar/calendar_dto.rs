@@ -0,0 +1,16 @@
+use serde::Deserialize;
+use uuid::Uuid;
+
+use super::calendar_models::CalendarComponent;
+
+/// Query parameters of the feed URL; calendar apps keep them on every refresh.
+#[derive(Debug, Default, Deserialize)]
+pub struct CalendarFeedQuery {
+    pub project_id: Option<Uuid>,
+    /// Comma-separated label IDs; tasks carrying any of them are included
+    pub labels: Option<String>,
+    /// A user ID, or `me` for the feed's owner
+    pub assignee: Option<String>,
+    /// `event` (default) or `todo`
+    pub component: Option<CalendarComponent>,
+}
@@ -0,0 +1,120 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, HeaderValue, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+
+use crate::{
+    calendar::calendar_dto::CalendarFeedQuery,
+    error::Result,
+    middleware::AuthUser,
+    state::AppState,
+};
+
+/// Get your calendar feed
+#[utoipa::path(
+    get,
+    path = "/api/calendar/feed",
+    tag = "calendar",
+    responses(
+        (status = 200, description = "Your feed and its subscription URL", body = CalendarFeedResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "You have no calendar feed")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_feed(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+) -> Result<impl IntoResponse> {
+    let feed = state.calendar_service.get_feed(user_id).await?;
+
+    Ok((StatusCode::OK, Json(feed)))
+}
+
+/// Create your calendar feed, or replace its token
+///
+/// The previous subscription URL stops working.
+#[utoipa::path(
+    post,
+    path = "/api/calendar/feed",
+    tag = "calendar",
+    responses(
+        (status = 201, description = "Feed created with a new token", body = CalendarFeedResponse),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn regenerate_feed(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+) -> Result<impl IntoResponse> {
+    let feed = state.calendar_service.regenerate_feed(user_id).await?;
+
+    Ok((StatusCode::CREATED, Json(feed)))
+}
+
+/// Revoke your calendar feed
+#[utoipa::path(
+    delete,
+    path = "/api/calendar/feed",
+    tag = "calendar",
+    responses(
+        (status = 204, description = "Feed revoked"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "You have no calendar feed")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn revoke_feed(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+) -> Result<impl IntoResponse> {
+    state.calendar_service.revoke_feed(user_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// iCalendar feed of your tasks with a due date, for calendar apps
+///
+/// The token in the URL is the only credential, so the `.ics` suffix is optional.
+#[utoipa::path(
+    get,
+    path = "/api/public/calendar/{token}",
+    tag = "calendar",
+    params(
+        ("token" = String, Path, description = "Feed token, optionally followed by .ics"),
+        ("project_id" = Option<Uuid>, Query, description = "Only tasks in this project"),
+        ("labels" = Option<String>, Query, description = "Comma-separated label IDs; tasks with any of them"),
+        ("assignee" = Option<String>, Query, description = "Only tasks assigned to this user ID, or `me`"),
+        ("component" = Option<CalendarComponent>, Query, description = "`event` (default) or `todo`")
+    ),
+    responses(
+        (status = 200, description = "iCalendar document", content_type = "text/calendar"),
+        (status = 400, description = "Invalid filter"),
+        (status = 404, description = "Feed not found or revoked")
+    )
+)]
+pub async fn calendar_feed(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+    Query(query): Query<CalendarFeedQuery>,
+) -> Result<impl IntoResponse> {
+    let token = token.strip_suffix(".ics").unwrap_or(&token);
+    let ics = state.calendar_service.render_feed(token, query).await?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, HeaderValue::from_static("text/calendar; charset=utf-8")),
+            (header::CONTENT_DISPOSITION, HeaderValue::from_static("inline; filename=\"tasks.ics\"")),
+        ],
+        ics,
+    ))
+}
@@ -0,0 +1,256 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::task::task_models::{Task, TaskPriority, TaskStatus};
+
+const PRODID: &str = "-//taskPadi//Task Feed//EN";
+
+/// Longest content line allowed by RFC 5545, in octets
+const MAX_LINE_OCTETS: usize = 75;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct CalendarFeed {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub last_accessed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// JWT claims of a feed token. Only the feed id matters; the user id lets us
+/// reject a token that was issued for someone else's feed.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CalendarFeedClaims {
+    pub sub: Uuid,
+    pub user_id: Uuid,
+}
+
+/// Your calendar feed and the URL to subscribe to.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CalendarFeedResponse {
+    pub id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub last_accessed_at: Option<DateTime<Utc>>,
+    /// Signed token; anyone holding it can read the feed
+    pub token: String,
+    /// Subscription path, relative to the API host
+    pub url: String,
+}
+
+/// How tasks appear in the feed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum CalendarComponent {
+    /// A `VEVENT` ending at the due date, starting `estimate_minutes` earlier
+    #[default]
+    Event,
+    /// A `VTODO` due at the due date, with its completion status
+    Todo,
+}
+
+impl CalendarComponent {
+    fn name(self) -> &'static str {
+        match self {
+            CalendarComponent::Event => "VEVENT",
+            CalendarComponent::Todo => "VTODO",
+        }
+    }
+}
+
+/// Renders tasks as an iCalendar document. Tasks without a due date are left out.
+pub fn render_calendar(tasks: &[Task], component: CalendarComponent, now: DateTime<Utc>) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        format!("PRODID:{}", PRODID),
+        "CALSCALE:GREGORIAN".to_string(),
+        "METHOD:PUBLISH".to_string(),
+        "X-WR-CALNAME:Tasks".to_string(),
+    ];
+    for task in tasks {
+        if let Some(due) = task.due_date {
+            lines.extend(render_task(task, due, component, now));
+        }
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    let mut document: String = lines.iter().map(|line| fold_line(line)).collect::<Vec<_>>().join("\r\n");
+    document.push_str("\r\n");
+    document
+}
+
+fn render_task(task: &Task, due: DateTime<Utc>, component: CalendarComponent, now: DateTime<Utc>) -> Vec<String> {
+    let mut lines = vec![
+        format!("BEGIN:{}", component.name()),
+        format!("UID:{}@taskpadi", task.id),
+        format!("DTSTAMP:{}", ics_time(now)),
+        format!("CREATED:{}", ics_time(task.created_at)),
+        format!("LAST-MODIFIED:{}", ics_time(task.updated_at)),
+        format!("SEQUENCE:{}", task.row_version),
+        format!("SUMMARY:{}", escape_text(&task.title)),
+    ];
+    if let Some(description) = task.description.as_deref().filter(|d| !d.is_empty()) {
+        lines.push(format!("DESCRIPTION:{}", escape_text(description)));
+    }
+    lines.push(format!("PRIORITY:{}", ics_priority(task.priority)));
+
+    match component {
+        CalendarComponent::Event => {
+            let start = match task.estimate_minutes.filter(|minutes| *minutes > 0) {
+                Some(minutes) => due - Duration::minutes(minutes as i64),
+                None => due,
+            };
+            lines.push(format!("DTSTART:{}", ics_time(start)));
+            // Without an estimate the event is a point in time, which has no DTEND
+            if start < due {
+                lines.push(format!("DTEND:{}", ics_time(due)));
+            }
+        }
+        CalendarComponent::Todo => {
+            lines.push(format!("DUE:{}", ics_time(due)));
+            lines.push(format!("STATUS:{}", ics_todo_status(task.status)));
+        }
+    }
+
+    if let Some(reminder) = task.reminder_time {
+        lines.extend([
+            "BEGIN:VALARM".to_string(),
+            "ACTION:DISPLAY".to_string(),
+            format!("DESCRIPTION:{}", escape_text(&task.title)),
+            format!("TRIGGER;VALUE=DATE-TIME:{}", ics_time(reminder)),
+            "END:VALARM".to_string(),
+        ]);
+    }
+
+    lines.push(format!("END:{}", component.name()));
+    lines
+}
+
+fn ics_time(time: DateTime<Utc>) -> String {
+    time.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// iCalendar priorities run from 1 (highest) to 9 (lowest).
+fn ics_priority(priority: TaskPriority) -> u8 {
+    match priority {
+        TaskPriority::Urgent => 1,
+        TaskPriority::High => 3,
+        TaskPriority::Medium => 5,
+        TaskPriority::Low => 9,
+    }
+}
+
+fn ics_todo_status(status: TaskStatus) -> &'static str {
+    match status {
+        TaskStatus::Completed | TaskStatus::Archived => "COMPLETED",
+        TaskStatus::InProgress => "IN-PROCESS",
+        TaskStatus::Pending => "NEEDS-ACTION",
+    }
+}
+
+/// Escapes a TEXT property value.
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Splits a content line into lines of at most 75 octets, each continuation
+/// starting with a space. Never splits inside a character.
+fn fold_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + line.len() / MAX_LINE_OCTETS * 3);
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > MAX_LINE_OCTETS {
+            folded.push_str("\r\n ");
+            width = 1;
+        }
+        folded.push(c);
+        width += c.len_utf8();
+    }
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task(title: &str) -> Task {
+        let now = Utc::now();
+        Task {
+            id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            title: title.to_string(),
+            description: None,
+            status: TaskStatus::InProgress,
+            priority: TaskPriority::High,
+            due_date: Some("2026-03-02T17:00:00Z".parse().unwrap()),
+            reminder_time: None,
+            notified: false,
+            created_at: now,
+            updated_at: now,
+            parent_task_id: None,
+            position: 0,
+            recurrence_rule: None,
+            recurrence_series_id: None,
+            recurrence_index: None,
+            project_id: None,
+            column_id: None,
+            board_rank: 0.0,
+            estimate_minutes: None,
+            deleted_at: None,
+            row_version: 1,
+        }
+    }
+
+    #[test]
+    fn test_escape_and_fold() {
+        assert_eq!(escape_text("a, b; c\\d\r\ne"), "a\\, b\\; c\\\\d\\ne");
+
+        let line = format!("SUMMARY:{}", "é".repeat(60));
+        let folded = fold_line(&line);
+        assert!(folded.split("\r\n").all(|part| part.len() <= MAX_LINE_OCTETS));
+        assert_eq!(folded.replace("\r\n ", ""), line);
+    }
+
+    #[test]
+    fn test_render_event_with_alarm() {
+        let mut with_reminder = task("Ship release");
+        with_reminder.estimate_minutes = Some(90);
+        with_reminder.reminder_time = Some("2026-03-02T15:00:00Z".parse().unwrap());
+        let mut undated = task("Someday");
+        undated.due_date = None;
+
+        let ics = render_calendar(&[with_reminder.clone(), undated], CalendarComponent::Event, Utc::now());
+
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 1);
+        assert!(ics.contains(&format!("UID:{}@taskpadi", with_reminder.id)));
+        assert!(ics.contains("DTSTART:20260302T153000Z\r\nDTEND:20260302T170000Z"));
+        assert!(ics.contains("PRIORITY:3"));
+        assert!(ics.contains("BEGIN:VALARM\r\nACTION:DISPLAY"));
+        assert!(ics.contains("TRIGGER;VALUE=DATE-TIME:20260302T150000Z"));
+    }
+
+    #[test]
+    fn test_render_todo() {
+        let ics = render_calendar(&[task("Review")], CalendarComponent::Todo, Utc::now());
+
+        assert!(ics.contains("BEGIN:VTODO"));
+        assert!(ics.contains("DUE:20260302T170000Z"));
+        assert!(ics.contains("STATUS:IN-PROCESS"));
+        assert!(!ics.contains("VALARM"));
+    }
+}
@@ -0,0 +1,113 @@
+use crate::error::Result;
+use crate::task::task_models::Task;
+use crate::task::task_repository::ACCESSIBLE_TASKS;
+use sqlx::PgPool;
+use uuid::Uuid;
+use super::calendar_models::CalendarFeed;
+
+#[derive(Clone)]
+pub struct CalendarRepository {
+    pool: PgPool,
+}
+
+impl CalendarRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Creates the user's feed, or gives the existing one a new id so tokens
+    /// signed for the old one stop working.
+    pub async fn replace(&self, user_id: Uuid) -> Result<CalendarFeed> {
+        let feed = sqlx::query_as::<_, CalendarFeed>(
+            "INSERT INTO calendar_feeds (user_id) VALUES ($1)
+             ON CONFLICT (user_id) DO UPDATE SET
+                id = gen_random_uuid(),
+                last_accessed_at = NULL,
+                created_at = NOW()
+             RETURNING *"
+        )
+        .bind(user_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(feed)
+    }
+
+    pub async fn find_by_user(&self, user_id: Uuid) -> Result<Option<CalendarFeed>> {
+        let feed = sqlx::query_as::<_, CalendarFeed>("SELECT * FROM calendar_feeds WHERE user_id = $1")
+            .bind(user_id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(feed)
+    }
+
+    /// The feed with this id, unless its owner's account is deactivated.
+    pub async fn find_active(&self, id: Uuid) -> Result<Option<CalendarFeed>> {
+        let feed = sqlx::query_as::<_, CalendarFeed>(
+            "SELECT f.* FROM calendar_feeds f
+             JOIN users u ON u.id = f.user_id
+             WHERE f.id = $1 AND u.is_active = true"
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(feed)
+    }
+
+    pub async fn delete_by_user(&self, user_id: Uuid) -> Result<u64> {
+        let result = sqlx::query("DELETE FROM calendar_feeds WHERE user_id = $1")
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    pub async fn record_access(&self, id: Uuid) -> Result<()> {
+        sqlx::query("UPDATE calendar_feeds SET last_accessed_at = NOW() WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Tasks with a due date the user has access to, latest due first.
+    /// Archived and trashed tasks are left out.
+    pub async fn find_feed_tasks(
+        &self,
+        user_id: Uuid,
+        project_id: Option<Uuid>,
+        label_ids: Option<&[Uuid]>,
+        assignee_id: Option<Uuid>,
+        limit: i64,
+    ) -> Result<Vec<Task>> {
+        let query = format!(
+            "SELECT DISTINCT t.* {}
+             AND t.due_date IS NOT NULL
+             AND t.status <> 'Archived'
+             AND ($2::uuid IS NULL OR t.project_id = $2)
+             AND ($3::uuid[] IS NULL OR EXISTS (
+                 SELECT 1 FROM task_labels tl WHERE tl.task_id = t.id AND tl.label_id = ANY($3)
+             ))
+             AND ($4::uuid IS NULL OR EXISTS (
+                 SELECT 1 FROM task_assignees ta WHERE ta.task_id = t.id AND ta.user_id = $4
+             ))
+             ORDER BY t.due_date DESC, t.id DESC
+             LIMIT $5",
+            ACCESSIBLE_TASKS
+        );
+        let tasks = sqlx::query_as::<_, Task>(&query)
+            .bind(user_id)
+            .bind(project_id)
+            .bind(label_ids)
+            .bind(assignee_id)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(tasks)
+    }
+}
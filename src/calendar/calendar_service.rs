@@ -0,0 +1,109 @@
+use crate::error::{AppError, Result};
+use crate::task::task_handlers::{parse_assignee_filter, parse_label_filter};
+use chrono::Utc;
+use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use uuid::Uuid;
+use super::calendar_dto::CalendarFeedQuery;
+use super::calendar_models::{render_calendar, CalendarFeed, CalendarFeedClaims, CalendarFeedResponse};
+use super::calendar_repository::CalendarRepository;
+
+/// Most tasks a feed lists; the ones due furthest in the past are dropped first
+const MAX_FEED_TASKS: i64 = 1000;
+
+#[derive(Clone)]
+pub struct CalendarService {
+    repo: CalendarRepository,
+    /// Derived from the JWT secret, so feed tokens can't be used as access
+    /// tokens or share link tokens, or the other way round
+    signing_key: String,
+}
+
+impl CalendarService {
+    pub fn new(repo: CalendarRepository, jwt_secret: &str) -> Self {
+        Self {
+            repo,
+            signing_key: format!("calendar-feed:{}", jwt_secret),
+        }
+    }
+
+    pub async fn get_feed(&self, user_id: Uuid) -> Result<CalendarFeedResponse> {
+        let feed = self.repo
+            .find_by_user(user_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Calendar feed not found".to_string()))?;
+
+        self.to_response(feed)
+    }
+
+    /// Creates the user's feed, or replaces its token if they already have one.
+    pub async fn regenerate_feed(&self, user_id: Uuid) -> Result<CalendarFeedResponse> {
+        let feed = self.repo.replace(user_id).await?;
+        self.to_response(feed)
+    }
+
+    pub async fn revoke_feed(&self, user_id: Uuid) -> Result<()> {
+        if self.repo.delete_by_user(user_id).await? == 0 {
+            return Err(AppError::NotFound("Calendar feed not found".to_string()));
+        }
+        Ok(())
+    }
+
+    /// The iCalendar document behind a feed token.
+    pub async fn render_feed(&self, token: &str, query: CalendarFeedQuery) -> Result<String> {
+        let feed = self.open(token).await?;
+
+        let labels = parse_label_filter(query.labels.as_deref())?;
+        let assignee_id = parse_assignee_filter(query.assignee.as_deref(), feed.user_id)?;
+
+        let tasks = self.repo
+            .find_feed_tasks(feed.user_id, query.project_id, labels.as_deref(), assignee_id, MAX_FEED_TASKS)
+            .await?;
+        self.repo.record_access(feed.id).await?;
+
+        Ok(render_calendar(&tasks, query.component.unwrap_or_default(), Utc::now()))
+    }
+
+    /// Checks the token and the feed it names. Bad tokens and replaced or
+    /// revoked feeds all look the same.
+    async fn open(&self, token: &str) -> Result<CalendarFeed> {
+        let not_found = || AppError::NotFound("Calendar feed not found".to_string());
+
+        let mut validation = Validation::default();
+        validation.required_spec_claims.clear();
+        validation.validate_exp = false;
+        let claims = decode::<CalendarFeedClaims>(
+            token,
+            &DecodingKey::from_secret(self.signing_key.as_bytes()),
+            &validation,
+        )
+        .map_err(|_| not_found())?
+        .claims;
+
+        self.repo
+            .find_active(claims.sub)
+            .await?
+            .filter(|feed| feed.user_id == claims.user_id)
+            .ok_or_else(not_found)
+    }
+
+    fn to_response(&self, feed: CalendarFeed) -> Result<CalendarFeedResponse> {
+        let claims = CalendarFeedClaims {
+            sub: feed.id,
+            user_id: feed.user_id,
+        };
+        let token = encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(self.signing_key.as_bytes()),
+        )
+        .map_err(|_| AppError::InternalError)?;
+
+        Ok(CalendarFeedResponse {
+            id: feed.id,
+            created_at: feed.created_at,
+            last_accessed_at: feed.last_accessed_at,
+            url: format!("/api/public/calendar/{}.ics", token),
+            token,
+        })
+    }
+}
@@ -0,0 +1,6 @@
+// Declare submodules
+pub mod calendar_models;
+pub mod calendar_dto;
+pub mod calendar_repository;
+pub mod calendar_service;
+pub mod calendar_handlers;
@@ -0,0 +1,29 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateChecklistItemRequest {
+    #[validate(length(min = 1, max = 500))]
+    pub content: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateChecklistItemRequest {
+    #[validate(length(min = 1, max = 500))]
+    pub content: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct BulkChecklistRequest {
+    /// One item per line; list markers and `[ ]`/`[x]` checkboxes are understood
+    #[validate(length(min = 1, max = 50000))]
+    pub text: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ReorderChecklistRequest {
+    /// Every item of the checklist, in the new order
+    pub item_ids: Vec<Uuid>,
+}
@@ -0,0 +1,234 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    error::Result,
+    middleware::{AppJson, AuthUser},
+    state::AppState,
+    checklist::checklist_dto::{
+        BulkChecklistRequest, CreateChecklistItemRequest, ReorderChecklistRequest, UpdateChecklistItemRequest,
+    },
+};
+
+/// Get a task's checklist in order
+#[utoipa::path(
+    get,
+    path = "/api/tasks/{task_id}/checklist",
+    tag = "checklists",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID")
+    ),
+    responses(
+        (status = 200, description = "Checklist items", body = Vec<ChecklistItem>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Access denied")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_checklist(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(task_id): Path<Uuid>,
+) -> Result<impl IntoResponse> {
+    let items = state.checklist_service.list_items(task_id, user_id).await?;
+
+    Ok((StatusCode::OK, Json(items)))
+}
+
+/// Add an item to the end of a task's checklist
+#[utoipa::path(
+    post,
+    path = "/api/tasks/{task_id}/checklist",
+    tag = "checklists",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID")
+    ),
+    request_body = CreateChecklistItemRequest,
+    responses(
+        (status = 201, description = "Item added", body = ChecklistItem),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Viewers cannot edit the checklist")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create_checklist_item(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(task_id): Path<Uuid>,
+    AppJson(payload): AppJson<CreateChecklistItemRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+
+    let item = state.checklist_service.add_item(task_id, user_id, &payload.content).await?;
+
+    Ok((StatusCode::CREATED, Json(item)))
+}
+
+/// Paste several checklist items at once, one per line
+#[utoipa::path(
+    post,
+    path = "/api/tasks/{task_id}/checklist/bulk",
+    tag = "checklists",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID")
+    ),
+    request_body = BulkChecklistRequest,
+    responses(
+        (status = 201, description = "Items added; returns the whole checklist", body = Vec<ChecklistItem>),
+        (status = 400, description = "No items, too many items, or an item too long"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Viewers cannot edit the checklist")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn bulk_create_checklist_items(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(task_id): Path<Uuid>,
+    AppJson(payload): AppJson<BulkChecklistRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+
+    let items = state.checklist_service.add_items_from_text(task_id, user_id, &payload.text).await?;
+
+    Ok((StatusCode::CREATED, Json(items)))
+}
+
+/// Reorder a task's checklist
+#[utoipa::path(
+    put,
+    path = "/api/tasks/{task_id}/checklist/order",
+    tag = "checklists",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID")
+    ),
+    request_body = ReorderChecklistRequest,
+    responses(
+        (status = 200, description = "Checklist in its new order", body = Vec<ChecklistItem>),
+        (status = 400, description = "item_ids is not a permutation of the checklist"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Viewers cannot edit the checklist")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn reorder_checklist(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(task_id): Path<Uuid>,
+    AppJson(payload): AppJson<ReorderChecklistRequest>,
+) -> Result<impl IntoResponse> {
+    let items = state.checklist_service.reorder_items(task_id, user_id, payload.item_ids).await?;
+
+    Ok((StatusCode::OK, Json(items)))
+}
+
+/// Rename a checklist item
+#[utoipa::path(
+    put,
+    path = "/api/tasks/{task_id}/checklist/{item_id}",
+    tag = "checklists",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID"),
+        ("item_id" = Uuid, Path, description = "Checklist item ID")
+    ),
+    request_body = UpdateChecklistItemRequest,
+    responses(
+        (status = 200, description = "Item updated", body = ChecklistItem),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Viewers cannot edit the checklist"),
+        (status = 404, description = "Checklist item not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn update_checklist_item(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path((task_id, item_id)): Path<(Uuid, Uuid)>,
+    AppJson(payload): AppJson<UpdateChecklistItemRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+
+    let item = state.checklist_service
+        .update_item(task_id, item_id, user_id, &payload.content)
+        .await?;
+
+    Ok((StatusCode::OK, Json(item)))
+}
+
+/// Tick or untick a checklist item
+///
+/// Everyone with access to the task gets a `checklist_item_toggled` WebSocket event.
+#[utoipa::path(
+    post,
+    path = "/api/tasks/{task_id}/checklist/{item_id}/toggle",
+    tag = "checklists",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID"),
+        ("item_id" = Uuid, Path, description = "Checklist item ID")
+    ),
+    responses(
+        (status = 200, description = "Item toggled", body = ChecklistItem),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Viewers cannot edit the checklist"),
+        (status = 404, description = "Checklist item not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn toggle_checklist_item(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path((task_id, item_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse> {
+    let item = state.checklist_service.toggle_item(task_id, item_id, user_id).await?;
+
+    Ok((StatusCode::OK, Json(item)))
+}
+
+/// Remove a checklist item
+#[utoipa::path(
+    delete,
+    path = "/api/tasks/{task_id}/checklist/{item_id}",
+    tag = "checklists",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID"),
+        ("item_id" = Uuid, Path, description = "Checklist item ID")
+    ),
+    responses(
+        (status = 204, description = "Item removed"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Viewers cannot edit the checklist"),
+        (status = 404, description = "Checklist item not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_checklist_item(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path((task_id, item_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse> {
+    state.checklist_service.delete_item(task_id, item_id, user_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
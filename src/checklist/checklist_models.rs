@@ -0,0 +1,86 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Longest checklist item, in characters
+pub const MAX_ITEM_CHARS: usize = 500;
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct ChecklistItem {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub content: String,
+    pub is_checked: bool,
+    /// Order within the task's checklist
+    pub position: i32,
+    pub checked_by: Option<Uuid>,
+    pub checked_at: Option<DateTime<Utc>>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Checked/total counts of a task's checklist.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, ToSchema)]
+pub struct ChecklistProgress {
+    pub checked: i64,
+    pub total: i64,
+}
+
+/// Splits pasted text into checklist items, one per non-blank line.
+///
+/// List markers (`-`, `*`, `+`, `•`, `1.`, `1)`) are dropped, and a leading
+/// Markdown checkbox sets the item's state: `[x]` is checked, `[ ]` is not.
+pub fn parse_checklist_text(text: &str) -> Vec<(String, bool)> {
+    text.lines()
+        .filter_map(|line| {
+            let mut item = line.trim();
+
+            if let Some(rest) = item.strip_prefix(['-', '*', '+', '•']) {
+                item = rest.trim_start();
+            } else {
+                let digits = item.len() - item.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+                if digits > 0 {
+                    if let Some(rest) = item[digits..].strip_prefix(['.', ')']) {
+                        item = rest.trim_start();
+                    }
+                }
+            }
+
+            let mut checked = false;
+            for (marker, state) in [("[ ]", false), ("[x]", true), ("[X]", true)] {
+                if let Some(rest) = item.strip_prefix(marker) {
+                    item = rest.trim_start();
+                    checked = state;
+                    break;
+                }
+            }
+
+            (!item.is_empty()).then(|| (item.to_string(), checked))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_checklist_text() {
+        let pasted = "Buy milk\n\n- [x] Call Sam\n  * [ ] Book venue  \n2. Send invites\n10) [X] Pay deposit\n-\n2026 plans";
+
+        assert_eq!(
+            parse_checklist_text(pasted),
+            vec![
+                ("Buy milk".to_string(), false),
+                ("Call Sam".to_string(), true),
+                ("Book venue".to_string(), false),
+                ("Send invites".to_string(), false),
+                ("Pay deposit".to_string(), true),
+                ("2026 plans".to_string(), false),
+            ]
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use crate::error::Result;
+use sqlx::PgPool;
+use std::collections::HashMap;
+use uuid::Uuid;
+use super::checklist_models::{ChecklistItem, ChecklistProgress};
+
+#[derive(Clone)]
+pub struct ChecklistRepository {
+    pool: PgPool,
+}
+
+impl ChecklistRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Appends items to the end of a task's checklist, keeping their order.
+    pub async fn append(&self, task_id: Uuid, items: &[(String, bool)], created_by: Uuid) -> Result<Vec<ChecklistItem>> {
+        let contents: Vec<&str> = items.iter().map(|(content, _)| content.as_str()).collect();
+        let checked: Vec<bool> = items.iter().map(|(_, checked)| *checked).collect();
+
+        let created = sqlx::query_as::<_, ChecklistItem>(
+            "INSERT INTO task_checklist_items (task_id, content, is_checked, position, checked_by, checked_at, created_by)
+             SELECT $1, item.content, item.is_checked,
+                    (SELECT COALESCE(MAX(position), -1) FROM task_checklist_items WHERE task_id = $1) + item.ord::int,
+                    CASE WHEN item.is_checked THEN $4::uuid END,
+                    CASE WHEN item.is_checked THEN NOW() END,
+                    $4
+             FROM UNNEST($2::text[], $3::bool[]) WITH ORDINALITY AS item(content, is_checked, ord)
+             ORDER BY item.ord
+             RETURNING *"
+        )
+        .bind(task_id)
+        .bind(&contents)
+        .bind(&checked)
+        .bind(created_by)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(created)
+    }
+
+    pub async fn find_for_task(&self, task_id: Uuid) -> Result<Vec<ChecklistItem>> {
+        let items = sqlx::query_as::<_, ChecklistItem>(
+            "SELECT * FROM task_checklist_items WHERE task_id = $1 ORDER BY position ASC, created_at ASC"
+        )
+        .bind(task_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(items)
+    }
+
+    pub async fn find_by_id(&self, task_id: Uuid, id: Uuid) -> Result<Option<ChecklistItem>> {
+        let item = sqlx::query_as::<_, ChecklistItem>(
+            "SELECT * FROM task_checklist_items WHERE id = $1 AND task_id = $2"
+        )
+        .bind(id)
+        .bind(task_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(item)
+    }
+
+    pub async fn update_content(&self, task_id: Uuid, id: Uuid, content: &str) -> Result<Option<ChecklistItem>> {
+        let item = sqlx::query_as::<_, ChecklistItem>(
+            "UPDATE task_checklist_items SET content = $3, updated_at = NOW()
+             WHERE id = $1 AND task_id = $2
+             RETURNING *"
+        )
+        .bind(id)
+        .bind(task_id)
+        .bind(content)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(item)
+    }
+
+    /// Flips an item between checked and unchecked, recording who checked it.
+    pub async fn toggle(&self, task_id: Uuid, id: Uuid, user_id: Uuid) -> Result<Option<ChecklistItem>> {
+        let item = sqlx::query_as::<_, ChecklistItem>(
+            "UPDATE task_checklist_items
+             SET is_checked = NOT is_checked,
+                 checked_by = CASE WHEN is_checked THEN NULL ELSE $3 END,
+                 checked_at = CASE WHEN is_checked THEN NULL ELSE NOW() END,
+                 updated_at = NOW()
+             WHERE id = $1 AND task_id = $2
+             RETURNING *"
+        )
+        .bind(id)
+        .bind(task_id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(item)
+    }
+
+    pub async fn delete(&self, task_id: Uuid, id: Uuid) -> Result<u64> {
+        let result = sqlx::query("DELETE FROM task_checklist_items WHERE id = $1 AND task_id = $2")
+            .bind(id)
+            .bind(task_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Rewrites the positions of a task's items to match `ordered_ids`.
+    pub async fn reorder(&self, task_id: Uuid, ordered_ids: &[Uuid]) -> Result<()> {
+        let positions: Vec<i32> = (0..ordered_ids.len() as i32).collect();
+
+        sqlx::query(
+            "UPDATE task_checklist_items SET position = ordering.position, updated_at = NOW()
+             FROM UNNEST($1::uuid[], $2::int[]) AS ordering(id, position)
+             WHERE task_checklist_items.id = ordering.id AND task_checklist_items.task_id = $3"
+        )
+        .bind(ordered_ids)
+        .bind(&positions)
+        .bind(task_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_progress(&self, task_ids: &[Uuid]) -> Result<HashMap<Uuid, ChecklistProgress>> {
+        let rows: Vec<(Uuid, i64, i64)> = sqlx::query_as(
+            "SELECT task_id, COUNT(*) FILTER (WHERE is_checked), COUNT(*)
+             FROM task_checklist_items
+             WHERE task_id = ANY($1)
+             GROUP BY task_id"
+        )
+        .bind(task_ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, checked, total)| (id, ChecklistProgress { checked, total }))
+            .collect())
+    }
+}
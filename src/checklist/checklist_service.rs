@@ -0,0 +1,231 @@
+use crate::error::{AppError, Result};
+use crate::task::task_models::{TaskEventKind, TaskPermission};
+use crate::task::task_repository::TaskRepository;
+use crate::task::task_service::TaskService;
+use crate::user::user_repository::UserRepository;
+use crate::websocket::types::{ChecklistItemToggledPayload, WsMessage};
+use crate::websocket::ConnectionManager;
+use std::collections::HashSet;
+use uuid::Uuid;
+use super::checklist_models::{parse_checklist_text, ChecklistItem, MAX_ITEM_CHARS};
+use super::checklist_repository::ChecklistRepository;
+
+/// Most items a single paste can add
+const MAX_BULK_ITEMS: usize = 200;
+
+#[derive(Clone)]
+pub struct ChecklistService {
+    repo: ChecklistRepository,
+    task_repo: TaskRepository,
+    task_service: TaskService,
+    user_repo: UserRepository,
+    ws_manager: ConnectionManager,
+}
+
+impl ChecklistService {
+    pub fn new(
+        repo: ChecklistRepository,
+        task_repo: TaskRepository,
+        task_service: TaskService,
+        user_repo: UserRepository,
+        ws_manager: ConnectionManager,
+    ) -> Self {
+        Self {
+            repo,
+            task_repo,
+            task_service,
+            user_repo,
+            ws_manager,
+        }
+    }
+
+    pub async fn list_items(&self, task_id: Uuid, user_id: Uuid) -> Result<Vec<ChecklistItem>> {
+        self.task_service.require_permission(task_id, user_id, TaskPermission::View).await?;
+        self.repo.find_for_task(task_id).await
+    }
+
+    pub async fn add_item(&self, task_id: Uuid, user_id: Uuid, content: &str) -> Result<ChecklistItem> {
+        self.task_service.require_permission(task_id, user_id, TaskPermission::Edit).await?;
+
+        let item = self.repo
+            .append(task_id, &[(content.trim().to_string(), false)], user_id)
+            .await?
+            .into_iter()
+            .next()
+            .ok_or(AppError::InternalError)?;
+
+        let _ = self.task_repo.log_task_activity(
+            task_id,
+            user_id,
+            "checklist_item_added",
+            Some(serde_json::json!({"item_id": item.id, "content": item.content})),
+        ).await;
+        self.publish_change(task_id, user_id, serde_json::json!({"action": "added", "item_ids": [item.id]})).await;
+
+        Ok(item)
+    }
+
+    /// Adds one item per line of pasted text to the end of the checklist.
+    /// Returns the whole checklist afterwards.
+    pub async fn add_items_from_text(&self, task_id: Uuid, user_id: Uuid, text: &str) -> Result<Vec<ChecklistItem>> {
+        self.task_service.require_permission(task_id, user_id, TaskPermission::Edit).await?;
+
+        let items = parse_checklist_text(text);
+        if items.is_empty() {
+            return Err(AppError::BadRequest("The pasted text has no checklist items".to_string()));
+        }
+        if items.len() > MAX_BULK_ITEMS {
+            return Err(AppError::BadRequest(format!(
+                "At most {} items can be pasted at once",
+                MAX_BULK_ITEMS
+            )));
+        }
+        if let Some((content, _)) = items.iter().find(|(content, _)| content.chars().count() > MAX_ITEM_CHARS) {
+            return Err(AppError::BadRequest(format!(
+                "Checklist items can be at most {} characters: '{}...'",
+                MAX_ITEM_CHARS,
+                content.chars().take(40).collect::<String>()
+            )));
+        }
+
+        let created = self.repo.append(task_id, &items, user_id).await?;
+
+        let _ = self.task_repo.log_task_activity(
+            task_id,
+            user_id,
+            "checklist_items_added",
+            Some(serde_json::json!({"count": created.len()})),
+        ).await;
+        let item_ids: Vec<Uuid> = created.iter().map(|item| item.id).collect();
+        self.publish_change(task_id, user_id, serde_json::json!({"action": "added", "item_ids": item_ids})).await;
+
+        self.repo.find_for_task(task_id).await
+    }
+
+    pub async fn update_item(&self, task_id: Uuid, item_id: Uuid, user_id: Uuid, content: &str) -> Result<ChecklistItem> {
+        self.task_service.require_permission(task_id, user_id, TaskPermission::Edit).await?;
+
+        let previous = self.repo
+            .find_by_id(task_id, item_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Checklist item not found".to_string()))?;
+        let item = self.repo
+            .update_content(task_id, item_id, content.trim())
+            .await?
+            .ok_or_else(|| AppError::NotFound("Checklist item not found".to_string()))?;
+
+        let _ = self.task_repo.log_task_activity(
+            task_id,
+            user_id,
+            "checklist_item_updated",
+            Some(serde_json::json!({"item_id": item.id, "from": previous.content, "to": item.content})),
+        ).await;
+        self.publish_change(task_id, user_id, serde_json::json!({"action": "updated", "item_ids": [item.id]})).await;
+
+        Ok(item)
+    }
+
+    /// Ticks or unticks an item and tells everyone on the task right away.
+    pub async fn toggle_item(&self, task_id: Uuid, item_id: Uuid, user_id: Uuid) -> Result<ChecklistItem> {
+        self.task_service.require_permission(task_id, user_id, TaskPermission::Edit).await?;
+
+        let item = self.repo
+            .toggle(task_id, item_id, user_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Checklist item not found".to_string()))?;
+
+        let _ = self.task_repo.log_task_activity(
+            task_id,
+            user_id,
+            if item.is_checked { "checklist_item_checked" } else { "checklist_item_unchecked" },
+            Some(serde_json::json!({"item_id": item.id, "content": item.content})),
+        ).await;
+
+        self.broadcast_toggle(&item, user_id).await;
+        let action = if item.is_checked { "checked" } else { "unchecked" };
+        self.publish_change(task_id, user_id, serde_json::json!({"action": action, "item_ids": [item.id]})).await;
+
+        Ok(item)
+    }
+
+    pub async fn delete_item(&self, task_id: Uuid, item_id: Uuid, user_id: Uuid) -> Result<()> {
+        self.task_service.require_permission(task_id, user_id, TaskPermission::Edit).await?;
+
+        let item = self.repo
+            .find_by_id(task_id, item_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Checklist item not found".to_string()))?;
+        self.repo.delete(task_id, item.id).await?;
+
+        let _ = self.task_repo.log_task_activity(
+            task_id,
+            user_id,
+            "checklist_item_removed",
+            Some(serde_json::json!({"item_id": item.id, "content": item.content})),
+        ).await;
+        self.publish_change(task_id, user_id, serde_json::json!({"action": "removed", "item_ids": [item.id]})).await;
+
+        Ok(())
+    }
+
+    pub async fn reorder_items(&self, task_id: Uuid, user_id: Uuid, item_ids: Vec<Uuid>) -> Result<Vec<ChecklistItem>> {
+        self.task_service.require_permission(task_id, user_id, TaskPermission::Edit).await?;
+
+        // The new order must be a permutation of the current items
+        let current: HashSet<Uuid> = self.repo
+            .find_for_task(task_id)
+            .await?
+            .into_iter()
+            .map(|item| item.id)
+            .collect();
+        let requested: HashSet<Uuid> = item_ids.iter().copied().collect();
+        if requested.len() != item_ids.len() || requested != current {
+            return Err(AppError::BadRequest(
+                "item_ids must list every checklist item of the task exactly once".to_string(),
+            ));
+        }
+
+        self.repo.reorder(task_id, &item_ids).await?;
+
+        let _ = self.task_repo.log_task_activity(task_id, user_id, "checklist_reordered", None).await;
+        self.publish_change(task_id, user_id, serde_json::json!({"action": "reordered", "item_ids": item_ids})).await;
+
+        self.repo.find_for_task(task_id).await
+    }
+
+    /// Tells everyone on the task about a checklist change with a `checklist_changed` task event.
+    async fn publish_change(&self, task_id: Uuid, user_id: Uuid, data: serde_json::Value) {
+        self.task_service
+            .publish_change(TaskEventKind::ChecklistChanged, task_id, user_id, data)
+            .await;
+    }
+
+    async fn broadcast_toggle(&self, item: &ChecklistItem, toggled_by: Uuid) {
+        let username = match self.user_repo.find_by_id(toggled_by).await {
+            Ok(Some(user)) => user.username,
+            _ => return,
+        };
+        let progress = match self.repo.get_progress(&[item.task_id]).await {
+            Ok(mut progress) => progress.remove(&item.task_id).unwrap_or_default(),
+            Err(_) => return,
+        };
+        let audience = match self.task_repo.get_audience(item.task_id).await {
+            Ok(audience) => audience,
+            Err(e) => {
+                tracing::warn!("Failed to load members of task {}: {:?}", item.task_id, e);
+                return;
+            }
+        };
+
+        let ws_message = WsMessage::ChecklistItemToggled(ChecklistItemToggledPayload {
+            task_id: item.task_id,
+            item_id: item.id,
+            is_checked: item.is_checked,
+            toggled_by,
+            toggled_by_username: username,
+            checked: progress.checked,
+            total: progress.total,
+        });
+        self.ws_manager.send_to_users(&audience, ws_message);
+    }
+}
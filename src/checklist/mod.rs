@@ -0,0 +1,6 @@
+// Declare submodules
+pub mod checklist_models;
+pub mod checklist_dto;
+pub mod checklist_repository;
+pub mod checklist_service;
+pub mod checklist_handlers;
@@ -0,0 +1,19 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateCommentRequest {
+    /// Comment text; `@username` mentions notify task members
+    #[validate(length(min = 1, max = 10000))]
+    pub content: String,
+    /// Reply to this comment instead of starting a new thread
+    pub parent_comment_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateCommentRequest {
+    #[validate(length(min = 1, max = 10000))]
+    pub content: String,
+}
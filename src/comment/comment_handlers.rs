@@ -0,0 +1,218 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::Deserialize;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    error::Result,
+    middleware::{AppJson, AuthUser},
+    state::AppState,
+    task::task_dto::PaginatedResponse,
+    comment::{
+        comment_dto::{CreateCommentRequest, UpdateCommentRequest},
+        comment_models::CommentResponse,
+    },
+};
+
+#[derive(Debug, Deserialize)]
+pub struct CommentQuery {
+    page: Option<u32>,
+    limit: Option<u32>,
+}
+
+impl CommentQuery {
+    fn page_and_limit(&self) -> (u32, u32) {
+        (self.page.unwrap_or(1).max(1), self.limit.unwrap_or(20).clamp(1, 100))
+    }
+}
+
+fn paginate(data: Vec<CommentResponse>, total: i64, page: u32, limit: u32) -> PaginatedResponse<CommentResponse> {
+    let total_pages = ((total as f64) / (limit as f64)).ceil() as u32;
+
+    PaginatedResponse {
+        data,
+        total,
+        page,
+        limit,
+        total_pages,
+        next_cursor: None,
+    }
+}
+
+/// Comment on a task, or reply to one of its comments
+#[utoipa::path(
+    post,
+    path = "/api/tasks/{task_id}/comments",
+    tag = "comments",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID")
+    ),
+    request_body = CreateCommentRequest,
+    responses(
+        (status = 201, description = "Comment created successfully", body = CommentResponse),
+        (status = 400, description = "Validation error or replying to a deleted comment"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Task not found or read-only for you"),
+        (status = 404, description = "Parent comment not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create_comment(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(task_id): Path<Uuid>,
+    AppJson(payload): AppJson<CreateCommentRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+
+    let comment = state.comment_service
+        .add_comment(task_id, user_id, payload)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(comment)))
+}
+
+/// Get the top-level comments of a task, oldest first
+#[utoipa::path(
+    get,
+    path = "/api/tasks/{task_id}/comments",
+    tag = "comments",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID"),
+        ("page" = Option<u32>, Query, description = "Page number (default: 1)"),
+        ("limit" = Option<u32>, Query, description = "Items per page (default: 20, max: 100)")
+    ),
+    responses(
+        (status = 200, description = "Paginated comments", body = PaginatedResponse<CommentResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Task not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_comments(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(task_id): Path<Uuid>,
+    Query(query): Query<CommentQuery>,
+) -> Result<impl IntoResponse> {
+    let (page, limit) = query.page_and_limit();
+    let offset = ((page - 1) * limit) as i64;
+
+    let (comments, total) = state.comment_service
+        .list_comments(task_id, user_id, limit as i64, offset)
+        .await?;
+
+    Ok((StatusCode::OK, Json(paginate(comments, total, page, limit))))
+}
+
+/// Get the replies to a comment, oldest first
+#[utoipa::path(
+    get,
+    path = "/api/tasks/{task_id}/comments/{comment_id}/replies",
+    tag = "comments",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID"),
+        ("comment_id" = Uuid, Path, description = "Comment ID"),
+        ("page" = Option<u32>, Query, description = "Page number (default: 1)"),
+        ("limit" = Option<u32>, Query, description = "Items per page (default: 20, max: 100)")
+    ),
+    responses(
+        (status = 200, description = "Paginated replies", body = PaginatedResponse<CommentResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Task or comment not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_comment_replies(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path((task_id, comment_id)): Path<(Uuid, Uuid)>,
+    Query(query): Query<CommentQuery>,
+) -> Result<impl IntoResponse> {
+    let (page, limit) = query.page_and_limit();
+    let offset = ((page - 1) * limit) as i64;
+
+    let (replies, total) = state.comment_service
+        .list_replies(task_id, comment_id, user_id, limit as i64, offset)
+        .await?;
+
+    Ok((StatusCode::OK, Json(paginate(replies, total, page, limit))))
+}
+
+/// Edit a comment (author only)
+#[utoipa::path(
+    put,
+    path = "/api/tasks/{task_id}/comments/{comment_id}",
+    tag = "comments",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID"),
+        ("comment_id" = Uuid, Path, description = "Comment ID")
+    ),
+    request_body = UpdateCommentRequest,
+    responses(
+        (status = 200, description = "Comment updated successfully", body = CommentResponse),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Not the author, or the task is read-only for you"),
+        (status = 404, description = "Comment not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn update_comment(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path((task_id, comment_id)): Path<(Uuid, Uuid)>,
+    AppJson(payload): AppJson<UpdateCommentRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+
+    let comment = state.comment_service
+        .update_comment(task_id, comment_id, user_id, payload.content)
+        .await?;
+
+    Ok((StatusCode::OK, Json(comment)))
+}
+
+/// Delete a comment (author or task owner). Replies stay in the thread.
+#[utoipa::path(
+    delete,
+    path = "/api/tasks/{task_id}/comments/{comment_id}",
+    tag = "comments",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID"),
+        ("comment_id" = Uuid, Path, description = "Comment ID")
+    ),
+    responses(
+        (status = 204, description = "Comment deleted successfully"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Not the author or task owner"),
+        (status = 404, description = "Task or comment not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_comment(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path((task_id, comment_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse> {
+    state.comment_service
+        .delete_comment(task_id, comment_id, user_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,89 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct TaskComment {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub user_id: Option<Uuid>,
+    pub parent_comment_id: Option<Uuid>,
+    pub content: String,
+    pub is_edited: bool,
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A comment as shown in a thread, with its author and number of replies.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct CommentResponse {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    /// `None` when the author's account has been deleted
+    pub user_id: Option<Uuid>,
+    pub username: Option<String>,
+    pub avatar_url: Option<String>,
+    /// Name given by a guest commenting through a share link
+    pub guest_name: Option<String>,
+    pub parent_comment_id: Option<Uuid>,
+    /// Empty once the comment has been deleted
+    pub content: String,
+    pub is_edited: bool,
+    pub is_deleted: bool,
+    pub reply_count: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+fn is_username_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-')
+}
+
+/// Extracts the distinct `@username` mentions from a comment, in order of appearance.
+///
+/// A mention must start the text or follow a character that can't be part of a
+/// username, so e-mail addresses like `jane@example.com` are not picked up.
+/// Trailing dots and dashes are treated as punctuation ("thanks @jane.").
+pub fn parse_mentions(content: &str) -> Vec<String> {
+    let mut mentions: Vec<String> = Vec::new();
+    let mut prev: Option<char> = None;
+
+    for (i, c) in content.char_indices() {
+        if c == '@' && !prev.is_some_and(|p| is_username_char(p) || p == '@') {
+            let rest = &content[i + 1..];
+            let end = rest.find(|ch: char| !is_username_char(ch)).unwrap_or(rest.len());
+            let username = rest[..end].trim_end_matches(['.', '-']);
+
+            if !username.is_empty() && !mentions.iter().any(|m| m == username) {
+                mentions.push(username.to_string());
+            }
+        }
+        prev = Some(c);
+    }
+
+    mentions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_mentions_finds_distinct_usernames() {
+        assert_eq!(
+            parse_mentions("@alice can you check this with @bob_2? cc @alice"),
+            vec!["alice", "bob_2"]
+        );
+        assert_eq!(parse_mentions("thanks @jane.doe."), vec!["jane.doe"]);
+        assert_eq!(parse_mentions("(@carol) and @dave-"), vec!["carol", "dave"]);
+    }
+
+    #[test]
+    fn parse_mentions_ignores_emails_and_bare_at_signs() {
+        assert!(parse_mentions("mail jane@example.com").is_empty());
+        assert!(parse_mentions("meet @ 5pm, @@x or @.").is_empty());
+    }
+}
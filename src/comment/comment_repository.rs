@@ -0,0 +1,192 @@
+use crate::error::Result;
+use sqlx::PgPool;
+use uuid::Uuid;
+use super::comment_models::{CommentResponse, TaskComment};
+
+const COMMENT_RESPONSE_SELECT: &str =
+    "SELECT c.id, c.task_id, c.user_id, u.username, u.avatar_url, c.guest_name, c.parent_comment_id,
+            c.content, c.is_edited, c.deleted_at IS NOT NULL AS is_deleted,
+            (SELECT COUNT(*) FROM task_comments r WHERE r.parent_comment_id = c.id) AS reply_count,
+            c.created_at, c.updated_at
+     FROM task_comments c
+     LEFT JOIN users u ON u.id = c.user_id";
+
+#[derive(Clone)]
+pub struct CommentRepository {
+    pool: PgPool,
+}
+
+impl CommentRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn create(
+        &self,
+        task_id: Uuid,
+        user_id: Uuid,
+        parent_comment_id: Option<Uuid>,
+        content: &str,
+    ) -> Result<TaskComment> {
+        let comment = sqlx::query_as::<_, TaskComment>(
+            "INSERT INTO task_comments (task_id, user_id, parent_comment_id, content)
+             VALUES ($1, $2, $3, $4)
+             RETURNING *"
+        )
+        .bind(task_id)
+        .bind(user_id)
+        .bind(parent_comment_id)
+        .bind(content)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(comment)
+    }
+
+    /// A comment left through a commentable share link by someone without an account.
+    pub async fn create_guest(
+        &self,
+        task_id: Uuid,
+        share_link_id: Uuid,
+        guest_name: &str,
+        parent_comment_id: Option<Uuid>,
+        content: &str,
+    ) -> Result<TaskComment> {
+        let comment = sqlx::query_as::<_, TaskComment>(
+            "INSERT INTO task_comments (task_id, share_link_id, guest_name, parent_comment_id, content)
+             VALUES ($1, $2, $3, $4, $5)
+             RETURNING *"
+        )
+        .bind(task_id)
+        .bind(share_link_id)
+        .bind(guest_name)
+        .bind(parent_comment_id)
+        .bind(content)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(comment)
+    }
+
+    pub async fn find_by_id(&self, id: Uuid) -> Result<Option<TaskComment>> {
+        let comment = sqlx::query_as::<_, TaskComment>("SELECT * FROM task_comments WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(comment)
+    }
+
+    pub async fn find_response(&self, id: Uuid) -> Result<Option<CommentResponse>> {
+        let comment = sqlx::query_as::<_, CommentResponse>(
+            &format!("{} WHERE c.id = $1", COMMENT_RESPONSE_SELECT)
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(comment)
+    }
+
+    /// Top-level comments of a task, oldest first
+    pub async fn find_threads(&self, task_id: Uuid, limit: i64, offset: i64) -> Result<Vec<CommentResponse>> {
+        let comments = sqlx::query_as::<_, CommentResponse>(
+            &format!(
+                "{} WHERE c.task_id = $1 AND c.parent_comment_id IS NULL
+                 ORDER BY c.created_at ASC, c.id ASC
+                 LIMIT $2 OFFSET $3",
+                COMMENT_RESPONSE_SELECT
+            )
+        )
+        .bind(task_id)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(comments)
+    }
+
+    pub async fn count_threads(&self, task_id: Uuid) -> Result<i64> {
+        let count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM task_comments WHERE task_id = $1 AND parent_comment_id IS NULL"
+        )
+        .bind(task_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(count)
+    }
+
+    /// Direct replies to a comment, oldest first
+    pub async fn find_replies(&self, parent_comment_id: Uuid, limit: i64, offset: i64) -> Result<Vec<CommentResponse>> {
+        let comments = sqlx::query_as::<_, CommentResponse>(
+            &format!(
+                "{} WHERE c.parent_comment_id = $1
+                 ORDER BY c.created_at ASC, c.id ASC
+                 LIMIT $2 OFFSET $3",
+                COMMENT_RESPONSE_SELECT
+            )
+        )
+        .bind(parent_comment_id)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(comments)
+    }
+
+    pub async fn count_replies(&self, parent_comment_id: Uuid) -> Result<i64> {
+        let count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM task_comments WHERE parent_comment_id = $1"
+        )
+        .bind(parent_comment_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(count)
+    }
+
+    pub async fn update_content(&self, id: Uuid, content: &str) -> Result<()> {
+        sqlx::query(
+            "UPDATE task_comments
+             SET content = $2, is_edited = TRUE, updated_at = NOW()
+             WHERE id = $1"
+        )
+        .bind(id)
+        .bind(content)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Blanks the comment but keeps the row so its replies stay in the thread.
+    pub async fn soft_delete(&self, id: Uuid) -> Result<()> {
+        sqlx::query(
+            "UPDATE task_comments
+             SET content = '', deleted_at = NOW(), updated_at = NOW()
+             WHERE id = $1"
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Resolves usernames to the users among them who can see the task.
+    pub async fn find_mentionable_users(&self, task_id: Uuid, usernames: &[String]) -> Result<Vec<(Uuid, String)>> {
+        let users = sqlx::query_as::<_, (Uuid, String)>(
+            "SELECT id, username FROM users
+             WHERE username = ANY($1) AND is_active = TRUE AND user_has_task_access($2, id)"
+        )
+        .bind(usernames)
+        .bind(task_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(users)
+    }
+}
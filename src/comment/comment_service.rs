@@ -0,0 +1,254 @@
+use crate::error::{AppError, Result};
+use crate::notification::NotificationHelper;
+use crate::task::task_events::TaskEvents;
+use crate::task::task_models::{Task, TaskEventKind, TaskPermission};
+use crate::task::task_repository::TaskRepository;
+use crate::task::task_service::TaskService;
+use crate::user::user_repository::UserRepository;
+use crate::websocket::types::{CommentMentionPayload, WsMessage};
+use crate::websocket::ConnectionManager;
+use uuid::Uuid;
+use super::comment_dto::CreateCommentRequest;
+use super::comment_models::{parse_mentions, CommentResponse, TaskComment};
+use super::comment_repository::CommentRepository;
+
+const MENTION_PREVIEW_CHARS: usize = 100;
+
+#[derive(Clone)]
+pub struct CommentService {
+    repo: CommentRepository,
+    task_repo: TaskRepository,
+    task_service: TaskService,
+    user_repo: UserRepository,
+    ws_manager: ConnectionManager,
+    notification_helper: NotificationHelper,
+    events: TaskEvents,
+}
+
+impl CommentService {
+    pub fn new(
+        repo: CommentRepository,
+        task_repo: TaskRepository,
+        task_service: TaskService,
+        user_repo: UserRepository,
+        ws_manager: ConnectionManager,
+        notification_helper: NotificationHelper,
+        events: TaskEvents,
+    ) -> Self {
+        Self {
+            repo,
+            task_repo,
+            task_service,
+            user_repo,
+            ws_manager,
+            notification_helper,
+            events,
+        }
+    }
+
+    /// Posts a comment (or a reply) on a task the user can edit and notifies
+    /// everyone it mentions.
+    pub async fn add_comment(
+        &self,
+        task_id: Uuid,
+        user_id: Uuid,
+        payload: CreateCommentRequest,
+    ) -> Result<CommentResponse> {
+        let task = self.get_editable_task(task_id, user_id).await?;
+
+        if let Some(parent_id) = payload.parent_comment_id {
+            let parent = self.get_task_comment(task_id, parent_id).await?;
+            if parent.deleted_at.is_some() {
+                return Err(AppError::BadRequest("Cannot reply to a deleted comment".to_string()));
+            }
+        }
+
+        let comment = self.repo
+            .create(task_id, user_id, payload.parent_comment_id, &payload.content)
+            .await?;
+
+        let _ = self.task_repo.log_task_activity(
+            task_id,
+            user_id,
+            "comment_added",
+            Some(serde_json::json!({
+                "comment_id": comment.id,
+                "parent_comment_id": comment.parent_comment_id,
+            })),
+        ).await;
+
+        self.notify_mentions(&task, &comment, user_id, &[]).await;
+        self.publish_comment_event(&task, comment.id, user_id, "added").await;
+
+        self.get_comment_response(comment.id).await
+    }
+
+    /// Top-level comments of a task with their reply counts
+    pub async fn list_comments(
+        &self,
+        task_id: Uuid,
+        user_id: Uuid,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<CommentResponse>, i64)> {
+        self.get_accessible_task(task_id, user_id).await?;
+
+        let comments = self.repo.find_threads(task_id, limit, offset).await?;
+        let total = self.repo.count_threads(task_id).await?;
+
+        Ok((comments, total))
+    }
+
+    pub async fn list_replies(
+        &self,
+        task_id: Uuid,
+        comment_id: Uuid,
+        user_id: Uuid,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<CommentResponse>, i64)> {
+        self.get_accessible_task(task_id, user_id).await?;
+        self.get_task_comment(task_id, comment_id).await?;
+
+        let replies = self.repo.find_replies(comment_id, limit, offset).await?;
+        let total = self.repo.count_replies(comment_id).await?;
+
+        Ok((replies, total))
+    }
+
+    /// Only the author can edit a comment. Users mentioned for the first time are notified.
+    pub async fn update_comment(
+        &self,
+        task_id: Uuid,
+        comment_id: Uuid,
+        user_id: Uuid,
+        content: String,
+    ) -> Result<CommentResponse> {
+        let task = self.get_editable_task(task_id, user_id).await?;
+        let comment = self.get_task_comment(task_id, comment_id).await?;
+
+        if comment.deleted_at.is_some() {
+            return Err(AppError::NotFound("Comment not found".to_string()));
+        }
+        if comment.user_id != Some(user_id) {
+            return Err(AppError::Forbidden("You can only edit your own comments".to_string()));
+        }
+
+        self.repo.update_content(comment_id, &content).await?;
+
+        let already_mentioned = parse_mentions(&comment.content);
+        let updated = TaskComment { content, ..comment };
+        self.notify_mentions(&task, &updated, user_id, &already_mentioned).await;
+        self.publish_comment_event(&task, comment_id, user_id, "edited").await;
+
+        self.get_comment_response(comment_id).await
+    }
+
+    /// The author or the task owner can delete a comment. Replies are kept.
+    pub async fn delete_comment(&self, task_id: Uuid, comment_id: Uuid, user_id: Uuid) -> Result<()> {
+        let task = self.get_accessible_task(task_id, user_id).await?;
+        let comment = self.get_task_comment(task_id, comment_id).await?;
+
+        if comment.deleted_at.is_some() {
+            return Err(AppError::NotFound("Comment not found".to_string()));
+        }
+        if comment.user_id != Some(user_id) && task.user_id != user_id {
+            return Err(AppError::Forbidden(
+                "Only the author or the task owner can delete this comment".to_string(),
+            ));
+        }
+
+        self.repo.soft_delete(comment_id).await?;
+        self.publish_comment_event(&task, comment_id, user_id, "deleted").await;
+
+        Ok(())
+    }
+
+    async fn publish_comment_event(&self, task: &Task, comment_id: Uuid, user_id: Uuid, action: &str) {
+        self.events
+            .publish(
+                TaskEventKind::Commented,
+                task,
+                Some(user_id),
+                Some(serde_json::json!({"action": action, "comment_id": comment_id})),
+            )
+            .await;
+    }
+
+    /// Notifies users mentioned in the comment who can see the task,
+    /// skipping the author and anyone in `skip_usernames`.
+    async fn notify_mentions(
+        &self,
+        task: &Task,
+        comment: &TaskComment,
+        author_id: Uuid,
+        skip_usernames: &[String],
+    ) {
+        let usernames: Vec<String> = parse_mentions(&comment.content)
+            .into_iter()
+            .filter(|u| !skip_usernames.contains(u))
+            .collect();
+        if usernames.is_empty() {
+            return;
+        }
+
+        let mentioned = match self.repo.find_mentionable_users(task.id, &usernames).await {
+            Ok(users) => users,
+            Err(e) => {
+                tracing::error!("Failed to resolve mentions for comment {}: {:?}", comment.id, e);
+                return;
+            }
+        };
+
+        let author_username = match self.user_repo.find_by_id(author_id).await {
+            Ok(Some(author)) => author.username,
+            _ => return,
+        };
+
+        let preview: String = comment.content.chars().take(MENTION_PREVIEW_CHARS).collect();
+
+        for (mentioned_id, _) in mentioned.into_iter().filter(|(id, _)| *id != author_id) {
+            let ws_message = WsMessage::CommentMention(CommentMentionPayload {
+                task_id: task.id,
+                task_title: task.title.clone(),
+                comment_id: comment.id,
+                mentioned_by: author_id,
+                mentioned_by_username: author_username.clone(),
+                content_preview: preview.clone(),
+            });
+            self.ws_manager.send_to_user(&mentioned_id, ws_message);
+
+            let _ = self.notification_helper
+                .notify_mentioned_in_comment(mentioned_id, &author_username, &task.title, task.id)
+                .await;
+        }
+    }
+
+    async fn get_accessible_task(&self, task_id: Uuid, user_id: Uuid) -> Result<Task> {
+        self.task_repo
+            .find_by_id_with_access(task_id, user_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Task not found".to_string()))
+    }
+
+    /// Commenting needs the same role as editing the task, so viewers can only read.
+    async fn get_editable_task(&self, task_id: Uuid, user_id: Uuid) -> Result<Task> {
+        self.task_service.require_permission(task_id, user_id, TaskPermission::Edit).await?;
+        self.get_accessible_task(task_id, user_id).await
+    }
+
+    async fn get_task_comment(&self, task_id: Uuid, comment_id: Uuid) -> Result<TaskComment> {
+        self.repo
+            .find_by_id(comment_id)
+            .await?
+            .filter(|c| c.task_id == task_id)
+            .ok_or_else(|| AppError::NotFound("Comment not found".to_string()))
+    }
+
+    async fn get_comment_response(&self, comment_id: Uuid) -> Result<CommentResponse> {
+        self.repo
+            .find_response(comment_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Comment not found".to_string()))
+    }
+}
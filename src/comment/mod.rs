@@ -0,0 +1,6 @@
+// Declare submodules
+pub mod comment_models;
+pub mod comment_dto;
+pub mod comment_repository;
+pub mod comment_service;
+pub mod comment_handlers;
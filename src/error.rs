@@ -1,4 +1,5 @@
 use axum::{
+    extract::rejection::{JsonRejection, QueryRejection},
     http::StatusCode,
     response::{IntoResponse, Response},
     Json,
@@ -31,6 +32,36 @@ pub enum AppError {
 
     #[error("Bad request: {0}")]
     BadRequest(String),
+
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
+    #[error("Payload too large: {0}")]
+    PayloadTooLarge(String),
+
+    #[error("Unsupported media type: {0}")]
+    UnsupportedMediaType(String),
+
+    /// Well-formed JSON with a field of the wrong type or an unknown value
+    #[error("Unprocessable entity: {0}")]
+    UnprocessableEntity(String),
+
+    /// A conditional write lost to a concurrent change; `current` is the
+    /// resource as it is now, so the client can merge and retry.
+    #[error("Precondition failed: {message}")]
+    PreconditionFailed {
+        message: String,
+        current: serde_json::Value,
+    },
+}
+
+impl AppError {
+    pub fn stale<T: serde::Serialize>(what: &str, current: &T) -> Self {
+        AppError::PreconditionFailed {
+            message: format!("The {} was changed by someone else; merge with the current version and retry", what),
+            current: serde_json::to_value(current).unwrap_or_default(),
+        }
+    }
 }
 
 impl From<validator::ValidationErrors> for AppError {
@@ -39,9 +70,37 @@ impl From<validator::ValidationErrors> for AppError {
     }
 }
 
-impl IntoResponse for AppError {
-    fn into_response(self) -> Response {
-        let (status, error_message) = match self {
+impl From<JsonRejection> for AppError {
+    fn from(rejection: JsonRejection) -> Self {
+        match rejection {
+            // e.g. "status: unknown variant `done`, expected one of `Pending`, ..."
+            JsonRejection::JsonDataError(e) => AppError::UnprocessableEntity(e.body_text()),
+            JsonRejection::MissingJsonContentType(e) => AppError::UnsupportedMediaType(e.body_text()),
+            rejection if rejection.status() == StatusCode::PAYLOAD_TOO_LARGE => {
+                AppError::PayloadTooLarge(rejection.body_text())
+            }
+            rejection => AppError::BadRequest(rejection.body_text()),
+        }
+    }
+}
+
+impl From<QueryRejection> for AppError {
+    fn from(rejection: QueryRejection) -> Self {
+        match rejection {
+            // Only an unknown enum value, e.g. `?status=done`, is a 422; a
+            // malformed number, UUID or date stays a plain bad request
+            QueryRejection::FailedToDeserializeQueryString(e) if e.body_text().contains("unknown variant") => {
+                AppError::UnprocessableEntity(e.body_text())
+            }
+            rejection => AppError::BadRequest(rejection.body_text()),
+        }
+    }
+}
+
+impl AppError {
+    /// The status code and the message shown to the client.
+    fn status_and_message(&self) -> (StatusCode, &str) {
+        match self {
             AppError::Database(ref e) => {
                 tracing::error!("Database error: {:?}", e);
                 (StatusCode::INTERNAL_SERVER_ERROR, "Database error occurred")
@@ -55,11 +114,32 @@ AppError::Unauthorized(ref msg) => (StatusCode::UNAUTHORIZED, msg.as_str()),
                 (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error")
             }
             AppError::BadRequest(ref msg) => (StatusCode::BAD_REQUEST, msg.as_str()),
-        };
+            AppError::Conflict(ref msg) => (StatusCode::CONFLICT, msg.as_str()),
+            AppError::PayloadTooLarge(ref msg) => (StatusCode::PAYLOAD_TOO_LARGE, msg.as_str()),
+            AppError::UnsupportedMediaType(ref msg) => (StatusCode::UNSUPPORTED_MEDIA_TYPE, msg.as_str()),
+            AppError::UnprocessableEntity(ref msg) => (StatusCode::UNPROCESSABLE_ENTITY, msg.as_str()),
+            AppError::PreconditionFailed { ref message, .. } => (StatusCode::PRECONDITION_FAILED, message.as_str()),
+        }
+    }
+
+    /// The message the client would see for this error, for reporting it
+    /// alongside other results instead of as the response.
+    pub fn message(&self) -> String {
+        self.status_and_message().1.to_string()
+    }
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        let (status, error_message) = self.status_and_message();
 
-        let body = Json(json!({
+        let mut body = json!({
             "error": error_message,
-        }));
+        });
+        if let AppError::PreconditionFailed { ref current, .. } = self {
+            body["current"] = current.clone();
+        }
+        let body = Json(body);
 
         (status, body).into_response()
     }
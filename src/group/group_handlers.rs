@@ -9,7 +9,7 @@ use validator::Validate;
 
 use crate::{
     error::Result,
-    middleware::AuthUser,
+    middleware::{etag, AppJson, AuthUser, IfMatch},
     state::AppState,
     group::group_dto::{CreateGroupRequest, UpdateGroupRequest, AddGroupMemberRequest},
 };
@@ -33,7 +33,7 @@ use crate::{
 pub async fn create_group(
     State(state): State<AppState>,
     AuthUser(user_id): AuthUser,
-    Json(payload): Json<CreateGroupRequest>,
+    AppJson(payload): AppJson<CreateGroupRequest>,
 ) -> Result<impl IntoResponse> {
     payload.validate()?;
 
@@ -80,7 +80,8 @@ pub async fn list_groups(
         ("group_id" = Uuid, Path, description = "Group ID")
     ),
     responses(
-        (status = 200, description = "Group retrieved successfully", body = GroupResponse),
+        (status = 200, description = "Group retrieved successfully", body = GroupResponse,
+            headers(("ETag" = String, description = "Current version of the group, for If-Match"))),
         (status = 401, description = "Unauthorized"),
         (status = 403, description = "Forbidden - Not a member"),
         (status = 404, description = "Group not found")
@@ -96,7 +97,7 @@ pub async fn get_group(
 ) -> Result<impl IntoResponse> {
     let group = state.group_service.get_group(group_id, user_id).await?;
 
-    Ok((StatusCode::OK, Json(group)))
+    Ok((StatusCode::OK, etag(group.row_version), Json(group)))
 }
 
 /// Update group (creator only)
@@ -105,15 +106,18 @@ pub async fn get_group(
     path = "/api/groups/{group_id}",
     tag = "groups",
     params(
-        ("group_id" = Uuid, Path, description = "Group ID")
+        ("group_id" = Uuid, Path, description = "Group ID"),
+        ("If-Match" = Option<String>, Header, description = "ETag from an earlier read; the update fails if the group changed since")
     ),
     request_body = UpdateGroupRequest,
     responses(
-        (status = 200, description = "Group updated successfully", body = GroupResponse),
+        (status = 200, description = "Group updated successfully", body = GroupResponse,
+            headers(("ETag" = String, description = "New version of the group"))),
         (status = 400, description = "Validation error"),
         (status = 401, description = "Unauthorized"),
         (status = 403, description = "Forbidden - Only creator can update"),
-        (status = 404, description = "Group not found")
+        (status = 404, description = "Group not found"),
+        (status = 412, description = "If-Match is stale; the body carries the current group")
     ),
     security(
         ("bearer_auth" = [])
@@ -123,7 +127,8 @@ pub async fn update_group(
     State(state): State<AppState>,
     AuthUser(user_id): AuthUser,
     Path(group_id): Path<Uuid>,
-    Json(payload): Json<UpdateGroupRequest>,
+    IfMatch(expected_version): IfMatch,
+    AppJson(payload): AppJson<UpdateGroupRequest>,
 ) -> Result<impl IntoResponse> {
     payload.validate()?;
 
@@ -134,10 +139,11 @@ pub async fn update_group(
             payload.name,
             payload.description,
             payload.avatar_url,
+            expected_version,
         )
         .await?;
 
-    Ok((StatusCode::OK, Json(group)))
+    Ok((StatusCode::OK, etag(group.row_version), Json(group)))
 }
 
 /// Delete group (creator only)
@@ -163,7 +169,8 @@ pub async fn delete_group(
     AuthUser(user_id): AuthUser,
     Path(group_id): Path<Uuid>,
 ) -> Result<impl IntoResponse> {
-    state.group_service.delete_group(group_id, user_id).await?;
+    let storage_keys = state.group_service.delete_group(group_id, user_id).await?;
+    state.attachment_service.remove_stored_files(&storage_keys).await;
 
     Ok(StatusCode::NO_CONTENT)
 }
@@ -192,7 +199,7 @@ pub async fn add_group_member(
     State(state): State<AppState>,
     AuthUser(user_id): AuthUser,
     Path(group_id): Path<Uuid>,
-    Json(payload): Json<AddGroupMemberRequest>,
+    AppJson(payload): AppJson<AddGroupMemberRequest>,
 ) -> Result<impl IntoResponse> {
     let _member = state.group_service
         .add_member(group_id, user_id, payload.user_id)
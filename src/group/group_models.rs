@@ -13,6 +13,8 @@ pub struct Group {
     pub avatar_url: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Goes up on every change; served as the group's ETag
+    pub row_version: i32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
@@ -34,6 +36,8 @@ pub struct GroupResponse {
     pub member_count: Option<i64>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Send back in `If-Match` when updating the group
+    pub row_version: i32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -58,6 +62,7 @@ impl From<Group> for GroupResponse {
             member_count: None,
             created_at: group.created_at,
             updated_at: group.updated_at,
+            row_version: group.row_version,
         }
     }
 }
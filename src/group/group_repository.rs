@@ -170,13 +170,15 @@ impl GroupRepository {
         Ok(count)
     }
 
+    /// With an `expected_version`, only updates the group if it is still at that version.
     pub async fn update(
         &self,
         group_id: Uuid,
         name: Option<&str>,
         description: Option<&str>,
         avatar_url: Option<&str>,
-    ) -> Result<Group> {
+        expected_version: Option<i32>,
+    ) -> Result<Option<Group>> {
         let group = sqlx::query_as::<_, Group>(
             "UPDATE groups 
              SET name = COALESCE($1, name),
@@ -184,24 +186,39 @@ impl GroupRepository {
                  avatar_url = COALESCE($3, avatar_url),
                  updated_at = NOW()
              WHERE id = $4 
+             AND ($5::int IS NULL OR row_version = $5)
              RETURNING *"
         )
         .bind(name)
         .bind(description)
         .bind(avatar_url)
         .bind(group_id)
-        .fetch_one(&self.pool)
+        .bind(expected_version)
+        .fetch_optional(&self.pool)
         .await?;
 
         Ok(group)
     }
 
-    pub async fn delete(&self, group_id: Uuid) -> Result<()> {
-        sqlx::query("DELETE FROM groups WHERE id = $1")
-            .bind(group_id)
-            .execute(&self.pool)
-            .await?;
+    /// Deletes the group and, through the cascade, its messages. Returns the
+    /// storage keys of the attachments that went with them; the caller must
+    /// remove the files.
+    pub async fn delete(&self, group_id: Uuid) -> Result<Vec<String>> {
+        let (storage_keys,): (Vec<String>,) = sqlx::query_as(
+            "WITH files AS (
+                SELECT a.storage_key FROM attachments a
+                JOIN messages m ON m.id = a.message_id
+                WHERE m.group_id = $1
+             ),
+             deleted AS (
+                DELETE FROM groups WHERE id = $1 RETURNING id
+             )
+             SELECT ARRAY(SELECT storage_key FROM files)"
+        )
+        .bind(group_id)
+        .fetch_one(&self.pool)
+        .await?;
 
-        Ok(())
+        Ok(storage_keys)
     }
 }
@@ -75,15 +75,20 @@ impl GroupService {
         name: Option<String>,
         description: Option<String>,
         avatar_url: Option<String>,
+        expected_version: Option<i32>,
     ) -> Result<GroupResponse> {
         // Only creator can update group
         if !self.repo.is_creator(group_id, user_id).await? {
             return Err(AppError::Forbidden("Only the group creator can update the group".to_string()));
         }
 
-        let group = self.repo
-            .update(group_id, name.as_deref(), description.as_deref(), avatar_url.as_deref())
-            .await?;
+        let Some(group) = self.repo
+            .update(group_id, name.as_deref(), description.as_deref(), avatar_url.as_deref(), expected_version)
+            .await?
+        else {
+            let current = self.get_group(group_id, user_id).await?;
+            return Err(AppError::stale("group", &current));
+        };
 
         let member_count = self.repo.get_member_count(group_id).await?;
 
@@ -93,15 +98,14 @@ impl GroupService {
         Ok(response)
     }
 
-    pub async fn delete_group(&self, group_id: Uuid, user_id: Uuid) -> Result<()> {
+    /// Returns the storage keys of the group's message attachments, which the caller must remove.
+    pub async fn delete_group(&self, group_id: Uuid, user_id: Uuid) -> Result<Vec<String>> {
         // Only creator can delete group
         if !self.repo.is_creator(group_id, user_id).await? {
             return Err(AppError::Forbidden("Only the group creator can delete the group".to_string()));
         }
 
-        self.repo.delete(group_id).await?;
-
-        Ok(())
+        self.repo.delete(group_id).await
     }
 
     pub async fn add_member(
@@ -0,0 +1,35 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::{Validate, ValidationError};
+
+fn validate_color(color: &str) -> Result<(), ValidationError> {
+    let hex = color.strip_prefix('#').unwrap_or_default();
+    if hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        Ok(())
+    } else {
+        Err(ValidationError::new("hex_color").with_message("must be a hex color like #ff8800".into()))
+    }
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateLabelRequest {
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+    /// Hex color, e.g. "#ff8800" (defaults to grey)
+    #[validate(custom(function = "validate_color"))]
+    pub color: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateLabelRequest {
+    #[validate(length(min = 1, max = 100))]
+    pub name: Option<String>,
+    #[validate(custom(function = "validate_color"))]
+    pub color: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct AddTaskLabelRequest {
+    pub label_id: Uuid,
+}
@@ -0,0 +1,194 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    error::Result,
+    middleware::{AppJson, AuthUser},
+    state::AppState,
+    label::label_dto::{CreateLabelRequest, UpdateLabelRequest, AddTaskLabelRequest},
+};
+
+/// Create a new label
+#[utoipa::path(
+    post,
+    path = "/api/labels",
+    tag = "labels",
+    request_body = CreateLabelRequest,
+    responses(
+        (status = 201, description = "Label created successfully", body = Label),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 409, description = "A label with this name already exists")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create_label(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    AppJson(payload): AppJson<CreateLabelRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+
+    let label = state.label_service
+        .create_label(user_id, payload.name, payload.color)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(label)))
+}
+
+/// Get all labels of the authenticated user
+#[utoipa::path(
+    get,
+    path = "/api/labels",
+    tag = "labels",
+    responses(
+        (status = 200, description = "Labels retrieved successfully", body = Vec<Label>),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_labels(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+) -> Result<impl IntoResponse> {
+    let labels = state.label_service.list_labels(user_id).await?;
+
+    Ok((StatusCode::OK, Json(labels)))
+}
+
+/// Update a label (owner only)
+#[utoipa::path(
+    put,
+    path = "/api/labels/{label_id}",
+    tag = "labels",
+    params(
+        ("label_id" = Uuid, Path, description = "Label ID")
+    ),
+    request_body = UpdateLabelRequest,
+    responses(
+        (status = 200, description = "Label updated successfully", body = Label),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Not the label owner"),
+        (status = 404, description = "Label not found"),
+        (status = 409, description = "A label with this name already exists")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn update_label(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(label_id): Path<Uuid>,
+    AppJson(payload): AppJson<UpdateLabelRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+
+    let label = state.label_service
+        .update_label(label_id, user_id, payload.name, payload.color)
+        .await?;
+
+    Ok((StatusCode::OK, Json(label)))
+}
+
+/// Delete a label (owner only); it is removed from every task
+#[utoipa::path(
+    delete,
+    path = "/api/labels/{label_id}",
+    tag = "labels",
+    params(
+        ("label_id" = Uuid, Path, description = "Label ID")
+    ),
+    responses(
+        (status = 204, description = "Label deleted successfully"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Not the label owner"),
+        (status = 404, description = "Label not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_label(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(label_id): Path<Uuid>,
+) -> Result<impl IntoResponse> {
+    state.label_service.delete_label(label_id, user_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Attach one of your labels to a task
+#[utoipa::path(
+    post,
+    path = "/api/tasks/{task_id}/labels",
+    tag = "labels",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID")
+    ),
+    request_body = AddTaskLabelRequest,
+    responses(
+        (status = 200, description = "Labels now on the task", body = Vec<Label>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - No edit access to the task, or not the label owner"),
+        (status = 404, description = "Label not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn add_task_label(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(task_id): Path<Uuid>,
+    AppJson(payload): AppJson<AddTaskLabelRequest>,
+) -> Result<impl IntoResponse> {
+    let labels = state.label_service
+        .add_label_to_task(task_id, payload.label_id, user_id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(labels)))
+}
+
+/// Remove a label from a task
+#[utoipa::path(
+    delete,
+    path = "/api/tasks/{task_id}/labels/{label_id}",
+    tag = "labels",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID"),
+        ("label_id" = Uuid, Path, description = "Label ID")
+    ),
+    responses(
+        (status = 204, description = "Label removed from task"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - No edit access to the task"),
+        (status = 404, description = "Label not attached to the task")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn remove_task_label(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path((task_id, label_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse> {
+    state.label_service
+        .remove_label_from_task(task_id, label_id, user_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct Label {
+    pub id: Uuid,
+    /// Owner of the label; only they can rename, recolor or delete it
+    pub user_id: Uuid,
+    pub name: String,
+    /// Hex color, e.g. "#ff8800"
+    pub color: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A label together with the task it is attached to.
+#[derive(Debug, Clone, FromRow)]
+pub struct TaskLabel {
+    pub task_id: Uuid,
+    #[sqlx(flatten)]
+    pub label: Label,
+}
@@ -0,0 +1,135 @@
+use crate::error::Result;
+use sqlx::PgPool;
+use std::collections::HashMap;
+use uuid::Uuid;
+use super::label_models::{Label, TaskLabel};
+
+#[derive(Clone)]
+pub struct LabelRepository {
+    pool: PgPool,
+}
+
+impl LabelRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn create(&self, user_id: Uuid, name: &str, color: Option<&str>) -> Result<Label> {
+        let label = sqlx::query_as::<_, Label>(
+            "INSERT INTO labels (user_id, name, color)
+             VALUES ($1, $2, COALESCE($3, '#808080'))
+             RETURNING *"
+        )
+        .bind(user_id)
+        .bind(name)
+        .bind(color)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(label)
+    }
+
+    pub async fn find_by_id(&self, id: Uuid) -> Result<Option<Label>> {
+        let label = sqlx::query_as::<_, Label>("SELECT * FROM labels WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(label)
+    }
+
+    pub async fn find_by_name(&self, user_id: Uuid, name: &str) -> Result<Option<Label>> {
+        let label = sqlx::query_as::<_, Label>(
+            "SELECT * FROM labels WHERE user_id = $1 AND name = $2"
+        )
+        .bind(user_id)
+        .bind(name)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(label)
+    }
+
+    pub async fn find_user_labels(&self, user_id: Uuid) -> Result<Vec<Label>> {
+        let labels = sqlx::query_as::<_, Label>(
+            "SELECT * FROM labels WHERE user_id = $1 ORDER BY name ASC"
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(labels)
+    }
+
+    pub async fn update(&self, id: Uuid, name: Option<&str>, color: Option<&str>) -> Result<Label> {
+        let label = sqlx::query_as::<_, Label>(
+            "UPDATE labels SET
+                name = COALESCE($1, name),
+                color = COALESCE($2, color),
+                updated_at = NOW()
+             WHERE id = $3
+             RETURNING *"
+        )
+        .bind(name)
+        .bind(color)
+        .bind(id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(label)
+    }
+
+    pub async fn delete(&self, id: Uuid) -> Result<()> {
+        sqlx::query("DELETE FROM labels WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn add_to_task(&self, task_id: Uuid, label_id: Uuid, added_by: Uuid) -> Result<()> {
+        sqlx::query(
+            "INSERT INTO task_labels (task_id, label_id, added_by)
+             VALUES ($1, $2, $3)
+             ON CONFLICT (task_id, label_id) DO NOTHING"
+        )
+        .bind(task_id)
+        .bind(label_id)
+        .bind(added_by)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_from_task(&self, task_id: Uuid, label_id: Uuid) -> Result<u64> {
+        let result = sqlx::query("DELETE FROM task_labels WHERE task_id = $1 AND label_id = $2")
+            .bind(task_id)
+            .bind(label_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Labels attached to each of `task_ids`, whoever owns them.
+    pub async fn find_for_tasks(&self, task_ids: &[Uuid]) -> Result<HashMap<Uuid, Vec<Label>>> {
+        let rows = sqlx::query_as::<_, TaskLabel>(
+            "SELECT tl.task_id, l.* FROM task_labels tl
+             JOIN labels l ON l.id = tl.label_id
+             WHERE tl.task_id = ANY($1)
+             ORDER BY l.name ASC"
+        )
+        .bind(task_ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut labels: HashMap<Uuid, Vec<Label>> = HashMap::new();
+        for row in rows {
+            labels.entry(row.task_id).or_default().push(row.label);
+        }
+
+        Ok(labels)
+    }
+}
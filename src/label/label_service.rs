@@ -0,0 +1,129 @@
+use crate::error::{AppError, Result};
+use crate::task::task_models::{TaskEventKind, TaskPermission};
+use crate::task::task_repository::TaskRepository;
+use crate::task::task_service::TaskService;
+use uuid::Uuid;
+use super::label_models::Label;
+use super::label_repository::LabelRepository;
+
+#[derive(Clone)]
+pub struct LabelService {
+    repo: LabelRepository,
+    task_repo: TaskRepository,
+    task_service: TaskService,
+}
+
+impl LabelService {
+    pub fn new(repo: LabelRepository, task_repo: TaskRepository, task_service: TaskService) -> Self {
+        Self { repo, task_repo, task_service }
+    }
+
+    pub async fn create_label(&self, user_id: Uuid, name: String, color: Option<String>) -> Result<Label> {
+        if self.repo.find_by_name(user_id, &name).await?.is_some() {
+            return Err(AppError::Conflict(format!("You already have a label named '{}'", name)));
+        }
+
+        self.repo.create(user_id, &name, color.as_deref()).await
+    }
+
+    pub async fn list_labels(&self, user_id: Uuid) -> Result<Vec<Label>> {
+        self.repo.find_user_labels(user_id).await
+    }
+
+    pub async fn update_label(
+        &self,
+        label_id: Uuid,
+        user_id: Uuid,
+        name: Option<String>,
+        color: Option<String>,
+    ) -> Result<Label> {
+        self.get_owned_label(label_id, user_id).await?;
+
+        if let Some(name) = &name {
+            if let Some(existing) = self.repo.find_by_name(user_id, name).await? {
+                if existing.id != label_id {
+                    return Err(AppError::Conflict(format!("You already have a label named '{}'", name)));
+                }
+            }
+        }
+
+        self.repo.update(label_id, name.as_deref(), color.as_deref()).await
+    }
+
+    pub async fn delete_label(&self, label_id: Uuid, user_id: Uuid) -> Result<()> {
+        self.get_owned_label(label_id, user_id).await?;
+        self.repo.delete(label_id).await
+    }
+
+    /// Attaches one of the user's own labels to a task they can edit.
+    /// Returns the task's labels afterwards.
+    pub async fn add_label_to_task(&self, task_id: Uuid, label_id: Uuid, user_id: Uuid) -> Result<Vec<Label>> {
+        self.task_service.require_permission(task_id, user_id, TaskPermission::Edit).await?;
+        let label = self.get_owned_label(label_id, user_id).await?;
+
+        self.repo.add_to_task(task_id, label.id, user_id).await?;
+
+        let _ = self.task_repo.log_task_activity(
+            task_id,
+            user_id,
+            "label_added",
+            Some(serde_json::json!({"label_id": label.id, "name": label.name})),
+        ).await;
+
+        self.task_service
+            .publish_change(
+                TaskEventKind::LabelsChanged,
+                task_id,
+                user_id,
+                serde_json::json!({"action": "added", "label_id": label.id}),
+            )
+            .await;
+
+        self.get_task_labels(task_id).await
+    }
+
+    /// Any task editor can take a label off the task, whoever owns the label.
+    pub async fn remove_label_from_task(&self, task_id: Uuid, label_id: Uuid, user_id: Uuid) -> Result<()> {
+        self.task_service.require_permission(task_id, user_id, TaskPermission::Edit).await?;
+
+        if self.repo.remove_from_task(task_id, label_id).await? == 0 {
+            return Err(AppError::NotFound("Label is not attached to this task".to_string()));
+        }
+
+        let _ = self.task_repo.log_task_activity(
+            task_id,
+            user_id,
+            "label_removed",
+            Some(serde_json::json!({"label_id": label_id})),
+        ).await;
+
+        self.task_service
+            .publish_change(
+                TaskEventKind::LabelsChanged,
+                task_id,
+                user_id,
+                serde_json::json!({"action": "removed", "label_id": label_id}),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    async fn get_task_labels(&self, task_id: Uuid) -> Result<Vec<Label>> {
+        let mut labels = self.repo.find_for_tasks(&[task_id]).await?;
+        Ok(labels.remove(&task_id).unwrap_or_default())
+    }
+
+    async fn get_owned_label(&self, label_id: Uuid, user_id: Uuid) -> Result<Label> {
+        let label = self.repo
+            .find_by_id(label_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Label not found".to_string()))?;
+
+        if label.user_id != user_id {
+            return Err(AppError::Forbidden("You can only use your own labels".to_string()));
+        }
+
+        Ok(label)
+    }
+}
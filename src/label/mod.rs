@@ -0,0 +1,6 @@
+// Declare submodules
+pub mod label_models;
+pub mod label_dto;
+pub mod label_repository;
+pub mod label_service;
+pub mod label_handlers;
@@ -1,14 +1,27 @@
 mod admin;
+mod attachment;
 mod auth;
+mod calendar;
+mod checklist;
+mod comment;
 mod db;
 mod error;
 mod group;
+mod label;
 mod message;
 mod middleware;
 mod notification;
+mod pagination;
+mod project;
 mod routes;
+mod saved_view;
+mod search;
+mod share_link;
 mod state;
 mod task;
+mod template;
+mod time_entry;
+mod transfer;
 mod user;
 mod video_call;
 mod websocket;
@@ -80,9 +93,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Create notification broadcaster
     let (notification_tx, _) = broadcast::channel(100);
     
-    // Create task broadcaster
-    let (task_tx, _) = broadcast::channel(100);
-
     // Create WebSocket connection manager
     let ws_connections = crate::websocket::ConnectionManager::new();
 
@@ -124,14 +134,41 @@ tracing::info!("Using Redis URL: {}", redis_url);
     let admin_repository = crate::admin::repository::AdminRepository::new(db.clone());
     let group_repository = crate::group::group_repository::GroupRepository::new(db.clone());
     let video_call_repository = crate::video_call::video_call_repository::VideoCallRepository::new(db.clone());
+    let label_repository = crate::label::label_repository::LabelRepository::new(db.clone());
+    let project_repository = crate::project::project_repository::ProjectRepository::new(db.clone());
+    let comment_repository = crate::comment::comment_repository::CommentRepository::new(db.clone());
+    let attachment_repository = crate::attachment::attachment_repository::AttachmentRepository::new(db.clone());
+    let time_entry_repository = crate::time_entry::time_entry_repository::TimeEntryRepository::new(db.clone());
+    let search_repository = crate::search::search_repository::SearchRepository::new(db.clone());
+    let checklist_repository = crate::checklist::checklist_repository::ChecklistRepository::new(db.clone());
+    let share_link_repository = crate::share_link::share_link_repository::ShareLinkRepository::new(db.clone());
+    let template_repository = crate::template::template_repository::TemplateRepository::new(db.clone());
+    let saved_view_repository = crate::saved_view::saved_view_repository::SavedViewRepository::new(db.clone());
+    let calendar_repository = crate::calendar::calendar_repository::CalendarRepository::new(db.clone());
+    let transfer_repository = crate::transfer::transfer_repository::TransferRepository::new(db.clone());
+
+    // Create attachment storage backend (local filesystem or S3-compatible)
+    let attachment_storage = crate::attachment::storage::storage_from_env();
 
     // Create services
     let user_service = crate::user::user_service::UserService::new(
         user_repository.clone(),
         task_repository.clone(),
+        time_entry_repository.clone(),
     );
     let notification_helper = crate::notification::notification_helper::NotificationHelper::new(notification_repository.clone());
-    let task_service = crate::task::task_service::TaskService::new(task_repository.clone(), notification_helper.clone());
+    let task_events = crate::task::task_events::TaskEvents::new(task_repository.clone(), ws_connections.clone());
+    let task_service = crate::task::task_service::TaskService::new(
+        task_repository.clone(),
+        label_repository.clone(),
+        checklist_repository.clone(),
+        project_repository.clone(),
+        user_repository.clone(),
+        ws_connections.clone(),
+        notification_helper.clone(),
+        task_events.clone(),
+        config.trash_retention_days,
+    );
     let auth_service = crate::auth::auth_service::AuthService::new(
         db.clone(),
         user_repository.clone(),
@@ -153,6 +190,79 @@ tracing::info!("Using Redis URL: {}", redis_url);
         group_repository.clone(),
     );
     let admin_service = crate::admin::service::AdminService::new(admin_repository.clone());
+    let label_service = crate::label::label_service::LabelService::new(
+        label_repository.clone(),
+        task_repository.clone(),
+        task_service.clone(),
+    );
+    let project_service = crate::project::project_service::ProjectService::new(
+        project_repository.clone(),
+        task_repository.clone(),
+        task_service.clone(),
+    );
+    let comment_service = crate::comment::comment_service::CommentService::new(
+        comment_repository.clone(),
+        task_repository.clone(),
+        task_service.clone(),
+        user_repository.clone(),
+        ws_connections.clone(),
+        notification_helper.clone(),
+        task_events.clone(),
+    );
+    let attachment_service = crate::attachment::attachment_service::AttachmentService::new(
+        attachment_repository.clone(),
+        task_repository.clone(),
+        message_repository.clone(),
+        group_repository.clone(),
+        attachment_storage,
+        crate::attachment::attachment_service::AttachmentLimits::from_env(),
+    );
+    let time_entry_service = crate::time_entry::time_entry_service::TimeEntryService::new(
+        time_entry_repository.clone(),
+        task_repository.clone(),
+        task_service.clone(),
+    );
+    let search_service = crate::search::search_service::SearchService::new(search_repository.clone());
+    let checklist_service = crate::checklist::checklist_service::ChecklistService::new(
+        checklist_repository.clone(),
+        task_repository.clone(),
+        task_service.clone(),
+        user_repository.clone(),
+        ws_connections.clone(),
+    );
+    let share_link_service = crate::share_link::share_link_service::ShareLinkService::new(
+        share_link_repository.clone(),
+        task_repository.clone(),
+        task_service.clone(),
+        comment_repository.clone(),
+        task_events.clone(),
+        &config.jwt_secret,
+    );
+    let template_service = crate::template::template_service::TemplateService::new(
+        template_repository.clone(),
+        task_repository.clone(),
+        task_service.clone(),
+        checklist_repository.clone(),
+        group_repository.clone(),
+        user_repository.clone(),
+    );
+    let saved_view_service = crate::saved_view::saved_view_service::SavedViewService::new(
+        saved_view_repository.clone(),
+        group_repository.clone(),
+    );
+    let calendar_service = crate::calendar::calendar_service::CalendarService::new(
+        calendar_repository.clone(),
+        &config.jwt_secret,
+    );
+    let transfer_service = crate::transfer::transfer_service::TransferService::new(
+        transfer_repository.clone(),
+        task_repository.clone(),
+        task_service.clone(),
+        label_repository.clone(),
+        project_repository.clone(),
+        checklist_repository.clone(),
+        comment_repository.clone(),
+    );
 
     // Create application state
     let state = AppState {
@@ -160,7 +270,7 @@ tracing::info!("Using Redis URL: {}", redis_url);
         config: config.clone(),
         oauth_client,
         notification_tx: notification_tx.clone(),
-        task_tx: task_tx.clone(),
+        task_events,
         ws_connections,
         redis_client,
         refresh_token_repository,
@@ -178,9 +288,40 @@ tracing::info!("Using Redis URL: {}", redis_url);
         group_service,
         video_call_repository,
         video_call_service,
+        label_repository,
+        label_service,
+        project_repository,
+        project_service,
+        comment_repository,
+        comment_service,
+        attachment_repository,
+        attachment_service,
+        time_entry_repository,
+        time_entry_service,
+        search_repository,
+        search_service,
+        checklist_repository,
+        checklist_service,
+        share_link_repository,
+        share_link_service,
+        template_repository,
+        template_service,
+        saved_view_repository,
+        saved_view_service,
+        calendar_repository,
+        calendar_service,
+        transfer_repository,
+        transfer_service,
         notification_helper,
     };
 
+    // Imports that were running when the server last stopped won't resume
+    match state.transfer_service.fail_interrupted_jobs().await {
+        Ok(0) => {}
+        Ok(count) => tracing::warn!("Marked {} interrupted import job(s) as failed", count),
+        Err(e) => tracing::error!("Could not clean up interrupted import jobs: {:?}", e),
+    }
+
     // Start notification service
     let notification_state = state.clone();
     tokio::spawn(async move {
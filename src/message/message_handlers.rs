@@ -1,7 +1,7 @@
 use axum::{
     extract::{Path, Query, State},
     http::StatusCode,
-    response::IntoResponse,
+    response::{IntoResponse, Response},
     Json,
 };
 use serde::Deserialize;
@@ -10,7 +10,8 @@ use validator::Validate;
 
 use crate::{
     error::{AppError, Result},
-    middleware::AuthUser,
+    middleware::{etag, AppJson, AuthUser, IfMatch},
+    pagination::{Cursor, CursorParams, MAX_CURSOR_LIMIT},
     state::AppState,
     task::task_dto::PaginatedResponse,
     message::{
@@ -25,6 +26,15 @@ pub struct MessageQuery {
     limit: Option<u32>,
 }
 
+/// Cursor after the last message of a page, so clients can switch from
+/// pages to cursors.
+fn next_cursor(messages: &[MessageResponse], page: u32, total_pages: u32) -> Option<String> {
+    messages
+        .last()
+        .filter(|_| page < total_pages)
+        .map(|m| Cursor::new(m.created_at, m.id).encode())
+}
+
 /// Send a message to another user
 #[utoipa::path(
     post,
@@ -44,7 +54,7 @@ pub struct MessageQuery {
 pub async fn send_message(
     State(state): State<AppState>,
     AuthUser(user_id): AuthUser,
-    Json(payload): Json<SendMessageRequest>,
+    AppJson(payload): AppJson<SendMessageRequest>,
 ) -> Result<impl IntoResponse> {
     payload.validate()?;
 
@@ -79,10 +89,13 @@ pub async fn send_message(
     params(
         ("user_id" = Uuid, Path, description = "Other user ID to get conversation with"),
         ("page" = Option<u32>, Query, description = "Page number (default: 1)"),
-        ("limit" = Option<u32>, Query, description = "Items per page (default: 50)")
+        ("limit" = Option<u32>, Query, description = "Items per page (default: 50)"),
+        ("after" = Option<String>, Query, description = "Cursor: older messages than this page"),
+        ("before" = Option<String>, Query, description = "Cursor: newer messages than this page")
     ),
     responses(
-        (status = 200, description = "Paginated conversation messages", body = PaginatedResponse<MessageResponse>),
+        (status = 200, description = "Paginated conversation messages, newest first; a `CursorPage<MessageResponse>` when `after` or `before` is given", body = PaginatedResponse<MessageResponse>),
+        (status = 400, description = "Invalid cursor"),
         (status = 401, description = "Unauthorized"),
         (status = 404, description = "User not found")
     ),
@@ -95,7 +108,23 @@ pub async fn get_conversation(
     AuthUser(user_id): AuthUser,
     Path(other_user_id): Path<Uuid>,
     Query(query): Query<MessageQuery>,
-) -> Result<impl IntoResponse> {
+    Query(cursor): Query<CursorParams>,
+) -> Result<Response> {
+    if let Some(position) = cursor.position()? {
+        let limit = query.limit.unwrap_or(50).clamp(1, MAX_CURSOR_LIMIT);
+        let page = state
+            .message_service
+            .get_conversation_page(user_id, other_user_id, Some(&position), limit)
+            .await?;
+
+        let _ = state
+            .message_service
+            .mark_conversation_as_read(user_id, other_user_id)
+            .await;
+
+        return Ok((StatusCode::OK, Json(page)).into_response());
+    }
+
     let page = query.page.unwrap_or(1);
     let limit = query.limit.unwrap_or(50);
     let offset = ((page - 1) * limit) as i64;
@@ -119,6 +148,7 @@ pub async fn get_conversation(
     let total_pages = ((total as f64) / (limit as f64)).ceil() as u32;
 
     let response = PaginatedResponse {
+        next_cursor: next_cursor(&message_responses, page, total_pages),
         data: message_responses,
         total,
         page,
@@ -126,7 +156,7 @@ pub async fn get_conversation(
         total_pages,
     };
 
-    Ok((StatusCode::OK, Json(response)))
+    Ok((StatusCode::OK, Json(response)).into_response())
 }
 
 /// Get all conversations for the authenticated user (1-on-1 only)
@@ -162,10 +192,13 @@ pub async fn get_conversations(
     params(
         ("group_id" = Uuid, Path, description = "Group ID to get messages from"),
         ("page" = Option<u32>, Query, description = "Page number (default: 1)"),
-        ("limit" = Option<u32>, Query, description = "Items per page (default: 50)")
+        ("limit" = Option<u32>, Query, description = "Items per page (default: 50)"),
+        ("after" = Option<String>, Query, description = "Cursor: older messages than this page"),
+        ("before" = Option<String>, Query, description = "Cursor: newer messages than this page")
     ),
     responses(
-        (status = 200, description = "Paginated group messages", body = PaginatedResponse<MessageResponse>),
+        (status = 200, description = "Paginated group messages, newest first; a `CursorPage<MessageResponse>` when `after` or `before` is given", body = PaginatedResponse<MessageResponse>),
+        (status = 400, description = "Invalid cursor"),
         (status = 401, description = "Unauthorized"),
         (status = 403, description = "Forbidden - Not a member"),
         (status = 404, description = "Group not found")
@@ -179,10 +212,26 @@ pub async fn get_group_messages(
     AuthUser(user_id): AuthUser,
     Path(group_id): Path<Uuid>,
     Query(query): Query<MessageQuery>,
-) -> Result<impl IntoResponse> {
+    Query(cursor): Query<CursorParams>,
+) -> Result<Response> {
     // Verify user is a member of the group
     state.group_service.verify_membership(group_id, user_id).await?;
 
+    if let Some(position) = cursor.position()? {
+        let limit = query.limit.unwrap_or(50).clamp(1, MAX_CURSOR_LIMIT);
+        let page = state
+            .message_service
+            .get_group_messages_page(group_id, Some(&position), limit)
+            .await?;
+
+        let _ = state
+            .message_service
+            .mark_group_messages_as_read(user_id, group_id)
+            .await;
+
+        return Ok((StatusCode::OK, Json(page)).into_response());
+    }
+
     let page = query.page.unwrap_or(1);
     let limit = query.limit.unwrap_or(50);
     let offset = ((page - 1) * limit) as i64;
@@ -206,6 +255,7 @@ pub async fn get_group_messages(
     let total_pages = ((total as f64) / (limit as f64)).ceil() as u32;
 
     let response = PaginatedResponse {
+        next_cursor: next_cursor(&message_responses, page, total_pages),
         data: message_responses,
         total,
         page,
@@ -213,7 +263,7 @@ pub async fn get_group_messages(
         total_pages,
     };
 
-    Ok((StatusCode::OK, Json(response)))
+    Ok((StatusCode::OK, Json(response)).into_response())
 }
 
 /// Mark a message as read
@@ -252,15 +302,18 @@ pub async fn mark_message_read(
     path = "/api/messages/{id}",
     tag = "messages",
     params(
-        ("id" = Uuid, Path, description = "Message ID to update")
+        ("id" = Uuid, Path, description = "Message ID to update"),
+        ("If-Match" = Option<String>, Header, description = "ETag or row_version from an earlier read; the edit fails if the message changed since")
     ),
     request_body = UpdateMessageRequest,
     responses(
-        (status = 200, description = "Message updated successfully", body = MessageResponse),
+        (status = 200, description = "Message updated successfully", body = MessageResponse,
+            headers(("ETag" = String, description = "New version of the message"))),
         (status = 400, description = "Validation error"),
         (status = 401, description = "Unauthorized"),
         (status = 403, description = "Forbidden - Not the message sender"),
-        (status = 404, description = "Message not found")
+        (status = 404, description = "Message not found"),
+        (status = 412, description = "If-Match is stale; the body carries the current message")
     ),
     security(
         ("bearer_auth" = [])
@@ -270,16 +323,17 @@ pub async fn update_message(
     State(state): State<AppState>,
     AuthUser(user_id): AuthUser,
     Path(message_id): Path<Uuid>,
-    Json(payload): Json<UpdateMessageRequest>,
+    IfMatch(expected_version): IfMatch,
+    AppJson(payload): AppJson<UpdateMessageRequest>,
 ) -> Result<impl IntoResponse> {
     payload.validate()?;
 
     let message = state
         .message_service
-        .update_message(message_id, user_id, payload.content, payload.image_url)
+        .update_message(message_id, user_id, payload.content, payload.image_url, expected_version)
         .await?;
 
-    Ok((StatusCode::OK, Json(MessageResponse::from(message))))
+    Ok((StatusCode::OK, etag(message.row_version), Json(MessageResponse::from(message))))
 }
 
 /// Delete a message (sender only)
@@ -305,10 +359,11 @@ pub async fn delete_message(
     AuthUser(user_id): AuthUser,
     Path(message_id): Path<Uuid>,
 ) -> Result<impl IntoResponse> {
-    state
+    let storage_keys = state
         .message_service
         .delete_message(message_id, user_id)
         .await?;
+    state.attachment_service.remove_stored_files(&storage_keys).await;
 
     Ok(StatusCode::NO_CONTENT)
 }
@@ -15,6 +15,8 @@ pub struct Message {
     pub is_read: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Goes up when the content is edited; served as the message's ETag
+    pub row_version: i32,
 }
 
 #[derive(Clone,Debug, Serialize, Deserialize, ToSchema)]
@@ -28,6 +30,8 @@ pub struct MessageResponse {
     pub is_read: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Send back in `If-Match` when editing the message
+    pub row_version: i32,
 }
 
 impl From<Message> for MessageResponse {
@@ -42,6 +46,7 @@ impl From<Message> for MessageResponse {
             is_read: message.is_read,
             created_at: message.created_at,
             updated_at: message.updated_at,
+            row_version: message.row_version,
         }
     }
 }
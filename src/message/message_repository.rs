@@ -1,6 +1,7 @@
 use crate::{
     error::Result,
     message::{message_dto::ConversationUser, message_models::Message},
+    pagination::{keyset_sql, CursorPosition},
 };
 use sqlx::PgPool;
 use uuid::Uuid;
@@ -39,6 +40,15 @@ impl MessageRepository {
         Ok(message)
     }
 
+    pub async fn find_by_id(&self, message_id: Uuid) -> Result<Option<Message>> {
+        let message = sqlx::query_as::<_, Message>("SELECT * FROM messages WHERE id = $1")
+            .bind(message_id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(message)
+    }
+
     pub async fn find_conversation(
         &self,
         user_id: Uuid,
@@ -51,7 +61,7 @@ impl MessageRepository {
              WHERE ((sender_id = $1 AND receiver_id = $2)
                 OR (sender_id = $2 AND receiver_id = $1))
              AND group_id IS NULL
-             ORDER BY created_at DESC
+             ORDER BY created_at DESC, id DESC
              LIMIT $3 OFFSET $4",
         )
         .bind(user_id)
@@ -73,7 +83,7 @@ impl MessageRepository {
         let messages = sqlx::query_as::<_, Message>(
             "SELECT * FROM messages
              WHERE group_id = $1
-             ORDER BY created_at DESC
+             ORDER BY created_at DESC, id DESC
              LIMIT $2 OFFSET $3",
         )
         .bind(group_id)
@@ -85,6 +95,62 @@ impl MessageRepository {
         Ok(messages)
     }
 
+    /// Newest-first conversation messages next to a cursor. Reads up to
+    /// `limit + 1` rows; see [`CursorPage::from_rows`](crate::pagination::CursorPage::from_rows).
+    pub async fn find_conversation_page(
+        &self,
+        user_id: Uuid,
+        other_user_id: Uuid,
+        position: Option<&CursorPosition>,
+        limit: u32,
+    ) -> Result<Vec<Message>> {
+        let (keyset, order) = keyset_sql("m", position, true, 3);
+        let query = format!(
+            "SELECT m.* FROM messages m
+             WHERE ((m.sender_id = $1 AND m.receiver_id = $2)
+                OR (m.sender_id = $2 AND m.receiver_id = $1))
+             AND m.group_id IS NULL{}{}
+             LIMIT {}",
+            keyset,
+            order,
+            limit + 1
+        );
+
+        let mut db_query = sqlx::query_as::<_, Message>(&query).bind(user_id).bind(other_user_id);
+        if let Some(position) = position {
+            let cursor = position.cursor();
+            db_query = db_query.bind(cursor.created_at).bind(cursor.id);
+        }
+
+        Ok(db_query.fetch_all(&self.pool).await?)
+    }
+
+    /// Newest-first group messages next to a cursor. Reads up to `limit + 1` rows.
+    pub async fn find_group_messages_page(
+        &self,
+        group_id: Uuid,
+        position: Option<&CursorPosition>,
+        limit: u32,
+    ) -> Result<Vec<Message>> {
+        let (keyset, order) = keyset_sql("m", position, true, 2);
+        let query = format!(
+            "SELECT m.* FROM messages m
+             WHERE m.group_id = $1{}{}
+             LIMIT {}",
+            keyset,
+            order,
+            limit + 1
+        );
+
+        let mut db_query = sqlx::query_as::<_, Message>(&query).bind(group_id);
+        if let Some(position) = position {
+            let cursor = position.cursor();
+            db_query = db_query.bind(cursor.created_at).bind(cursor.id);
+        }
+
+        Ok(db_query.fetch_all(&self.pool).await?)
+    }
+
     pub async fn count_group_messages(&self, group_id: Uuid) -> Result<i64> {
         let count: i64 = sqlx::query_scalar(
             "SELECT COUNT(*) FROM messages WHERE group_id = $1",
@@ -229,44 +295,57 @@ impl MessageRepository {
 
 
 
+    /// With an `expected_version`, only updates the message if it is still at
+    /// that version; returns `None` if nothing was updated.
     pub async fn update(
         &self,
         message_id: Uuid,
         sender_id: Uuid,
         content: &str,
         image_url: Option<&str>,
-    ) -> Result<Message> {
+        expected_version: Option<i32>,
+    ) -> Result<Option<Message>> {
         let message = sqlx::query_as::<_, Message>(
             "UPDATE messages 
              SET content = $1, image_url = $2, updated_at = NOW()
              WHERE id = $3 AND sender_id = $4
+             AND ($5::int IS NULL OR row_version = $5)
              RETURNING *"
         )
         .bind(content)
         .bind(image_url)
         .bind(message_id)
         .bind(sender_id)
+        .bind(expected_version)
         .fetch_optional(&self.pool)
-        .await?
-        .ok_or(crate::error::AppError::NotFound("Message not found or you are not the sender".to_string()))?;
+        .await?;
 
         Ok(message)
     }
 
-    pub async fn delete(&self, message_id: Uuid, sender_id: Uuid) -> Result<()> {
-        let result = sqlx::query(
-            "DELETE FROM messages 
-             WHERE id = $1 AND sender_id = $2"
+    /// Deletes the message. Returns the storage keys of its attachments, whose
+    /// rows go with it; the caller must remove the files.
+    pub async fn delete(&self, message_id: Uuid, sender_id: Uuid) -> Result<Vec<String>> {
+        let (deleted, storage_keys): (bool, Vec<String>) = sqlx::query_as(
+            "WITH files AS (
+                SELECT storage_key FROM attachments WHERE message_id = $1
+             ),
+             deleted AS (
+                DELETE FROM messages
+                WHERE id = $1 AND sender_id = $2
+                RETURNING id
+             )
+             SELECT EXISTS (SELECT 1 FROM deleted), ARRAY(SELECT storage_key FROM files)"
         )
         .bind(message_id)
         .bind(sender_id)
-        .execute(&self.pool)
+        .fetch_one(&self.pool)
         .await?;
 
-        if result.rows_affected() == 0 {
+        if !deleted {
             return Err(crate::error::AppError::NotFound("Message not found or you are not the sender".to_string()));
         }
 
-        Ok(())
+        Ok(storage_keys)
     }
 }
@@ -1,12 +1,13 @@
-use crate::error::Result;
+use crate::error::{AppError, Result};
 use crate::message::message_repository::MessageRepository;
-use crate::message::message_models::Message;
+use crate::message::message_models::{Message, MessageResponse};
 use crate::message::message_dto::SendMessageRequest;
 use crate::websocket::ConnectionManager;
 
 use crate::websocket::types::{WsMessage, ChatMessagePayload};
 use crate::group::group_service::GroupService;
 use crate::notification::NotificationHelper;
+use crate::pagination::{Cursor, CursorPage, CursorPosition};
 use uuid::Uuid;
 
 #[derive(Clone)]
@@ -132,6 +133,16 @@ impl MessageService {
         Ok((messages, total))
     }
 
+    pub async fn get_group_messages_page(
+        &self,
+        group_id: Uuid,
+        position: Option<&CursorPosition>,
+        limit: u32,
+    ) -> Result<CursorPage<MessageResponse>> {
+        let messages = self.repo.find_group_messages_page(group_id, position, limit).await?;
+        Ok(to_cursor_page(messages, limit, position))
+    }
+
     #[allow(dead_code)]
     pub async fn get_group_messages(
         &self,
@@ -156,15 +167,29 @@ impl MessageService {
         sender_id: Uuid,
         content: String,
         image_url: Option<String>,
+        expected_version: Option<i32>,
     ) -> Result<Message> {
-        self.repo.update(message_id, sender_id, &content, image_url.as_deref()).await
+        if let Some(message) = self.repo
+            .update(message_id, sender_id, &content, image_url.as_deref(), expected_version)
+            .await?
+        {
+            return Ok(message);
+        }
+
+        match self.repo.find_by_id(message_id).await? {
+            Some(current) if current.sender_id == sender_id => {
+                Err(AppError::stale("message", &MessageResponse::from(current)))
+            }
+            _ => Err(AppError::NotFound("Message not found or you are not the sender".to_string())),
+        }
     }
 
+    /// Returns the storage keys of the message's attachments, which the caller must remove.
     pub async fn delete_message(
         &self,
         message_id: Uuid,
         sender_id: Uuid,
-    ) -> Result<()> {
+    ) -> Result<Vec<String>> {
         self.repo.delete(message_id, sender_id).await
     }
 
@@ -180,6 +205,17 @@ impl MessageService {
         Ok((messages, total))
     }
 
+    pub async fn get_conversation_page(
+        &self,
+        user_id: Uuid,
+        other_user_id: Uuid,
+        position: Option<&CursorPosition>,
+        limit: u32,
+    ) -> Result<CursorPage<MessageResponse>> {
+        let messages = self.repo.find_conversation_page(user_id, other_user_id, position, limit).await?;
+        Ok(to_cursor_page(messages, limit, position))
+    }
+
     #[allow(dead_code)]
     pub async fn get_conversation(
         &self,
@@ -203,3 +239,8 @@ impl MessageService {
         self.repo.mark_conversation_as_read(user_id, other_user_id).await
     }
 }
+
+fn to_cursor_page(messages: Vec<Message>, limit: u32, position: Option<&CursorPosition>) -> CursorPage<MessageResponse> {
+    let messages = messages.into_iter().map(MessageResponse::from).collect();
+    CursorPage::from_rows(messages, limit, position, |m: &MessageResponse| Cursor::new(m.created_at, m.id))
+}
@@ -0,0 +1,9 @@
+use crate::error::AppError;
+use axum::extract::FromRequest;
+
+// `axum::Json` whose rejections use our error body. A field with an unknown
+// value, such as a status that isn't one of ours, is a 422 naming the field
+// and the values it accepts
+#[derive(FromRequest)]
+#[from_request(via(axum::Json), rejection(AppError))]
+pub struct AppJson<T>(pub T);
@@ -1,3 +1,9 @@
 pub mod auth;
+pub mod json;
+pub mod query;
+pub mod precondition;
 
 pub use auth::{auth_middleware, AuthUser};
+pub use json::AppJson;
+pub use query::AppQuery;
+pub use precondition::{etag, IfMatch};
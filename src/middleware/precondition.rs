@@ -0,0 +1,44 @@
+use crate::error::AppError;
+use axum::{
+    extract::FromRequestParts,
+    http::{header, request::Parts, HeaderValue},
+    async_trait,
+};
+
+/// Strong ETag for a row version, e.g. `"3"`.
+pub fn etag(row_version: i32) -> [(header::HeaderName, HeaderValue); 1] {
+    let value = HeaderValue::from_str(&format!("\"{}\"", row_version))
+        .expect("a quoted number is a valid header value");
+    [(header::ETAG, value)]
+}
+
+// Extractor for the `If-Match` header: `Some(version)` when the client sent
+// one of our ETags, `None` when the header is absent or `*`
+pub struct IfMatch(pub Option<i32>);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for IfMatch
+where
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let Some(value) = parts.headers.get(header::IF_MATCH) else {
+            return Ok(IfMatch(None));
+        };
+
+        let invalid = || AppError::BadRequest("If-Match must be an ETag returned by this API".to_string());
+        let value = value.to_str().map_err(|_| invalid())?.trim();
+        if value == "*" {
+            return Ok(IfMatch(None));
+        }
+
+        value
+            .strip_prefix('"')
+            .and_then(|v| v.strip_suffix('"'))
+            .and_then(|v| v.parse().ok())
+            .map(|version| IfMatch(Some(version)))
+            .ok_or_else(invalid)
+    }
+}
@@ -0,0 +1,36 @@
+use crate::error::AppError;
+use axum::extract::FromRequestParts;
+
+// `axum::extract::Query` whose rejections use our error body, so an unknown
+// filter value is a 422 like it is in a JSON body
+#[derive(FromRequestParts)]
+#[from_request(via(axum::extract::Query), rejection(AppError))]
+pub struct AppQuery<T>(pub T);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::task::task_handlers::TaskFilters;
+    use axum::{extract::Query, http::Uri};
+
+    fn rejection(query: &str) -> AppError {
+        let uri: Uri = format!("/api/tasks?{}", query).parse().unwrap();
+        match Query::<TaskFilters>::try_from_uri(&uri) {
+            Ok(_) => panic!("{} should be rejected", query),
+            Err(rejection) => rejection.into(),
+        }
+    }
+
+    #[test]
+    fn test_unknown_status_is_unprocessable() {
+        assert!(matches!(rejection("status=done"), AppError::UnprocessableEntity(_)));
+        assert!(matches!(rejection("priority=critical"), AppError::UnprocessableEntity(_)));
+    }
+
+    #[test]
+    fn test_malformed_values_are_bad_requests() {
+        assert!(matches!(rejection("page=abc"), AppError::BadRequest(_)));
+        assert!(matches!(rejection("project_id=not-a-uuid"), AppError::BadRequest(_)));
+        assert!(matches!(rejection("due_from=tomorrow"), AppError::BadRequest(_)));
+    }
+}
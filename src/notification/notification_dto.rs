@@ -5,3 +5,9 @@ use utoipa::ToSchema;
 pub struct UpdateNotificationPreferencesRequest {
     pub notification_enabled: bool,
 }
+
+#[derive(Debug, Deserialize)]
+pub struct NotificationQuery {
+    /// Items per page (default: 50)
+    pub limit: Option<u32>,
+}
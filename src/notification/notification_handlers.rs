@@ -1,5 +1,5 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     response::{
         sse::{Event, KeepAlive},
@@ -13,19 +13,27 @@ use uuid::Uuid;
 
 use crate::{
     error::{AppError, Result},
+    middleware::AppJson,
+    pagination::{Cursor, CursorPage, CursorParams, MAX_CURSOR_LIMIT},
     state::AppState,
     notification::{
-        notification_dto::UpdateNotificationPreferencesRequest,
+        notification_dto::{NotificationQuery, UpdateNotificationPreferencesRequest},
         notification_models::Notification,
     },
 };
 
-/// Get all notifications for the authenticated user only
+/// Get the authenticated user's notifications, newest first, a page at a time
 #[utoipa::path(
     get,
     path = "/api/notifications",
+    params(
+        ("limit" = Option<u32>, Query, description = "Items per page (default: 50, max: 100)"),
+        ("after" = Option<String>, Query, description = "Cursor: older notifications than this page"),
+        ("before" = Option<String>, Query, description = "Cursor: newer notifications than this page")
+    ),
     responses(
-        (status = 200, description = "List of notifications", body = Vec<Notification>),
+        (status = 200, description = "Page of notifications", body = CursorPage<Notification>),
+        (status = 400, description = "Invalid cursor"),
         (status = 401, description = "Unauthorized")
     ),
     tag = "notifications",
@@ -34,10 +42,20 @@ use crate::{
 pub async fn get_notifications(
     State(state): State<AppState>,
     Extension(user_id): Extension<Uuid>,
-) -> Result<Json<Vec<Notification>>> {
-    let notifications = state.notification_repository.find_all_by_user(user_id).await?;
+    Query(query): Query<NotificationQuery>,
+    Query(cursor): Query<CursorParams>,
+) -> Result<Json<CursorPage<Notification>>> {
+    let position = cursor.position()?;
+    let limit = query.limit.unwrap_or(50).clamp(1, MAX_CURSOR_LIMIT);
+
+    let notifications = state
+        .notification_repository
+        .find_page_by_user(user_id, position.as_ref(), limit)
+        .await?;
 
-    Ok(Json(notifications))
+    Ok(Json(CursorPage::from_rows(notifications, limit, position.as_ref(), |n| {
+        Cursor::new(n.created_at, n.id)
+    })))
 }
 
 /// Subscribe to real-time notifications via Server-Sent Events
@@ -145,7 +163,7 @@ pub async fn delete_notification(
 pub async fn update_notification_preferences(
     State(state): State<AppState>,
     Extension(user_id): Extension<Uuid>,
-    Json(payload): Json<UpdateNotificationPreferencesRequest>,
+    AppJson(payload): AppJson<UpdateNotificationPreferencesRequest>,
 ) -> Result<StatusCode> {
     state.user_repository.update_notification_preferences(user_id, payload.notification_enabled).await?;
 
@@ -98,6 +98,57 @@ impl NotificationHelper {
         Ok(())
     }
 
+    /// Send notification when user is assigned to a task
+    pub async fn notify_task_assigned(
+        &self,
+        receiver_id: Uuid,
+        task_title: &str,
+        assigner_username: &str,
+        task_id: Uuid,
+    ) -> Result<()> {
+        let message = format!("{} assigned you to task: {}", assigner_username, task_title);
+        let _ = self.repo.create(receiver_id, Some(task_id), &message).await;
+        Ok(())
+    }
+
+    /// Send notification when user is unassigned from a task
+    pub async fn notify_task_unassigned(
+        &self,
+        receiver_id: Uuid,
+        task_title: &str,
+        remover_username: &str,
+        task_id: Uuid,
+    ) -> Result<()> {
+        let message = format!("{} unassigned you from task: {}", remover_username, task_title);
+        let _ = self.repo.create(receiver_id, Some(task_id), &message).await;
+        Ok(())
+    }
+
+    /// Send notification when the last blocker of a task is finished
+    pub async fn notify_task_unblocked(
+        &self,
+        user_id: Uuid,
+        task_title: &str,
+        task_id: Uuid,
+    ) -> Result<()> {
+        let message = format!("Task '{}' is unblocked: all of its blockers are done", task_title);
+        let _ = self.repo.create(user_id, Some(task_id), &message).await;
+        Ok(())
+    }
+
+    /// Send notification when user is @mentioned in a task comment
+    pub async fn notify_mentioned_in_comment(
+        &self,
+        receiver_id: Uuid,
+        author_username: &str,
+        task_title: &str,
+        task_id: Uuid,
+    ) -> Result<()> {
+        let message = format!("{} mentioned you in a comment on: {}", author_username, task_title);
+        let _ = self.repo.create(receiver_id, Some(task_id), &message).await;
+        Ok(())
+    }
+
     /// Send notification when user is removed from a task
     pub async fn notify_task_removed(
         &self,
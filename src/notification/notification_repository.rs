@@ -1,4 +1,5 @@
 use crate::error::Result;
+use crate::pagination::{keyset_sql, CursorPosition};
 use sqlx::PgPool;
 use uuid::Uuid;
 use super::notification_models::Notification;
@@ -13,15 +14,28 @@ impl NotificationRepository {
         Self { pool }
     }
 
-    pub async fn find_all_by_user(&self, user_id: Uuid) -> Result<Vec<Notification>> {
-        let notifications = sqlx::query_as::<_, Notification>(
-            "SELECT * FROM notifications WHERE user_id = $1 ORDER BY created_at DESC"
-        )
-        .bind(user_id)
-        .fetch_all(&self.pool)
-        .await?;
+    /// Newest-first notifications next to a cursor. Reads up to `limit + 1` rows.
+    pub async fn find_page_by_user(
+        &self,
+        user_id: Uuid,
+        position: Option<&CursorPosition>,
+        limit: u32,
+    ) -> Result<Vec<Notification>> {
+        let (keyset, order) = keyset_sql("n", position, true, 2);
+        let query = format!(
+            "SELECT n.* FROM notifications n WHERE n.user_id = $1{}{} LIMIT {}",
+            keyset,
+            order,
+            limit + 1
+        );
+
+        let mut db_query = sqlx::query_as::<_, Notification>(&query).bind(user_id);
+        if let Some(position) = position {
+            let cursor = position.cursor();
+            db_query = db_query.bind(cursor.created_at).bind(cursor.id);
+        }
 
-        Ok(notifications)
+        Ok(db_query.fetch_all(&self.pool).await?)
     }
 
     pub async fn mark_as_read(&self, id: Uuid, user_id: Uuid) -> Result<Option<Notification>> {
@@ -1,17 +1,27 @@
 use crate::state::AppState;
+use chrono::Duration;
 use tokio_cron_scheduler::{Job, JobScheduler};
 use tracing::{error, info};
 
+/// How far ahead recurring tasks get their next occurrence created
+const RECURRENCE_LOOKAHEAD_HOURS: i64 = 24;
+
 pub async fn start_notification_service(
     state: AppState,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let scheduler = JobScheduler::new().await?;
+    let purge_state = state.clone();
 
     // Run every minute to check for tasks with upcoming reminders
     let job = Job::new_async("0 * * * * *", move |_uuid, _l| {
         let state = state.clone();
 
         Box::pin(async move {
+            // Create upcoming occurrences first so their reminders go out on this run
+            if let Err(e) = materialize_recurring_tasks(&state).await {
+                error!("Error materializing recurring tasks: {:?}", e);
+            }
+
             if let Err(e) = check_and_send_notifications(state).await {
                 error!("Error checking notifications: {:?}", e);
             }
@@ -19,12 +29,52 @@ pub async fn start_notification_service(
     })?;
 
     scheduler.add(job).await?;
+
+    // Run hourly to empty the trash of tasks past their retention period
+    let purge_job = Job::new_async("0 0 * * * *", move |_uuid, _l| {
+        let state = purge_state.clone();
+
+        Box::pin(async move {
+            if let Err(e) = purge_expired_trash(&state).await {
+                error!("Error purging trashed tasks: {:?}", e);
+            }
+        })
+    })?;
+
+    scheduler.add(purge_job).await?;
     scheduler.start().await?;
 
     info!("Notification service started");
     Ok(())
 }
 
+async fn materialize_recurring_tasks(
+    state: &AppState,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let created = state.task_service
+        .materialize_upcoming_occurrences(Duration::hours(RECURRENCE_LOOKAHEAD_HOURS))
+        .await?;
+
+    for task in created {
+        info!("Created next occurrence of recurring task: {}", task.title);
+    }
+
+    Ok(())
+}
+
+async fn purge_expired_trash(
+    state: &AppState,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let (purged, storage_keys) = state.task_service.purge_expired_trash().await?;
+    state.attachment_service.remove_stored_files(&storage_keys).await;
+
+    if purged > 0 {
+        info!("Purged {} task(s) from the trash", purged);
+    }
+
+    Ok(())
+}
+
 async fn check_and_send_notifications(
     state: AppState,
 ) -> Result<(), Box<dyn std::error::Error>> {
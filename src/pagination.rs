@@ -0,0 +1,213 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::error::{AppError, Result};
+
+/// Largest page a cursor request can ask for.
+pub const MAX_CURSOR_LIMIT: u32 = 100;
+
+/// Position of a row in `(created_at, id)` order. Clients only ever see it
+/// as an opaque token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cursor {
+    pub created_at: DateTime<Utc>,
+    pub id: Uuid,
+}
+
+impl Cursor {
+    pub fn new(created_at: DateTime<Utc>, id: Uuid) -> Self {
+        Self { created_at, id }
+    }
+
+    pub fn encode(&self) -> String {
+        // Postgres keeps microseconds, so this round-trips exactly
+        hex::encode(format!("{}:{}", self.created_at.timestamp_micros(), self.id))
+    }
+
+    pub fn decode(token: &str) -> Result<Self> {
+        let invalid = || AppError::BadRequest("Invalid cursor".to_string());
+
+        let bytes = hex::decode(token).map_err(|_| invalid())?;
+        let raw = String::from_utf8(bytes).map_err(|_| invalid())?;
+        let (micros, id) = raw.split_once(':').ok_or_else(invalid)?;
+        let created_at = micros
+            .parse()
+            .ok()
+            .and_then(DateTime::<Utc>::from_timestamp_micros)
+            .ok_or_else(invalid)?;
+        let id = id.parse().map_err(|_| invalid())?;
+
+        Ok(Self { created_at, id })
+    }
+}
+
+/// Which side of a cursor to read, in the list's own order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CursorPosition {
+    /// The rows listed after the cursor (the next page)
+    After(Cursor),
+    /// The rows listed before the cursor (the previous page)
+    Before(Cursor),
+}
+
+impl CursorPosition {
+    pub fn cursor(&self) -> Cursor {
+        match self {
+            CursorPosition::After(cursor) | CursorPosition::Before(cursor) => *cursor,
+        }
+    }
+
+    /// Whether rows have to be read against the list order.
+    fn is_backward(&self) -> bool {
+        matches!(self, CursorPosition::Before(_))
+    }
+}
+
+/// `after` / `before` query parameters shared by cursor-paged endpoints.
+#[derive(Debug, Default, Deserialize)]
+pub struct CursorParams {
+    pub after: Option<String>,
+    pub before: Option<String>,
+}
+
+impl CursorParams {
+    pub fn position(&self) -> Result<Option<CursorPosition>> {
+        match (self.after.as_deref(), self.before.as_deref()) {
+            (None, None) => Ok(None),
+            (Some(after), None) => Ok(Some(CursorPosition::After(Cursor::decode(after)?))),
+            (None, Some(before)) => Ok(Some(CursorPosition::Before(Cursor::decode(before)?))),
+            (Some(_), Some(_)) => Err(AppError::BadRequest("Use either after or before, not both".to_string())),
+        }
+    }
+}
+
+/// The keyset condition and ordering for a list sorted on
+/// `(<alias>.created_at, <alias>.id)`. The cursor is bound as `$param` and
+/// `$param + 1`; fetch one row more than the page size and hand the rows to
+/// [`CursorPage::from_rows`].
+pub fn keyset_sql(alias: &str, position: Option<&CursorPosition>, descending: bool, param: usize) -> (String, String) {
+    let scan_descending = descending != position.is_some_and(CursorPosition::is_backward);
+    let direction = if scan_descending { "DESC" } else { "ASC" };
+    let order = format!(" ORDER BY {0}.created_at {1}, {0}.id {1}", alias, direction);
+
+    let condition = match position {
+        Some(_) => format!(
+            " AND ({0}.created_at, {0}.id) {1} (${2}, ${3})",
+            alias,
+            if scan_descending { "<" } else { ">" },
+            param,
+            param + 1
+        ),
+        None => String::new(),
+    };
+
+    (condition, order)
+}
+
+/// One page of a cursor-paged list. Pass `next_cursor` as `after` to keep
+/// going, or `prev_cursor` as `before` to go back.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CursorPage<T> {
+    pub data: Vec<T>,
+    pub limit: u32,
+    pub next_cursor: Option<String>,
+    pub prev_cursor: Option<String>,
+}
+
+impl<T> CursorPage<T> {
+    /// Builds a page from rows read with [`keyset_sql`] and `LIMIT limit + 1`.
+    pub fn from_rows(
+        mut rows: Vec<T>,
+        limit: u32,
+        position: Option<&CursorPosition>,
+        key: impl Fn(&T) -> Cursor,
+    ) -> Self {
+        let has_more = rows.len() > limit as usize;
+        rows.truncate(limit as usize);
+
+        let backward = position.is_some_and(CursorPosition::is_backward);
+        if backward {
+            rows.reverse();
+        }
+
+        let first = rows.first().map(|row| key(row).encode());
+        let last = rows.last().map(|row| key(row).encode());
+
+        // Reading backward, the cursor row itself is still ahead of us;
+        // reading forward from a cursor, it is behind us.
+        let (next_cursor, prev_cursor) = match position {
+            None => (last.filter(|_| has_more), None),
+            Some(_) if backward => (last, first.filter(|_| has_more)),
+            Some(_) => (last.filter(|_| has_more), first),
+        };
+
+        Self {
+            data: rows,
+            limit,
+            next_cursor,
+            prev_cursor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cursor(micros: i64) -> Cursor {
+        Cursor::new(DateTime::<Utc>::from_timestamp_micros(micros).unwrap(), Uuid::new_v4())
+    }
+
+    #[test]
+    fn test_cursor_round_trip() {
+        let original = cursor(1_760_000_000_123_456);
+        assert_eq!(Cursor::decode(&original.encode()).unwrap(), original);
+
+        assert!(Cursor::decode("not-a-cursor").is_err());
+        assert!(Cursor::decode(&hex::encode("12:nope")).is_err());
+    }
+
+    #[test]
+    fn test_keyset_sql_flips_when_reading_backward() {
+        let c = cursor(0);
+
+        let (condition, order) = keyset_sql("m", Some(&CursorPosition::After(c)), true, 3);
+        assert_eq!(condition, " AND (m.created_at, m.id) < ($3, $4)");
+        assert_eq!(order, " ORDER BY m.created_at DESC, m.id DESC");
+
+        let (condition, order) = keyset_sql("m", Some(&CursorPosition::Before(c)), true, 3);
+        assert_eq!(condition, " AND (m.created_at, m.id) > ($3, $4)");
+        assert_eq!(order, " ORDER BY m.created_at ASC, m.id ASC");
+
+        let (condition, _) = keyset_sql("m", None, true, 3);
+        assert!(condition.is_empty());
+    }
+
+    #[test]
+    fn test_page_cursors() {
+        let rows: Vec<Cursor> = (0..4).map(|i| cursor(100 - i)).collect();
+        let key = |c: &Cursor| *c;
+
+        // First page with more to come
+        let page = CursorPage::from_rows(rows.clone(), 3, None, key);
+        assert_eq!(page.data.len(), 3);
+        assert_eq!(page.next_cursor, Some(rows[2].encode()));
+        assert_eq!(page.prev_cursor, None);
+
+        // Last page reached going forward
+        let position = CursorPosition::After(cursor(200));
+        let page = CursorPage::from_rows(rows[..2].to_vec(), 3, Some(&position), key);
+        assert_eq!(page.next_cursor, None);
+        assert_eq!(page.prev_cursor, Some(rows[0].encode()));
+
+        // Going backward, rows arrive reversed and are put back in list order
+        let position = CursorPosition::Before(cursor(0));
+        let reversed: Vec<Cursor> = rows.iter().rev().copied().collect();
+        let page = CursorPage::from_rows(reversed, 3, Some(&position), key);
+        assert_eq!(page.data, rows[1..].to_vec());
+        assert_eq!(page.prev_cursor, Some(rows[1].encode()));
+        assert_eq!(page.next_cursor, Some(rows[3].encode()));
+    }
+}
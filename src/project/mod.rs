@@ -0,0 +1,6 @@
+// Declare submodules
+pub mod project_models;
+pub mod project_dto;
+pub mod project_repository;
+pub mod project_service;
+pub mod project_handlers;
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::task::task_models::TaskStatus;
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateProjectRequest {
+    #[validate(length(min = 1, max = 255))]
+    pub name: String,
+    #[validate(length(max = 1000))]
+    pub description: Option<String>,
+    /// Initial columns, left to right (defaults to To Do / In Progress / Done)
+    #[validate(length(min = 1), nested)]
+    pub columns: Option<Vec<CreateColumnRequest>>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateProjectRequest {
+    #[validate(length(min = 1, max = 255))]
+    pub name: Option<String>,
+    #[validate(length(max = 1000))]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct AddProjectMembersRequest {
+    #[validate(length(min = 1))]
+    pub user_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateColumnRequest {
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+    /// Status given to tasks placed in this column
+    pub status: TaskStatus,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateColumnRequest {
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct ReorderColumnsRequest {
+    /// Every column of the project, in the desired order
+    #[validate(length(min = 1))]
+    pub column_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct MoveTaskRequest {
+    /// Target column
+    pub column_id: Uuid,
+    /// Zero-based index within the target column
+    pub position: u32,
+}
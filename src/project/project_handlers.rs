@@ -0,0 +1,470 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    error::Result,
+    middleware::{AppJson, AuthUser},
+    state::AppState,
+    task::task_models::TaskEventKind,
+    project::project_dto::{
+        CreateProjectRequest, UpdateProjectRequest, AddProjectMembersRequest,
+        CreateColumnRequest, UpdateColumnRequest, ReorderColumnsRequest, MoveTaskRequest,
+    },
+};
+
+/// Create a new project
+#[utoipa::path(
+    post,
+    path = "/api/projects",
+    tag = "projects",
+    request_body = CreateProjectRequest,
+    responses(
+        (status = 201, description = "Project created successfully", body = Project),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 422, description = "Unknown column status; the error lists the allowed values")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create_project(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    AppJson(payload): AppJson<CreateProjectRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+
+    let project = state.project_service
+        .create_project(user_id, payload.name, payload.description, payload.columns)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(project)))
+}
+
+/// Get all projects the authenticated user is a member of
+#[utoipa::path(
+    get,
+    path = "/api/projects",
+    tag = "projects",
+    responses(
+        (status = 200, description = "Projects retrieved successfully", body = Vec<Project>),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_projects(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+) -> Result<impl IntoResponse> {
+    let projects = state.project_service.list_projects(user_id).await?;
+
+    Ok((StatusCode::OK, Json(projects)))
+}
+
+/// Get a specific project by ID
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}",
+    tag = "projects",
+    params(
+        ("project_id" = Uuid, Path, description = "Project ID")
+    ),
+    responses(
+        (status = 200, description = "Project retrieved successfully", body = Project),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Not a member"),
+        (status = 404, description = "Project not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_project(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(project_id): Path<Uuid>,
+) -> Result<impl IntoResponse> {
+    let project = state.project_service.get_project(project_id, user_id).await?;
+
+    Ok((StatusCode::OK, Json(project)))
+}
+
+/// Update project (owner only)
+#[utoipa::path(
+    put,
+    path = "/api/projects/{project_id}",
+    tag = "projects",
+    params(
+        ("project_id" = Uuid, Path, description = "Project ID")
+    ),
+    request_body = UpdateProjectRequest,
+    responses(
+        (status = 200, description = "Project updated successfully", body = Project),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Only owner can update"),
+        (status = 404, description = "Project not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn update_project(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(project_id): Path<Uuid>,
+    AppJson(payload): AppJson<UpdateProjectRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+
+    let project = state.project_service
+        .update_project(project_id, user_id, payload.name, payload.description)
+        .await?;
+
+    Ok((StatusCode::OK, Json(project)))
+}
+
+/// Delete project and move its tasks to their owners' trash (owner only)
+#[utoipa::path(
+    delete,
+    path = "/api/projects/{project_id}",
+    tag = "projects",
+    params(
+        ("project_id" = Uuid, Path, description = "Project ID")
+    ),
+    responses(
+        (status = 204, description = "Project deleted successfully"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Only owner can delete"),
+        (status = 404, description = "Project not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_project(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(project_id): Path<Uuid>,
+) -> Result<impl IntoResponse> {
+    state.project_service.delete_project(project_id, user_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Add collaborators to a project (owner only)
+#[utoipa::path(
+    post,
+    path = "/api/projects/{project_id}/members",
+    tag = "projects",
+    params(
+        ("project_id" = Uuid, Path, description = "Project ID")
+    ),
+    request_body = AddProjectMembersRequest,
+    responses(
+        (status = 200, description = "Members added successfully", body = Vec<ProjectMemberInfo>),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Only owner can add members"),
+        (status = 404, description = "Project not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn add_project_members(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(project_id): Path<Uuid>,
+    AppJson(payload): AppJson<AddProjectMembersRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+
+    state.project_service
+        .add_members(project_id, payload.user_ids, user_id)
+        .await?;
+    let members = state.project_service.list_members(project_id, user_id).await?;
+
+    Ok((StatusCode::OK, Json(members)))
+}
+
+/// Get project members
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/members",
+    tag = "projects",
+    params(
+        ("project_id" = Uuid, Path, description = "Project ID")
+    ),
+    responses(
+        (status = 200, description = "Members retrieved successfully", body = Vec<ProjectMemberInfo>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Not a member"),
+        (status = 404, description = "Project not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_project_members(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(project_id): Path<Uuid>,
+) -> Result<impl IntoResponse> {
+    let members = state.project_service.list_members(project_id, user_id).await?;
+
+    Ok((StatusCode::OK, Json(members)))
+}
+
+/// Remove a collaborator from a project (owner only)
+#[utoipa::path(
+    delete,
+    path = "/api/projects/{project_id}/members/{user_id}",
+    tag = "projects",
+    params(
+        ("project_id" = Uuid, Path, description = "Project ID"),
+        ("user_id" = Uuid, Path, description = "User ID to remove")
+    ),
+    responses(
+        (status = 204, description = "Member removed successfully"),
+        (status = 400, description = "Cannot remove project owner"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Only owner can remove members"),
+        (status = 404, description = "Project or member not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn remove_project_member(
+    State(state): State<AppState>,
+    AuthUser(requesting_user): AuthUser,
+    Path((project_id, user_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse> {
+    state.project_service
+        .remove_member(project_id, user_id, requesting_user)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Add a column to the project board
+#[utoipa::path(
+    post,
+    path = "/api/projects/{project_id}/columns",
+    tag = "projects",
+    params(
+        ("project_id" = Uuid, Path, description = "Project ID")
+    ),
+    request_body = CreateColumnRequest,
+    responses(
+        (status = 201, description = "Column created successfully", body = ProjectColumn),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Not a member"),
+        (status = 404, description = "Project not found"),
+        (status = 422, description = "Unknown status; the error lists the allowed values")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create_column(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(project_id): Path<Uuid>,
+    AppJson(payload): AppJson<CreateColumnRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+
+    let column = state.project_service
+        .create_column(project_id, user_id, payload.name, payload.status)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(column)))
+}
+
+/// Rename a board column
+#[utoipa::path(
+    put,
+    path = "/api/projects/{project_id}/columns/{column_id}",
+    tag = "projects",
+    params(
+        ("project_id" = Uuid, Path, description = "Project ID"),
+        ("column_id" = Uuid, Path, description = "Column ID")
+    ),
+    request_body = UpdateColumnRequest,
+    responses(
+        (status = 200, description = "Column updated successfully", body = ProjectColumn),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Not a member"),
+        (status = 404, description = "Project or column not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn update_column(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path((project_id, column_id)): Path<(Uuid, Uuid)>,
+    AppJson(payload): AppJson<UpdateColumnRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+
+    let column = state.project_service
+        .rename_column(project_id, column_id, user_id, payload.name)
+        .await?;
+
+    Ok((StatusCode::OK, Json(column)))
+}
+
+/// Delete an empty board column
+#[utoipa::path(
+    delete,
+    path = "/api/projects/{project_id}/columns/{column_id}",
+    tag = "projects",
+    params(
+        ("project_id" = Uuid, Path, description = "Project ID"),
+        ("column_id" = Uuid, Path, description = "Column ID")
+    ),
+    responses(
+        (status = 204, description = "Column deleted successfully"),
+        (status = 400, description = "Cannot delete the last column"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Not a member"),
+        (status = 404, description = "Project or column not found"),
+        (status = 409, description = "Column still has tasks")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_column(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path((project_id, column_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse> {
+    state.project_service
+        .delete_column(project_id, column_id, user_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Reorder the columns of a project board
+#[utoipa::path(
+    put,
+    path = "/api/projects/{project_id}/columns/order",
+    tag = "projects",
+    params(
+        ("project_id" = Uuid, Path, description = "Project ID")
+    ),
+    request_body = ReorderColumnsRequest,
+    responses(
+        (status = 200, description = "Columns reordered", body = Vec<ProjectColumn>),
+        (status = 400, description = "column_ids does not match the project's columns"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Not a member"),
+        (status = 404, description = "Project not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn reorder_columns(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(project_id): Path<Uuid>,
+    AppJson(payload): AppJson<ReorderColumnsRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+
+    let columns = state.project_service
+        .reorder_columns(project_id, user_id, payload.column_ids)
+        .await?;
+
+    Ok((StatusCode::OK, Json(columns)))
+}
+
+/// Get the project board: every column with its tasks in order
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/board",
+    tag = "projects",
+    params(
+        ("project_id" = Uuid, Path, description = "Project ID")
+    ),
+    responses(
+        (status = 200, description = "Board retrieved successfully", body = ProjectBoard),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Not a member"),
+        (status = 404, description = "Project not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_board(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(project_id): Path<Uuid>,
+) -> Result<impl IntoResponse> {
+    let board = state.project_service.get_board(project_id, user_id).await?;
+
+    Ok((StatusCode::OK, Json(board)))
+}
+
+/// Move a task to a column and position on the board
+#[utoipa::path(
+    put,
+    path = "/api/projects/{project_id}/tasks/{task_id}/move",
+    tag = "projects",
+    params(
+        ("project_id" = Uuid, Path, description = "Project ID"),
+        ("task_id" = Uuid, Path, description = "Task ID")
+    ),
+    request_body = MoveTaskRequest,
+    responses(
+        (status = 200, description = "Task moved", body = Task),
+        (status = 400, description = "Task cannot be placed on this board"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Not a member"),
+        (status = 404, description = "Project, column or task not found"),
+        (status = 409, description = "Task is blocked by unfinished tasks")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn move_task(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path((project_id, task_id)): Path<(Uuid, Uuid)>,
+    AppJson(payload): AppJson<MoveTaskRequest>,
+) -> Result<impl IntoResponse> {
+    let task = state.project_service
+        .move_task(project_id, task_id, user_id, payload.column_id, payload.position)
+        .await?;
+
+    state.task_events
+        .publish(
+            TaskEventKind::Updated,
+            &task,
+            Some(user_id),
+            Some(serde_json::json!({"project_id": project_id, "column_id": task.column_id})),
+        )
+        .await;
+
+    Ok((StatusCode::OK, Json(task)))
+}
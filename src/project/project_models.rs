@@ -0,0 +1,87 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::task::task_models::{TaskListItem, TaskStatus};
+
+/// Gap left between neighbouring tasks in a column, so most moves only touch the moved task.
+pub const RANK_STEP: f64 = 1024.0;
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct Project {
+    pub id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub owner_id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct ProjectMemberInfo {
+    pub user_id: Uuid,
+    pub username: String,
+    pub avatar_url: Option<String>,
+    pub role: String, // "owner" or "collaborator"
+    pub added_at: DateTime<Utc>,
+}
+
+/// Kanban column; tasks dropped into it take on its status.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct ProjectColumn {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub name: String,
+    pub status: TaskStatus,
+    pub position: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BoardColumn {
+    #[serde(flatten)]
+    pub column: ProjectColumn,
+    /// Tasks in manual order
+    pub tasks: Vec<TaskListItem>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ProjectBoard {
+    pub project: Project,
+    pub columns: Vec<BoardColumn>,
+}
+
+/// Rank for a task dropped between two neighbours (either may be missing at
+/// the ends of a column). Returns `None` when the neighbours are too close
+/// together to fit another rank, meaning the column needs renumbering.
+pub fn rank_between(prev: Option<f64>, next: Option<f64>) -> Option<f64> {
+    match (prev, next) {
+        (None, None) => Some(RANK_STEP),
+        (Some(prev), None) => Some(prev + RANK_STEP),
+        (None, Some(next)) => Some(next - RANK_STEP),
+        (Some(prev), Some(next)) => {
+            let mid = prev + (next - prev) / 2.0;
+            (mid > prev && mid < next).then_some(mid)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rank_between() {
+        assert_eq!(rank_between(None, None), Some(RANK_STEP));
+        assert_eq!(rank_between(Some(1024.0), None), Some(2048.0));
+        assert_eq!(rank_between(None, Some(1024.0)), Some(0.0));
+        assert_eq!(rank_between(Some(1024.0), Some(2048.0)), Some(1536.0));
+
+        // Adjacent floats leave no room in between
+        let prev = 1.0_f64;
+        let next = f64::from_bits(prev.to_bits() + 1);
+        assert_eq!(rank_between(Some(prev), Some(next)), None);
+    }
+}
@@ -0,0 +1,417 @@
+use crate::error::Result;
+use crate::task::task_models::{Task, TaskStatus};
+use crate::task::task_repository::SOFT_DELETE_TASK_TREE;
+use sqlx::PgPool;
+use uuid::Uuid;
+use super::project_models::{Project, ProjectColumn, ProjectMemberInfo, RANK_STEP};
+
+#[derive(Clone)]
+pub struct ProjectRepository {
+    pool: PgPool,
+}
+
+impl ProjectRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Creates a project with its owner membership and initial `(name, status)` columns.
+    pub async fn create(
+        &self,
+        owner_id: Uuid,
+        name: &str,
+        description: Option<&str>,
+        columns: &[(String, TaskStatus)],
+    ) -> Result<Project> {
+        let mut tx = self.pool.begin().await?;
+
+        let project = sqlx::query_as::<_, Project>(
+            "INSERT INTO projects (name, description, owner_id)
+             VALUES ($1, $2, $3)
+             RETURNING *"
+        )
+        .bind(name)
+        .bind(description)
+        .bind(owner_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            "INSERT INTO project_members (project_id, user_id, role, added_by)
+             VALUES ($1, $2, 'owner', $2)"
+        )
+        .bind(project.id)
+        .bind(owner_id)
+        .execute(&mut *tx)
+        .await?;
+
+        for (position, (column_name, status)) in columns.iter().enumerate() {
+            sqlx::query(
+                "INSERT INTO project_columns (project_id, name, status, position)
+                 VALUES ($1, $2, $3, $4)"
+            )
+            .bind(project.id)
+            .bind(column_name)
+            .bind(status)
+            .bind(position as i32)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(project)
+    }
+
+    pub async fn find_by_id(&self, id: Uuid) -> Result<Option<Project>> {
+        let project = sqlx::query_as::<_, Project>("SELECT * FROM projects WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(project)
+    }
+
+    pub async fn find_user_projects(&self, user_id: Uuid) -> Result<Vec<Project>> {
+        let projects = sqlx::query_as::<_, Project>(
+            "SELECT p.* FROM projects p
+             JOIN project_members pm ON pm.project_id = p.id
+             WHERE pm.user_id = $1
+             ORDER BY p.updated_at DESC"
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(projects)
+    }
+
+    pub async fn update(&self, id: Uuid, name: Option<&str>, description: Option<&str>) -> Result<Project> {
+        let project = sqlx::query_as::<_, Project>(
+            "UPDATE projects SET
+                name = COALESCE($1, name),
+                description = COALESCE($2, description),
+                updated_at = NOW()
+             WHERE id = $3
+             RETURNING *"
+        )
+        .bind(name)
+        .bind(description)
+        .bind(id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(project)
+    }
+
+    /// Deletes the project after moving its live task trees to their owners'
+    /// trash. The tasks, trashed ones included, are detached from the project
+    /// first so they outlive it and can be restored as personal tasks.
+    /// Returns the IDs of the trashed tree roots.
+    pub async fn delete(&self, id: Uuid, deleted_by: Uuid) -> Result<Vec<Uuid>> {
+        let mut tx = self.pool.begin().await?;
+
+        let roots: Vec<Uuid> = sqlx::query_scalar(
+            "SELECT t.id FROM tasks t
+             WHERE t.project_id = $1 AND t.deleted_at IS NULL
+             AND NOT EXISTS (
+                SELECT 1 FROM tasks p
+                WHERE p.id = t.parent_task_id AND p.project_id = $1 AND p.deleted_at IS NULL
+             )"
+        )
+        .bind(id)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        for root in &roots {
+            sqlx::query(SOFT_DELETE_TASK_TREE)
+                .bind(root)
+                .bind(deleted_by)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        sqlx::query("UPDATE tasks SET project_id = NULL, column_id = NULL WHERE project_id = $1")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query("DELETE FROM projects WHERE id = $1")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+        Ok(roots)
+    }
+
+    // Membership methods
+
+    pub async fn is_member(&self, project_id: Uuid, user_id: Uuid) -> Result<bool> {
+        let exists: bool = sqlx::query_scalar(
+            "SELECT EXISTS (SELECT 1 FROM project_members WHERE project_id = $1 AND user_id = $2)"
+        )
+        .bind(project_id)
+        .bind(user_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(exists)
+    }
+
+    pub async fn add_member(&self, project_id: Uuid, user_id: Uuid, role: &str, added_by: Uuid) -> Result<()> {
+        sqlx::query(
+            "INSERT INTO project_members (project_id, user_id, role, added_by)
+             VALUES ($1, $2, $3, $4)
+             ON CONFLICT (project_id, user_id) DO NOTHING"
+        )
+        .bind(project_id)
+        .bind(user_id)
+        .bind(role)
+        .bind(added_by)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_member(&self, project_id: Uuid, user_id: Uuid) -> Result<u64> {
+        let result = sqlx::query("DELETE FROM project_members WHERE project_id = $1 AND user_id = $2")
+            .bind(project_id)
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    pub async fn get_members(&self, project_id: Uuid) -> Result<Vec<ProjectMemberInfo>> {
+        let members = sqlx::query_as::<_, ProjectMemberInfo>(
+            "SELECT pm.user_id, u.username, u.avatar_url, pm.role, pm.added_at
+             FROM project_members pm
+             JOIN users u ON u.id = pm.user_id
+             WHERE pm.project_id = $1
+             ORDER BY pm.added_at ASC"
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(members)
+    }
+
+    // Column methods
+
+    pub async fn get_columns(&self, project_id: Uuid) -> Result<Vec<ProjectColumn>> {
+        let columns = sqlx::query_as::<_, ProjectColumn>(
+            "SELECT * FROM project_columns WHERE project_id = $1 ORDER BY position ASC, created_at ASC"
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(columns)
+    }
+
+    pub async fn find_column(&self, project_id: Uuid, column_id: Uuid) -> Result<Option<ProjectColumn>> {
+        let column = sqlx::query_as::<_, ProjectColumn>(
+            "SELECT * FROM project_columns WHERE id = $1 AND project_id = $2"
+        )
+        .bind(column_id)
+        .bind(project_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(column)
+    }
+
+    pub async fn create_column(&self, project_id: Uuid, name: &str, status: TaskStatus) -> Result<ProjectColumn> {
+        let column = sqlx::query_as::<_, ProjectColumn>(
+            "INSERT INTO project_columns (project_id, name, status, position)
+             VALUES ($1, $2, $3,
+                     COALESCE((SELECT MAX(position) + 1 FROM project_columns WHERE project_id = $1), 0))
+             RETURNING *"
+        )
+        .bind(project_id)
+        .bind(name)
+        .bind(status)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(column)
+    }
+
+    pub async fn rename_column(&self, column_id: Uuid, name: &str) -> Result<ProjectColumn> {
+        let column = sqlx::query_as::<_, ProjectColumn>(
+            "UPDATE project_columns SET name = $1 WHERE id = $2 RETURNING *"
+        )
+        .bind(name)
+        .bind(column_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(column)
+    }
+
+    pub async fn delete_column(&self, column_id: Uuid) -> Result<()> {
+        sqlx::query("DELETE FROM project_columns WHERE id = $1")
+            .bind(column_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn count_column_tasks(&self, column_id: Uuid) -> Result<i64> {
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM tasks WHERE column_id = $1 AND deleted_at IS NULL")
+            .bind(column_id)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(count)
+    }
+
+    /// Stores the order of a project's columns; `ordered_ids` must list all of them.
+    pub async fn reorder_columns(&self, project_id: Uuid, ordered_ids: &[Uuid]) -> Result<()> {
+        let positions: Vec<i32> = (0..ordered_ids.len() as i32).collect();
+
+        sqlx::query(
+            "UPDATE project_columns c SET position = o.position
+             FROM UNNEST($1::uuid[], $2::int[]) AS o(id, position)
+             WHERE c.id = o.id AND c.project_id = $3"
+        )
+        .bind(ordered_ids)
+        .bind(&positions)
+        .bind(project_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    // Board methods
+
+    /// Top-level tasks of a project, by column and manual rank.
+    pub async fn get_board_tasks(&self, project_id: Uuid) -> Result<Vec<Task>> {
+        let tasks = sqlx::query_as::<_, Task>(
+            "SELECT * FROM tasks
+             WHERE project_id = $1 AND parent_task_id IS NULL AND deleted_at IS NULL
+             ORDER BY board_rank ASC, created_at ASC"
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(tasks)
+    }
+
+    /// Ranks of the tasks in a column in order, leaving out `exclude_task_id`.
+    pub async fn get_column_ranks(&self, column_id: Uuid, exclude_task_id: Uuid) -> Result<Vec<f64>> {
+        let ranks: Vec<f64> = sqlx::query_scalar(
+            "SELECT board_rank FROM tasks
+             WHERE column_id = $1 AND id <> $2 AND deleted_at IS NULL
+             ORDER BY board_rank ASC, created_at ASC"
+        )
+        .bind(column_id)
+        .bind(exclude_task_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(ranks)
+    }
+
+    /// Spreads the ranks in a column back out to multiples of `RANK_STEP`, keeping their order.
+    pub async fn renumber_column(&self, column_id: Uuid) -> Result<()> {
+        sqlx::query(
+            "UPDATE tasks t SET board_rank = r.rn * $2
+             FROM (
+                SELECT id, ROW_NUMBER() OVER (ORDER BY board_rank ASC, created_at ASC) AS rn
+                FROM tasks WHERE column_id = $1
+             ) r
+             WHERE t.id = r.id"
+        )
+        .bind(column_id)
+        .bind(RANK_STEP)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn set_task_position(&self, task_id: Uuid, project_id: Uuid, column_id: Uuid, rank: f64) -> Result<Task> {
+        let task = sqlx::query_as::<_, Task>(
+            "UPDATE tasks SET project_id = $1, column_id = $2, board_rank = $3, updated_at = NOW()
+             WHERE id = $4
+             RETURNING *"
+        )
+        .bind(project_id)
+        .bind(column_id)
+        .bind(rank)
+        .bind(task_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(task)
+    }
+
+    /// Puts a task at the bottom of the first column matching its status
+    /// (or the first column, if none does).
+    pub async fn add_task_to_project(&self, task_id: Uuid, project_id: Uuid, status: TaskStatus) -> Result<Task> {
+        let task = sqlx::query_as::<_, Task>(
+            "UPDATE tasks t SET
+                project_id = $2,
+                column_id = c.id,
+                board_rank = COALESCE((SELECT MAX(board_rank) FROM tasks WHERE column_id = c.id), 0) + $4,
+                updated_at = NOW()
+             FROM (
+                SELECT id FROM project_columns
+                WHERE project_id = $2
+                ORDER BY (status = $3) DESC, position ASC
+                LIMIT 1
+             ) c
+             WHERE t.id = $1
+             RETURNING t.*"
+        )
+        .bind(task_id)
+        .bind(project_id)
+        .bind(status)
+        .bind(RANK_STEP)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(task)
+    }
+
+    /// After a status change, moves a board task to the bottom of the first
+    /// column mapped to its new status, unless its current column already is.
+    /// Returns the updated task if it moved.
+    pub async fn sync_task_column(&self, task: &Task) -> Result<Option<Task>> {
+        let (Some(project_id), None) = (task.project_id, task.parent_task_id) else {
+            return Ok(None);
+        };
+
+        let moved = sqlx::query_as::<_, Task>(
+            "UPDATE tasks t SET
+                column_id = c.id,
+                board_rank = COALESCE((SELECT MAX(board_rank) FROM tasks WHERE column_id = c.id), 0) + $4
+             FROM (
+                SELECT id FROM project_columns
+                WHERE project_id = $2 AND status = $3
+                ORDER BY position ASC
+                LIMIT 1
+             ) c
+             WHERE t.id = $1
+             AND NOT EXISTS (
+                SELECT 1 FROM project_columns cur WHERE cur.id = t.column_id AND cur.status = $3
+             )
+             RETURNING t.*"
+        )
+        .bind(task.id)
+        .bind(project_id)
+        .bind(task.status)
+        .bind(RANK_STEP)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(moved)
+    }
+}
@@ -0,0 +1,318 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::error::{AppError, Result};
+use crate::task::task_dto::UpdateTaskStatusRequest;
+use crate::task::task_models::{Task, TaskStatus};
+use crate::task::task_repository::TaskRepository;
+use crate::task::task_service::TaskService;
+use uuid::Uuid;
+use super::project_dto::CreateColumnRequest;
+use super::project_models::{rank_between, BoardColumn, Project, ProjectBoard, ProjectColumn, ProjectMemberInfo};
+use super::project_repository::ProjectRepository;
+
+/// Service layer for projects and their Kanban boards.
+///
+/// Mirrors task collaboration: any member can work on the board, only the
+/// owner can manage membership or delete the project.
+#[derive(Clone)]
+pub struct ProjectService {
+    repo: ProjectRepository,
+    task_repo: TaskRepository,
+    task_service: TaskService,
+}
+
+impl ProjectService {
+    pub fn new(repo: ProjectRepository, task_repo: TaskRepository, task_service: TaskService) -> Self {
+        Self {
+            repo,
+            task_repo,
+            task_service,
+        }
+    }
+
+    pub async fn create_project(
+        &self,
+        owner_id: Uuid,
+        name: String,
+        description: Option<String>,
+        columns: Option<Vec<CreateColumnRequest>>,
+    ) -> Result<Project> {
+        let columns: Vec<(String, TaskStatus)> = match columns {
+            Some(columns) => columns
+                .into_iter()
+                .map(|c| (c.name, c.status))
+                .collect(),
+            None => vec![
+                ("To Do".to_string(), TaskStatus::Pending),
+                ("In Progress".to_string(), TaskStatus::InProgress),
+                ("Done".to_string(), TaskStatus::Completed),
+            ],
+        };
+
+        self.repo
+            .create(owner_id, &name, description.as_deref(), &columns)
+            .await
+    }
+
+    pub async fn get_project(&self, project_id: Uuid, user_id: Uuid) -> Result<Project> {
+        let project = self.repo
+            .find_by_id(project_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Project not found".to_string()))?;
+
+        if !self.repo.is_member(project_id, user_id).await? {
+            return Err(AppError::Forbidden("You are not a member of this project".to_string()));
+        }
+
+        Ok(project)
+    }
+
+    pub async fn list_projects(&self, user_id: Uuid) -> Result<Vec<Project>> {
+        self.repo.find_user_projects(user_id).await
+    }
+
+    pub async fn update_project(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+        name: Option<String>,
+        description: Option<String>,
+    ) -> Result<Project> {
+        self.get_owned_project(project_id, user_id, "Only the project owner can update it").await?;
+
+        self.repo
+            .update(project_id, name.as_deref(), description.as_deref())
+            .await
+    }
+
+    /// Deletes the project and moves its tasks to the trash.
+    pub async fn delete_project(&self, project_id: Uuid, user_id: Uuid) -> Result<()> {
+        self.get_owned_project(project_id, user_id, "Only the project owner can delete it").await?;
+
+        let trashed = self.repo.delete(project_id, user_id).await?;
+        let details = serde_json::json!({"project_deleted": project_id});
+        for task_id in trashed {
+            let _ = self.task_repo.log_task_activity(task_id, user_id, "task_deleted", Some(details.clone())).await;
+        }
+
+        Ok(())
+    }
+
+    // Membership methods
+
+    pub async fn add_members(&self, project_id: Uuid, user_ids: Vec<Uuid>, added_by: Uuid) -> Result<()> {
+        self.get_owned_project(project_id, added_by, "Only the project owner can add members").await?;
+
+        for user_id in user_ids {
+            self.repo.add_member(project_id, user_id, "collaborator", added_by).await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn remove_member(&self, project_id: Uuid, user_id: Uuid, removed_by: Uuid) -> Result<()> {
+        let project = self
+            .get_owned_project(project_id, removed_by, "Only the project owner can remove members")
+            .await?;
+
+        // Cannot remove owner
+        if project.owner_id == user_id {
+            return Err(AppError::BadRequest("Cannot remove project owner".to_string()));
+        }
+
+        if self.repo.remove_member(project_id, user_id).await? == 0 {
+            return Err(AppError::NotFound("User is not a member of this project".to_string()));
+        }
+        self.task_repo.remove_uneditable_project_assignments(project_id, user_id).await?;
+
+        Ok(())
+    }
+
+    pub async fn list_members(&self, project_id: Uuid, user_id: Uuid) -> Result<Vec<ProjectMemberInfo>> {
+        self.get_project(project_id, user_id).await?;
+        self.repo.get_members(project_id).await
+    }
+
+    // Column methods
+
+    pub async fn create_column(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+        name: String,
+        status: TaskStatus,
+    ) -> Result<ProjectColumn> {
+        self.get_project(project_id, user_id).await?;
+        self.repo.create_column(project_id, &name, status).await
+    }
+
+    pub async fn rename_column(
+        &self,
+        project_id: Uuid,
+        column_id: Uuid,
+        user_id: Uuid,
+        name: String,
+    ) -> Result<ProjectColumn> {
+        self.get_column(project_id, column_id, user_id).await?;
+        self.repo.rename_column(column_id, &name).await
+    }
+
+    /// Only empty columns can be deleted, and a project always keeps at least one.
+    pub async fn delete_column(&self, project_id: Uuid, column_id: Uuid, user_id: Uuid) -> Result<()> {
+        self.get_column(project_id, column_id, user_id).await?;
+
+        if self.repo.count_column_tasks(column_id).await? > 0 {
+            return Err(AppError::Conflict("Move the tasks out of this column before deleting it".to_string()));
+        }
+        if self.repo.get_columns(project_id).await?.len() <= 1 {
+            return Err(AppError::BadRequest("A project needs at least one column".to_string()));
+        }
+
+        self.repo.delete_column(column_id).await
+    }
+
+    pub async fn reorder_columns(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+        column_ids: Vec<Uuid>,
+    ) -> Result<Vec<ProjectColumn>> {
+        self.get_project(project_id, user_id).await?;
+
+        // The new order must be a permutation of the current columns
+        let current: HashSet<Uuid> = self.repo
+            .get_columns(project_id)
+            .await?
+            .into_iter()
+            .map(|c| c.id)
+            .collect();
+        let requested: HashSet<Uuid> = column_ids.iter().copied().collect();
+        if requested.len() != column_ids.len() || requested != current {
+            return Err(AppError::BadRequest(
+                "column_ids must list every column of the project exactly once".to_string(),
+            ));
+        }
+
+        self.repo.reorder_columns(project_id, &column_ids).await?;
+        self.repo.get_columns(project_id).await
+    }
+
+    // Board methods
+
+    /// All columns of the project with their tasks in manual order.
+    pub async fn get_board(&self, project_id: Uuid, user_id: Uuid) -> Result<ProjectBoard> {
+        let project = self.get_project(project_id, user_id).await?;
+        let columns = self.repo.get_columns(project_id).await?;
+        let tasks = self.repo.get_board_tasks(project_id).await?;
+        let items = self.task_service.to_list_items(tasks).await?;
+
+        let mut by_column: HashMap<Uuid, Vec<_>> = HashMap::new();
+        for item in items {
+            // Tasks whose column went away show up in the first column matching their status
+            let column_id = item.task.column_id
+                .filter(|id| columns.iter().any(|c| c.id == *id))
+                .or_else(|| {
+                    columns.iter()
+                        .find(|c| c.status == item.task.status)
+                        .or(columns.first())
+                        .map(|c| c.id)
+                });
+
+            if let Some(column_id) = column_id {
+                by_column.entry(column_id).or_default().push(item);
+            }
+        }
+
+        let columns = columns
+            .into_iter()
+            .map(|column| BoardColumn {
+                tasks: by_column.remove(&column.id).unwrap_or_default(),
+                column,
+            })
+            .collect();
+
+        Ok(ProjectBoard { project, columns })
+    }
+
+    /// Drops a task into `column_id` at `position`, taking on the column's status.
+    /// A top-level task the user owns that isn't in any project yet is adopted
+    /// into this one.
+    pub async fn move_task(
+        &self,
+        project_id: Uuid,
+        task_id: Uuid,
+        user_id: Uuid,
+        column_id: Uuid,
+        position: u32,
+    ) -> Result<Task> {
+        let column = self.get_column(project_id, column_id, user_id).await?;
+        let mut task = self.task_service.get_task(user_id, task_id).await?;
+
+        if task.parent_task_id.is_some() {
+            return Err(AppError::BadRequest("Subtasks are not placed on the board".to_string()));
+        }
+        match task.project_id {
+            Some(id) if id == project_id => {}
+            None if task.user_id == user_id => {}
+            _ => return Err(AppError::BadRequest("Task belongs to another project".to_string())),
+        }
+
+        // Changing column may change status; this enforces blockers and cascades as usual
+        if column.status != task.status {
+            task = self.task_service
+                .update_status(user_id, task.id, UpdateTaskStatusRequest { status: column.status }, None)
+                .await?;
+        }
+
+        let rank = match self.rank_at(column.id, task.id, position).await? {
+            Some(rank) => rank,
+            None => {
+                // Ranks have run out of room at that spot; spread the column out and retry
+                self.repo.renumber_column(column.id).await?;
+                self.rank_at(column.id, task.id, position)
+                    .await?
+                    .ok_or(AppError::InternalError)?
+            }
+        };
+
+        let task = self.repo.set_task_position(task.id, project_id, column.id, rank).await?;
+
+        let _ = self.task_repo.log_task_activity(
+            task.id,
+            user_id,
+            "moved_on_board",
+            Some(serde_json::json!({"project_id": project_id, "column_id": column.id, "position": position})),
+        ).await;
+
+        Ok(task)
+    }
+
+    async fn rank_at(&self, column_id: Uuid, task_id: Uuid, position: u32) -> Result<Option<f64>> {
+        let ranks = self.repo.get_column_ranks(column_id, task_id).await?;
+        let index = (position as usize).min(ranks.len());
+
+        let prev = index.checked_sub(1).map(|i| ranks[i]);
+        let next = ranks.get(index).copied();
+
+        Ok(rank_between(prev, next))
+    }
+
+    async fn get_column(&self, project_id: Uuid, column_id: Uuid, user_id: Uuid) -> Result<ProjectColumn> {
+        self.get_project(project_id, user_id).await?;
+
+        self.repo
+            .find_column(project_id, column_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Column not found".to_string()))
+    }
+
+    async fn get_owned_project(&self, project_id: Uuid, user_id: Uuid, message: &str) -> Result<Project> {
+        let project = self.get_project(project_id, user_id).await?;
+
+        if project.owner_id != user_id {
+            return Err(AppError::Forbidden(message.to_string()));
+        }
+
+        Ok(project)
+    }
+}
@@ -12,6 +12,77 @@ use crate::{
         message_handlers,
         message_models::{Message, MessageResponse},
     },
+    label::{
+        label_handlers,
+        label_models::Label,
+        label_dto::{CreateLabelRequest, UpdateLabelRequest, AddTaskLabelRequest},
+    },
+    project::{
+        project_handlers,
+        project_models::{Project, ProjectMemberInfo, ProjectColumn, ProjectBoard, BoardColumn},
+        project_dto::{
+            CreateProjectRequest, UpdateProjectRequest, AddProjectMembersRequest,
+            CreateColumnRequest, UpdateColumnRequest, ReorderColumnsRequest, MoveTaskRequest,
+        },
+    },
+    attachment::{
+        attachment_handlers,
+        attachment_models::{Attachment, StorageUsage},
+        attachment_dto::AttachmentUpload,
+    },
+    time_entry::{
+        time_entry_handlers,
+        time_entry_models::{TimeEntry, UserTimeTotal, TaskTimeTotal, TaskTimeReport, UserTimeReport},
+        time_entry_dto::{CreateTimeEntryRequest, UpdateTimeEntryRequest},
+    },
+    search::{
+        search_handlers,
+        search_models::{SearchHit, SearchKind, SearchResponse},
+    },
+    checklist::{
+        checklist_handlers,
+        checklist_models::{ChecklistItem, ChecklistProgress},
+        checklist_dto::{
+            CreateChecklistItemRequest, UpdateChecklistItemRequest, BulkChecklistRequest, ReorderChecklistRequest,
+        },
+    },
+    share_link::{
+        share_link_handlers,
+        share_link_models::{ShareLinkAccess, ShareLinkResponse, PublicTaskView, PublicSubtask, PublicComment},
+        share_link_dto::{CreateShareLinkRequest, CreateGuestCommentRequest},
+    },
+    template::{
+        template_handlers,
+        template_models::{
+            TaskTemplate, TemplateFields, TemplateMember, TemplateVisibility, TemplateInstantiation,
+            InstantiateTemplatesResponse,
+        },
+        template_dto::{
+            CreateTemplateRequest, UpdateTemplateRequest, InstantiateTemplateRequest, InstantiateTemplatesRequest,
+        },
+    },
+    saved_view::{
+        saved_view_handlers,
+        saved_view_models::SavedView,
+        saved_view_dto::{CreateSavedViewRequest, UpdateSavedViewRequest},
+    },
+    calendar::{
+        calendar_handlers,
+        calendar_models::{CalendarComponent, CalendarFeedResponse},
+    },
+    transfer::{
+        transfer_handlers,
+        transfer_models::{
+            ExportedActivity, ExportedMember, ExportedTask, ImportJob, ImportJobStatus, ImportReport, ImportRowResult,
+            ImportSource, ImportSummary, SkippedImport, TransferFormat,
+        },
+        transfer_service::MAX_IMPORT_FILE_BYTES,
+    },
+    comment::{
+        comment_handlers,
+        comment_models::CommentResponse,
+        comment_dto::{CreateCommentRequest, UpdateCommentRequest},
+    },
     group::{
         group_handlers,
         group_models::{Group, GroupResponse, GroupMemberResponse},
@@ -30,9 +101,9 @@ use crate::{
     },
     state::AppState,
     task::{
-        task_dto::{CreateTaskRequest, UpdateTaskRequest, UpdateTaskStatusRequest},
+        task_dto::{CreateTaskRequest, UpdateTaskRequest, UpdateTaskStatusRequest, ReorderSubtasksRequest, AddTaskDependencyRequest, AssignTaskRequest, ShareTaskRequest, UpdateTaskMemberRequest, BulkTaskRequest},
         task_handlers,
-        task_models::{Task, TaskPriority, TaskStatus},
+        task_models::{Task, TaskPriority, TaskStatus, TaskListItem, SubtaskProgress, TaskDetail, TaskDependencyInfo, RecurrenceRule, RecurrenceFrequency, RecurrenceWeekday, TrashedTask, TaskSnapshot, TaskVersion, FieldChange, TaskVersionDiff, TaskAssignee, TaskMemberInfo, TaskRole, BulkTaskOperation, BulkTaskResult, BulkTaskResponse, TaskEvent, TaskEventKind},
     },
     user::{
         user_dto::{UpdateProfileRequest, UserStatsResponse},
@@ -41,11 +112,12 @@ use crate::{
     },
 };
 use axum::{
+    extract::DefaultBodyLimit,
     middleware,
     routing::{delete, get, patch, post, put},
     Router,
 };
-use axum::http::{header::{AUTHORIZATION, CONTENT_TYPE}, Method};
+use axum::http::{header::{AUTHORIZATION, CONTENT_TYPE}, HeaderName, Method};
 use tower_http::cors::{AllowOrigin, CorsLayer};
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
@@ -64,12 +136,28 @@ use utoipa_swagger_ui::SwaggerUi;
         crate::task::task_handlers::create_task,
         crate::task::task_handlers::update_task,
         crate::task::task_handlers::delete_task,
+        crate::task::task_handlers::bulk_update_tasks,
+        crate::task::task_handlers::get_trash,
+        crate::task::task_handlers::restore_task,
         crate::task::task_handlers::update_task_status,
         crate::task::task_handlers::task_stream,
         crate::task::task_handlers::share_task,
         crate::task::task_handlers::remove_task_member,
+        crate::task::task_handlers::update_task_member,
         crate::task::task_handlers::get_task_members,
         crate::task::task_handlers::get_task_activity,
+        crate::task::task_handlers::get_assigned_tasks,
+        crate::task::task_handlers::assign_task,
+        crate::task::task_handlers::unassign_task,
+        crate::task::task_handlers::get_task_assignees,
+        crate::task::task_handlers::get_task_versions,
+        crate::task::task_handlers::get_task_version,
+        crate::task::task_handlers::revert_task_version,
+        crate::task::task_handlers::create_subtask,
+        crate::task::task_handlers::list_subtasks,
+        crate::task::task_handlers::reorder_subtasks,
+        crate::task::task_handlers::add_task_dependency,
+        crate::task::task_handlers::remove_task_dependency,
         crate::notification::notification_handlers::get_notifications,
         crate::notification::notification_handlers::notification_stream,
         crate::notification::notification_handlers::mark_notification_read,
@@ -112,6 +200,82 @@ use utoipa_swagger_ui::SwaggerUi;
         crate::video_call::video_call_handlers::get_call_history,
         crate::video_call::video_call_handlers::get_active_calls,
         crate::video_call::video_call_handlers::add_participant,
+        crate::label::label_handlers::create_label,
+        crate::label::label_handlers::list_labels,
+        crate::label::label_handlers::update_label,
+        crate::label::label_handlers::delete_label,
+        crate::label::label_handlers::add_task_label,
+        crate::label::label_handlers::remove_task_label,
+        crate::project::project_handlers::create_project,
+        crate::project::project_handlers::list_projects,
+        crate::project::project_handlers::get_project,
+        crate::project::project_handlers::update_project,
+        crate::project::project_handlers::delete_project,
+        crate::project::project_handlers::add_project_members,
+        crate::project::project_handlers::list_project_members,
+        crate::project::project_handlers::remove_project_member,
+        crate::project::project_handlers::create_column,
+        crate::project::project_handlers::update_column,
+        crate::project::project_handlers::delete_column,
+        crate::project::project_handlers::reorder_columns,
+        crate::project::project_handlers::get_board,
+        crate::project::project_handlers::move_task,
+        crate::comment::comment_handlers::create_comment,
+        crate::comment::comment_handlers::list_comments,
+        crate::comment::comment_handlers::list_comment_replies,
+        crate::comment::comment_handlers::update_comment,
+        crate::comment::comment_handlers::delete_comment,
+        crate::attachment::attachment_handlers::upload_task_attachment,
+        crate::attachment::attachment_handlers::list_task_attachments,
+        crate::attachment::attachment_handlers::upload_message_attachment,
+        crate::attachment::attachment_handlers::list_message_attachments,
+        crate::attachment::attachment_handlers::get_attachment,
+        crate::attachment::attachment_handlers::download_attachment,
+        crate::attachment::attachment_handlers::delete_attachment,
+        crate::attachment::attachment_handlers::get_storage_usage,
+        crate::time_entry::time_entry_handlers::start_timer,
+        crate::time_entry::time_entry_handlers::stop_timer,
+        crate::time_entry::time_entry_handlers::create_time_entry,
+        crate::time_entry::time_entry_handlers::get_task_time,
+        crate::time_entry::time_entry_handlers::get_my_time,
+        crate::time_entry::time_entry_handlers::get_running_timer,
+        crate::time_entry::time_entry_handlers::update_time_entry,
+        crate::time_entry::time_entry_handlers::delete_time_entry,
+        crate::search::search_handlers::search,
+        crate::checklist::checklist_handlers::list_checklist,
+        crate::checklist::checklist_handlers::create_checklist_item,
+        crate::checklist::checklist_handlers::bulk_create_checklist_items,
+        crate::checklist::checklist_handlers::reorder_checklist,
+        crate::checklist::checklist_handlers::update_checklist_item,
+        crate::checklist::checklist_handlers::toggle_checklist_item,
+        crate::checklist::checklist_handlers::delete_checklist_item,
+        crate::share_link::share_link_handlers::create_share_link,
+        crate::share_link::share_link_handlers::list_share_links,
+        crate::share_link::share_link_handlers::revoke_share_link,
+        crate::share_link::share_link_handlers::view_shared_task,
+        crate::share_link::share_link_handlers::add_guest_comment,
+        crate::template::template_handlers::create_template,
+        crate::template::template_handlers::list_templates,
+        crate::template::template_handlers::get_template,
+        crate::template::template_handlers::update_template,
+        crate::template::template_handlers::delete_template,
+        crate::template::template_handlers::instantiate_template,
+        crate::template::template_handlers::instantiate_templates,
+        crate::saved_view::saved_view_handlers::create_view,
+        crate::saved_view::saved_view_handlers::list_views,
+        crate::saved_view::saved_view_handlers::get_view,
+        crate::saved_view::saved_view_handlers::update_view,
+        crate::saved_view::saved_view_handlers::delete_view,
+        crate::calendar::calendar_handlers::get_feed,
+        crate::calendar::calendar_handlers::regenerate_feed,
+        crate::calendar::calendar_handlers::revoke_feed,
+        crate::calendar::calendar_handlers::calendar_feed,
+        crate::transfer::transfer_handlers::export_tasks,
+        crate::transfer::transfer_handlers::import_tasks,
+        crate::transfer::transfer_handlers::import_trello,
+        crate::transfer::transfer_handlers::import_todoist,
+        crate::transfer::transfer_handlers::list_import_jobs,
+        crate::transfer::transfer_handlers::get_import_job,
     ),
     components(
         schemas(
@@ -123,6 +287,12 @@ use utoipa_swagger_ui::SwaggerUi;
             CreateTaskRequest,
             UpdateTaskRequest,
             UpdateTaskStatusRequest,
+            ReorderSubtasksRequest,
+            AddTaskDependencyRequest,
+            AssignTaskRequest,
+            ShareTaskRequest,
+            UpdateTaskMemberRequest,
+            BulkTaskRequest,
             UpdateNotificationPreferencesRequest,
             UpdateProfileRequest,
             UserStatsResponse,
@@ -144,12 +314,103 @@ use utoipa_swagger_ui::SwaggerUi;
             CallParticipantResponse,
             VideoCallResponse,
             CallStatus,
+            Label,
+            CreateLabelRequest,
+            UpdateLabelRequest,
+            AddTaskLabelRequest,
+            Project,
+            ProjectMemberInfo,
+            ProjectColumn,
+            ProjectBoard,
+            BoardColumn,
+            CreateProjectRequest,
+            UpdateProjectRequest,
+            AddProjectMembersRequest,
+            CreateColumnRequest,
+            UpdateColumnRequest,
+            ReorderColumnsRequest,
+            MoveTaskRequest,
+            CommentResponse,
+            CreateCommentRequest,
+            UpdateCommentRequest,
+            Attachment,
+            StorageUsage,
+            AttachmentUpload,
+            TimeEntry,
+            UserTimeTotal,
+            TaskTimeTotal,
+            TaskTimeReport,
+            UserTimeReport,
+            CreateTimeEntryRequest,
+            UpdateTimeEntryRequest,
+            SearchKind,
+            SearchHit,
+            SearchResponse,
+            ChecklistItem,
+            ChecklistProgress,
+            CreateChecklistItemRequest,
+            UpdateChecklistItemRequest,
+            BulkChecklistRequest,
+            ReorderChecklistRequest,
+            ShareLinkAccess,
+            ShareLinkResponse,
+            PublicTaskView,
+            PublicSubtask,
+            PublicComment,
+            CreateShareLinkRequest,
+            CreateGuestCommentRequest,
+            TaskTemplate,
+            TemplateFields,
+            TemplateMember,
+            TemplateVisibility,
+            TemplateInstantiation,
+            InstantiateTemplatesResponse,
+            CreateTemplateRequest,
+            UpdateTemplateRequest,
+            InstantiateTemplateRequest,
+            InstantiateTemplatesRequest,
+            SavedView,
+            CreateSavedViewRequest,
+            UpdateSavedViewRequest,
+            CalendarFeedResponse,
+            CalendarComponent,
+            TransferFormat,
+            ExportedTask,
+            ExportedMember,
+            ExportedActivity,
+            ImportReport,
+            ImportRowResult,
+            ImportJob,
+            ImportJobStatus,
+            ImportSource,
+            ImportSummary,
+            SkippedImport,
             admin_dto::AdminUpdateUserRequest,
             admin_dto::UpdateUserStatusRequest,
             admin_dto::UpdateAdminStatusRequest,
             User,
             UserResponse,
             Task,
+            TaskListItem,
+            TrashedTask,
+            TaskSnapshot,
+            TaskVersion,
+            FieldChange,
+            TaskVersionDiff,
+            TaskAssignee,
+            TaskMemberInfo,
+            TaskRole,
+            BulkTaskOperation,
+            BulkTaskResult,
+            BulkTaskResponse,
+            TaskEvent,
+            TaskEventKind,
+            SubtaskProgress,
+            TaskDetail,
+            TaskDependencyInfo,
+            RecurrenceRule,
+            RecurrenceFrequency,
+            RecurrenceWeekday,
             TaskStatus,
             TaskPriority,
             Notification,
@@ -165,7 +426,18 @@ use utoipa_swagger_ui::SwaggerUi;
         (name = "admin", description = "Admin user management endpoints"),
         (name = "messages", description = "User messaging endpoints"),
         (name = "groups", description = "Group chat endpoints"),
-        (name = "video-calls", description = "Video call endpoints")
+        (name = "video-calls", description = "Video call endpoints"),
+        (name = "labels", description = "Task label endpoints"),
+        (name = "projects", description = "Project and Kanban board endpoints"),
+        (name = "comments", description = "Task comment endpoints"),
+        (name = "attachments", description = "File attachment endpoints"),
+        (name = "time-tracking", description = "Timer and worklog endpoints"),
+        (name = "search", description = "Full-text search endpoints"),
+        (name = "share-links", description = "Public task share link endpoints"),
+        (name = "checklists", description = "Task checklist endpoints"),
+        (name = "templates", description = "Task template endpoints"),
+        (name = "views", description = "Saved task list view endpoints"),
+        (name = "calendar", description = "iCalendar feed endpoints")
     ),
     modifiers(&SecurityAddon)
 )]
@@ -208,7 +480,11 @@ pub fn create_router(state: AppState) -> Router {
             Method::PATCH,
             Method::OPTIONS,
         ])
-        .allow_headers([AUTHORIZATION, CONTENT_TYPE])
+        .allow_headers([
+            AUTHORIZATION,
+            CONTENT_TYPE,
+            HeaderName::from_static(share_link_handlers::SHARE_PASSWORD_HEADER),
+        ])
         .allow_credentials(true);
 
     // Public routes (no auth required)
@@ -220,10 +496,38 @@ pub fn create_router(state: AppState) -> Router {
         .route("/google", get(auth_handlers::google_login))
         .route("/google/callback", get(auth_handlers::google_callback));
 
+    // Share links are opened by people without an account, and calendar
+    // apps can't send an Authorization header
+    let public_routes = Router::new()
+        .route("/share/:token", get(share_link_handlers::view_shared_task))
+        .route("/share/:token/comments", post(share_link_handlers::add_guest_comment))
+        .route("/calendar/:token", get(calendar_handlers::calendar_feed));
+
+    // Uploads may exceed axum's default 2 MB body limit; leave headroom for multipart framing
+    let upload_body_limit = state.attachment_service.max_file_bytes() + 64 * 1024;
+
     // Protected routes (auth required)
     let task_routes = Router::new()
         .route("/", get(task_handlers::get_tasks).post(task_handlers::create_task))
         .route("/stream", get(task_handlers::task_stream))
+        .route("/trash", get(task_handlers::get_trash))
+        .route("/bulk", post(task_handlers::bulk_update_tasks))
+        .route("/export", get(transfer_handlers::export_tasks))
+        .route(
+            "/import",
+            post(transfer_handlers::import_tasks).layer(DefaultBodyLimit::max(MAX_IMPORT_FILE_BYTES)),
+        )
+        .route(
+            "/import/trello",
+            post(transfer_handlers::import_trello).layer(DefaultBodyLimit::max(MAX_IMPORT_FILE_BYTES)),
+        )
+        .route(
+            "/import/todoist",
+            post(transfer_handlers::import_todoist).layer(DefaultBodyLimit::max(MAX_IMPORT_FILE_BYTES)),
+        )
+        .route("/import/jobs", get(transfer_handlers::list_import_jobs))
+        .route("/import/jobs/:job_id", get(transfer_handlers::get_import_job))
+        .route("/assigned", get(task_handlers::get_assigned_tasks))
         .route(
             "/:id",
             get(task_handlers::get_task)
@@ -231,10 +535,59 @@ pub fn create_router(state: AppState) -> Router {
                 .delete(task_handlers::delete_task),
         )
         .route("/:id/status", patch(task_handlers::update_task_status))
+        .route("/:id/restore", post(task_handlers::restore_task))
         .route("/:id/share", post(task_handlers::share_task))
         .route("/:id/members", get(task_handlers::get_task_members))
-        .route("/:id/members/:user_id", delete(task_handlers::remove_task_member))
+        .route(
+            "/:id/members/:user_id",
+            delete(task_handlers::remove_task_member).patch(task_handlers::update_task_member),
+        )
         .route("/:id/activity", get(task_handlers::get_task_activity))
+        .route("/:id/assignees", get(task_handlers::get_task_assignees).post(task_handlers::assign_task))
+        .route("/:id/assignees/:user_id", delete(task_handlers::unassign_task))
+        .route("/:id/versions", get(task_handlers::get_task_versions))
+        .route("/:id/versions/:version", get(task_handlers::get_task_version))
+        .route("/:id/versions/:version/revert", post(task_handlers::revert_task_version))
+        .route("/:id/subtasks", get(task_handlers::list_subtasks).post(task_handlers::create_subtask))
+        .route("/:id/subtasks/order", put(task_handlers::reorder_subtasks))
+        .route("/:id/dependencies", post(task_handlers::add_task_dependency))
+        .route("/:id/dependencies/:blocked_by_task_id", delete(task_handlers::remove_task_dependency))
+        .route("/:id/labels", post(label_handlers::add_task_label))
+        .route("/:id/labels/:label_id", delete(label_handlers::remove_task_label))
+        .route("/:id/comments", get(comment_handlers::list_comments).post(comment_handlers::create_comment))
+        .route(
+            "/:id/comments/:comment_id",
+            put(comment_handlers::update_comment).delete(comment_handlers::delete_comment),
+        )
+        .route("/:id/comments/:comment_id/replies", get(comment_handlers::list_comment_replies))
+        .route(
+            "/:id/attachments",
+            get(attachment_handlers::list_task_attachments)
+                .post(attachment_handlers::upload_task_attachment)
+                .layer(DefaultBodyLimit::max(upload_body_limit)),
+        )
+        .route(
+            "/:id/checklist",
+            get(checklist_handlers::list_checklist).post(checklist_handlers::create_checklist_item),
+        )
+        .route("/:id/checklist/bulk", post(checklist_handlers::bulk_create_checklist_items))
+        .route("/:id/checklist/order", put(checklist_handlers::reorder_checklist))
+        .route(
+            "/:id/checklist/:item_id",
+            put(checklist_handlers::update_checklist_item).delete(checklist_handlers::delete_checklist_item),
+        )
+        .route("/:id/checklist/:item_id/toggle", post(checklist_handlers::toggle_checklist_item))
+        .route(
+            "/:id/share-links",
+            get(share_link_handlers::list_share_links).post(share_link_handlers::create_share_link),
+        )
+        .route("/:id/share-links/:link_id", delete(share_link_handlers::revoke_share_link))
+        .route("/:id/timer/start", post(time_entry_handlers::start_timer))
+        .route("/:id/timer/stop", post(time_entry_handlers::stop_timer))
+        .route(
+            "/:id/time-entries",
+            get(time_entry_handlers::get_task_time).post(time_entry_handlers::create_time_entry),
+        )
         .route_layer(middleware::from_fn_with_state(
             state.clone(),
             auth_middleware,
@@ -295,6 +648,12 @@ pub fn create_router(state: AppState) -> Router {
         .route("/groups/:group_id", get(message_handlers::get_group_messages))
         .route("/:id", put(message_handlers::update_message).delete(message_handlers::delete_message))
         .route("/:id/read", patch(message_handlers::mark_message_read))
+        .route(
+            "/:id/attachments",
+            get(attachment_handlers::list_message_attachments)
+                .post(attachment_handlers::upload_message_attachment)
+                .layer(DefaultBodyLimit::max(upload_body_limit)),
+        )
         .route_layer(middleware::from_fn_with_state(
             state.clone(),
             auth_middleware,
@@ -311,6 +670,102 @@ pub fn create_router(state: AppState) -> Router {
             auth_middleware,
         ));
 
+    // Attachment routes
+    let attachment_routes = Router::new()
+        .route("/usage", get(attachment_handlers::get_storage_usage))
+        .route("/:id", get(attachment_handlers::get_attachment).delete(attachment_handlers::delete_attachment))
+        .route("/:id/download", get(attachment_handlers::download_attachment))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            auth_middleware,
+        ));
+
+    // Time tracking routes
+    let time_entry_routes = Router::new()
+        .route("/", get(time_entry_handlers::get_my_time))
+        .route("/running", get(time_entry_handlers::get_running_timer))
+        .route("/:id", put(time_entry_handlers::update_time_entry).delete(time_entry_handlers::delete_time_entry))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            auth_middleware,
+        ));
+
+    // Search routes
+    let search_routes = Router::new()
+        .route("/", get(search_handlers::search))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            auth_middleware,
+        ));
+
+    // Label routes
+    let label_routes = Router::new()
+        .route("/", post(label_handlers::create_label).get(label_handlers::list_labels))
+        .route("/:label_id", put(label_handlers::update_label).delete(label_handlers::delete_label))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            auth_middleware,
+        ));
+
+    // Project routes
+    let project_routes = Router::new()
+        .route("/", post(project_handlers::create_project).get(project_handlers::list_projects))
+        .route("/:project_id", get(project_handlers::get_project).put(project_handlers::update_project).delete(project_handlers::delete_project))
+        .route("/:project_id/members", get(project_handlers::list_project_members).post(project_handlers::add_project_members))
+        .route("/:project_id/members/:user_id", delete(project_handlers::remove_project_member))
+        .route("/:project_id/columns", post(project_handlers::create_column))
+        .route("/:project_id/columns/order", put(project_handlers::reorder_columns))
+        .route("/:project_id/columns/:column_id", put(project_handlers::update_column).delete(project_handlers::delete_column))
+        .route("/:project_id/board", get(project_handlers::get_board))
+        .route("/:project_id/tasks/:task_id/move", put(project_handlers::move_task))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            auth_middleware,
+        ));
+
+    // Template routes
+    let template_routes = Router::new()
+        .route("/", post(template_handlers::create_template).get(template_handlers::list_templates))
+        .route("/instantiate", post(template_handlers::instantiate_templates))
+        .route(
+            "/:template_id",
+            get(template_handlers::get_template)
+                .put(template_handlers::update_template)
+                .delete(template_handlers::delete_template),
+        )
+        .route("/:template_id/instantiate", post(template_handlers::instantiate_template))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            auth_middleware,
+        ));
+
+    // Saved view routes
+    let saved_view_routes = Router::new()
+        .route("/", post(saved_view_handlers::create_view).get(saved_view_handlers::list_views))
+        .route(
+            "/:view_id",
+            get(saved_view_handlers::get_view)
+                .put(saved_view_handlers::update_view)
+                .delete(saved_view_handlers::delete_view),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            auth_middleware,
+        ));
+
+    // Calendar feed routes
+    let calendar_routes = Router::new()
+        .route(
+            "/feed",
+            get(calendar_handlers::get_feed)
+                .post(calendar_handlers::regenerate_feed)
+                .delete(calendar_handlers::revoke_feed),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            auth_middleware,
+        ));
+
     // Video call routes
     let video_call_routes = Router::new()
         .route("/", post(video_call_handlers::initiate_call).get(video_call_handlers::get_call_history))
@@ -373,12 +828,21 @@ pub fn create_router(state: AppState) -> Router {
 
     let api_routes = Router::new()
         .nest("/auth", auth_routes)
+        .nest("/public", public_routes)
         .nest("/tasks", task_routes)
         .nest("/notifications", notification_routes)
         .nest("/users", user_routes)
         .nest("/admin", admin_routes.merge(admin_register_route))
         .nest("/messages", message_routes)
         .nest("/groups", group_routes)
+        .nest("/labels", label_routes)
+        .nest("/attachments", attachment_routes)
+        .nest("/time-entries", time_entry_routes)
+        .nest("/search", search_routes)
+        .nest("/projects", project_routes)
+        .nest("/templates", template_routes)
+        .nest("/views", saved_view_routes)
+        .nest("/calendar", calendar_routes)
         .nest("/video-calls", video_call_routes)
         .merge(ws_routes)
         .merge(redis_test_route);
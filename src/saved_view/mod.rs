@@ -0,0 +1,6 @@
+// Declare submodules
+pub mod saved_view_models;
+pub mod saved_view_dto;
+pub mod saved_view_repository;
+pub mod saved_view_service;
+pub mod saved_view_handlers;
@@ -0,0 +1,32 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::task::task_handlers::TaskFilters;
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateSavedViewRequest {
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+    /// Same fields as the `GET /api/tasks` query parameters; `page` is not kept
+    #[serde(default)]
+    #[schema(value_type = Object)]
+    pub filters: TaskFilters,
+    /// Share the view with every member of this group
+    pub group_id: Option<Uuid>,
+}
+
+/// Fields left out keep their value; `filters` given replaces the old ones.
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateSavedViewRequest {
+    #[validate(length(min = 1, max = 100))]
+    pub name: Option<String>,
+    #[schema(value_type = Option<Object>)]
+    pub filters: Option<TaskFilters>,
+    /// Share the view with every member of this group
+    pub group_id: Option<Uuid>,
+    /// Stop sharing the view with its group
+    #[serde(default)]
+    pub unshare: bool,
+}
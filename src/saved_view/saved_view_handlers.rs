@@ -0,0 +1,155 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    error::Result,
+    middleware::{AppJson, AuthUser},
+    saved_view::saved_view_dto::{CreateSavedViewRequest, UpdateSavedViewRequest},
+    state::AppState,
+};
+
+/// Save a task list view
+#[utoipa::path(
+    post,
+    path = "/api/views",
+    tag = "views",
+    request_body = CreateSavedViewRequest,
+    responses(
+        (status = 201, description = "View created", body = SavedView),
+        (status = 400, description = "Validation error or invalid filter"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Not a member of the group"),
+        (status = 409, description = "You already have a view with this name")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create_view(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    AppJson(payload): AppJson<CreateSavedViewRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+
+    let view = state.saved_view_service.create_view(user_id, payload).await?;
+
+    Ok((StatusCode::CREATED, Json(view)))
+}
+
+/// List your own views and those shared with your groups
+#[utoipa::path(
+    get,
+    path = "/api/views",
+    tag = "views",
+    responses(
+        (status = 200, description = "Views retrieved", body = Vec<SavedView>),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_views(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+) -> Result<impl IntoResponse> {
+    let views = state.saved_view_service.list_views(user_id).await?;
+
+    Ok((StatusCode::OK, Json(views)))
+}
+
+/// Get a saved view
+#[utoipa::path(
+    get,
+    path = "/api/views/{view_id}",
+    tag = "views",
+    params(
+        ("view_id" = Uuid, Path, description = "View ID")
+    ),
+    responses(
+        (status = 200, description = "View retrieved", body = SavedView),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "View not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_view(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(view_id): Path<Uuid>,
+) -> Result<impl IntoResponse> {
+    let view = state.saved_view_service.get_view(view_id, user_id).await?;
+
+    Ok((StatusCode::OK, Json(view)))
+}
+
+/// Update a saved view (owner only)
+#[utoipa::path(
+    put,
+    path = "/api/views/{view_id}",
+    tag = "views",
+    params(
+        ("view_id" = Uuid, Path, description = "View ID")
+    ),
+    request_body = UpdateSavedViewRequest,
+    responses(
+        (status = 200, description = "View updated", body = SavedView),
+        (status = 400, description = "Validation error or invalid filter"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Not the view owner"),
+        (status = 404, description = "View not found"),
+        (status = 409, description = "You already have a view with this name")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn update_view(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(view_id): Path<Uuid>,
+    AppJson(payload): AppJson<UpdateSavedViewRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+
+    let view = state.saved_view_service.update_view(view_id, user_id, payload).await?;
+
+    Ok((StatusCode::OK, Json(view)))
+}
+
+/// Delete a saved view (owner only)
+#[utoipa::path(
+    delete,
+    path = "/api/views/{view_id}",
+    tag = "views",
+    params(
+        ("view_id" = Uuid, Path, description = "View ID")
+    ),
+    responses(
+        (status = 204, description = "View deleted"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Not the view owner"),
+        (status = 404, description = "View not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_view(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(view_id): Path<Uuid>,
+) -> Result<impl IntoResponse> {
+    state.saved_view_service.delete_view(view_id, user_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
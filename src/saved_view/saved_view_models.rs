@@ -0,0 +1,62 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::types::Json;
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::task::task_handlers::TaskFilters;
+
+/// A named set of task list filters, including sort order and page size.
+#[derive(Debug, Clone, Serialize, FromRow, ToSchema)]
+pub struct SavedView {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    /// Same fields as the `GET /api/tasks` query parameters, minus `page`
+    #[schema(value_type = Object)]
+    pub filters: Json<TaskFilters>,
+    /// Group whose members can also use the view
+    pub group_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::task::task_models::{TaskPriority, TaskStatus};
+
+    #[test]
+    fn test_query_overrides_saved_filters() {
+        let saved: TaskFilters = serde_json::from_value(serde_json::json!({
+            "statuses": ["Pending", "InProgress"],
+            "priority": "High",
+            "sort_by": "due_date",
+            "limit": 50,
+            "assignee": "me"
+        }))
+        .unwrap();
+        let query = TaskFilters {
+            status: Some(TaskStatus::Completed),
+            limit: Some(20),
+            ..Default::default()
+        };
+
+        let merged = query.or(saved);
+
+        assert_eq!(merged.status, Some(TaskStatus::Completed));
+        assert!(merged.statuses.is_none());
+        assert_eq!(merged.priority, Some(TaskPriority::High));
+        assert_eq!(merged.sort_by.as_deref(), Some("due_date"));
+        assert_eq!(merged.limit, Some(20));
+        assert_eq!(merged.assignee.as_deref(), Some("me"));
+    }
+
+    #[test]
+    fn test_saved_filters_reject_unknown_status() {
+        let saved = serde_json::from_value::<TaskFilters>(serde_json::json!({ "status": "done" }));
+
+        assert!(saved.is_err());
+    }
+}
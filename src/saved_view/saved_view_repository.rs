@@ -0,0 +1,131 @@
+use crate::error::Result;
+use crate::task::task_handlers::TaskFilters;
+use sqlx::types::Json;
+use sqlx::PgPool;
+use uuid::Uuid;
+use super::saved_view_models::SavedView;
+
+#[derive(Clone)]
+pub struct SavedViewRepository {
+    pool: PgPool,
+}
+
+impl SavedViewRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn create(
+        &self,
+        user_id: Uuid,
+        name: &str,
+        filters: &TaskFilters,
+        group_id: Option<Uuid>,
+    ) -> Result<SavedView> {
+        let view = sqlx::query_as::<_, SavedView>(
+            "INSERT INTO saved_views (user_id, name, filters, group_id)
+             VALUES ($1, $2, $3, $4)
+             RETURNING *"
+        )
+        .bind(user_id)
+        .bind(name)
+        .bind(Json(filters))
+        .bind(group_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(view)
+    }
+
+    pub async fn find_by_id(&self, id: Uuid) -> Result<Option<SavedView>> {
+        let view = sqlx::query_as::<_, SavedView>("SELECT * FROM saved_views WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(view)
+    }
+
+    pub async fn find_by_name(&self, user_id: Uuid, name: &str) -> Result<Option<SavedView>> {
+        let view = sqlx::query_as::<_, SavedView>(
+            "SELECT * FROM saved_views WHERE user_id = $1 AND name = $2"
+        )
+        .bind(user_id)
+        .bind(name)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(view)
+    }
+
+    /// Views the user owns or can reach through a group, their own first.
+    pub async fn find_usable(&self, user_id: Uuid) -> Result<Vec<SavedView>> {
+        let views = sqlx::query_as::<_, SavedView>(
+            "SELECT * FROM saved_views v
+             WHERE v.user_id = $1
+             OR EXISTS (
+                 SELECT 1 FROM group_members gm WHERE gm.group_id = v.group_id AND gm.user_id = $1
+             )
+             ORDER BY (v.user_id = $1) DESC, v.name ASC"
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(views)
+    }
+
+    pub async fn can_use(&self, id: Uuid, user_id: Uuid) -> Result<bool> {
+        let can_use: bool = sqlx::query_scalar(
+            "SELECT EXISTS (
+                 SELECT 1 FROM saved_views v
+                 WHERE v.id = $1
+                 AND (v.user_id = $2
+                      OR EXISTS (
+                          SELECT 1 FROM group_members gm WHERE gm.group_id = v.group_id AND gm.user_id = $2
+                      ))
+             )"
+        )
+        .bind(id)
+        .bind(user_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(can_use)
+    }
+
+    pub async fn update(
+        &self,
+        id: Uuid,
+        name: &str,
+        filters: &TaskFilters,
+        group_id: Option<Uuid>,
+    ) -> Result<SavedView> {
+        let view = sqlx::query_as::<_, SavedView>(
+            "UPDATE saved_views SET
+                name = $2,
+                filters = $3,
+                group_id = $4,
+                updated_at = NOW()
+             WHERE id = $1
+             RETURNING *"
+        )
+        .bind(id)
+        .bind(name)
+        .bind(Json(filters))
+        .bind(group_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(view)
+    }
+
+    pub async fn delete(&self, id: Uuid) -> Result<()> {
+        sqlx::query("DELETE FROM saved_views WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+use crate::error::{AppError, Result};
+use crate::group::group_repository::GroupRepository;
+use crate::task::task_handlers::TaskFilters;
+use uuid::Uuid;
+use super::saved_view_dto::{CreateSavedViewRequest, UpdateSavedViewRequest};
+use super::saved_view_models::SavedView;
+use super::saved_view_repository::SavedViewRepository;
+
+/// Service layer for saved task list views.
+///
+/// Group members can list tasks through a shared view; only its owner can
+/// change or delete it.
+#[derive(Clone)]
+pub struct SavedViewService {
+    repo: SavedViewRepository,
+    group_repo: GroupRepository,
+}
+
+impl SavedViewService {
+    pub fn new(repo: SavedViewRepository, group_repo: GroupRepository) -> Self {
+        Self { repo, group_repo }
+    }
+
+    pub async fn create_view(&self, user_id: Uuid, payload: CreateSavedViewRequest) -> Result<SavedView> {
+        self.ensure_name_free(user_id, &payload.name, None).await?;
+        let filters = self.check_filters(user_id, payload.filters)?;
+        self.check_group(user_id, payload.group_id).await?;
+
+        self.repo.create(user_id, &payload.name, &filters, payload.group_id).await
+    }
+
+    pub async fn list_views(&self, user_id: Uuid) -> Result<Vec<SavedView>> {
+        self.repo.find_usable(user_id).await
+    }
+
+    /// A view the user can use. Views they can't see are reported as missing.
+    pub async fn get_view(&self, view_id: Uuid, user_id: Uuid) -> Result<SavedView> {
+        let view = self.repo.find_by_id(view_id).await?;
+
+        match view {
+            Some(view) if self.repo.can_use(view.id, user_id).await? => Ok(view),
+            _ => Err(AppError::NotFound("Saved view not found".to_string())),
+        }
+    }
+
+    pub async fn update_view(
+        &self,
+        view_id: Uuid,
+        user_id: Uuid,
+        payload: UpdateSavedViewRequest,
+    ) -> Result<SavedView> {
+        let view = self.get_owned_view(view_id, user_id, "Only the view owner can change it").await?;
+
+        let name = match payload.name {
+            Some(name) => {
+                self.ensure_name_free(user_id, &name, Some(view_id)).await?;
+                name
+            }
+            None => view.name,
+        };
+        let filters = match payload.filters {
+            Some(filters) => self.check_filters(user_id, filters)?,
+            None => view.filters.0,
+        };
+        let group_id = if payload.unshare {
+            None
+        } else if payload.group_id.is_some() {
+            self.check_group(user_id, payload.group_id).await?;
+            payload.group_id
+        } else {
+            view.group_id
+        };
+
+        self.repo.update(view_id, &name, &filters, group_id).await
+    }
+
+    pub async fn delete_view(&self, view_id: Uuid, user_id: Uuid) -> Result<()> {
+        self.get_owned_view(view_id, user_id, "Only the view owner can delete it").await?;
+        self.repo.delete(view_id).await
+    }
+
+    async fn get_owned_view(&self, view_id: Uuid, user_id: Uuid, denied: &str) -> Result<SavedView> {
+        let view = self.get_view(view_id, user_id).await?;
+        if view.user_id != user_id {
+            return Err(AppError::Forbidden(denied.to_string()));
+        }
+        Ok(view)
+    }
+
+    async fn ensure_name_free(&self, user_id: Uuid, name: &str, view_id: Option<Uuid>) -> Result<()> {
+        if let Some(existing) = self.repo.find_by_name(user_id, name).await? {
+            if Some(existing.id) != view_id {
+                return Err(AppError::Conflict(format!("You already have a view named '{}'", name)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Validates filters before they are saved. The page is not part of a view.
+    fn check_filters(&self, user_id: Uuid, mut filters: TaskFilters) -> Result<TaskFilters> {
+        filters.check(user_id)?;
+        filters.page = None;
+        Ok(filters)
+    }
+
+    async fn check_group(&self, user_id: Uuid, group_id: Option<Uuid>) -> Result<()> {
+        if let Some(group_id) = group_id {
+            if !self.group_repo.is_member(group_id, user_id).await? {
+                return Err(AppError::Forbidden("You are not a member of this group".to_string()));
+            }
+        }
+        Ok(())
+    }
+}
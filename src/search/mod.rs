@@ -0,0 +1,5 @@
+// Declare submodules
+pub mod search_models;
+pub mod search_repository;
+pub mod search_service;
+pub mod search_handlers;
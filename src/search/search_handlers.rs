@@ -0,0 +1,68 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::Deserialize;
+
+use crate::{
+    error::{AppError, Result},
+    middleware::AuthUser,
+    search::search_models::SearchKind,
+    state::AppState,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct SearchQuery {
+    q: String,
+    types: Option<String>,
+    limit: Option<u32>,
+}
+
+/// Search tasks, messages, comments and groups you have access to
+#[utoipa::path(
+    get,
+    path = "/api/search",
+    tag = "search",
+    params(
+        ("q" = String, Query, description = "Search text; every word is matched as a prefix"),
+        ("types" = Option<String>, Query, description = "Comma-separated kinds to search: tasks, messages, comments, groups (default: all)"),
+        ("limit" = Option<u32>, Query, description = "Maximum number of hits (default: 20, max: 50)")
+    ),
+    responses(
+        (status = 200, description = "Ranked search hits", body = SearchResponse),
+        (status = 400, description = "Empty query or unknown type"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn search(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Query(query): Query<SearchQuery>,
+) -> Result<impl IntoResponse> {
+    if query.q.chars().count() > 200 {
+        return Err(AppError::BadRequest("Search query can be at most 200 characters".to_string()));
+    }
+
+    let mut kinds = Vec::new();
+    for name in query.types.as_deref().unwrap_or_default().split(',').filter(|name| !name.trim().is_empty()) {
+        let kind = SearchKind::parse(name)
+            .ok_or_else(|| AppError::BadRequest(format!("Unknown search type '{}'", name.trim())))?;
+        if !kinds.contains(&kind) {
+            kinds.push(kind);
+        }
+    }
+    if kinds.is_empty() {
+        kinds = SearchKind::ALL.to_vec();
+    }
+
+    let response = state.search_service
+        .search(user_id, &query.q, &kinds, query.limit)
+        .await?;
+
+    Ok((StatusCode::OK, Json(response)))
+}
@@ -0,0 +1,134 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Longest query, in terms, that is turned into a tsquery.
+pub const MAX_QUERY_TERMS: usize = 8;
+
+/// Markers `ts_headline` wraps matches in. They are private-use characters so
+/// they cannot collide with user text, and are turned into `<mark>` tags only
+/// after the rest of the snippet has been HTML-escaped.
+pub const HIGHLIGHT_START: char = '\u{E000}';
+pub const HIGHLIGHT_STOP: char = '\u{E001}';
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "text", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum SearchKind {
+    Task,
+    Message,
+    Comment,
+    Group,
+}
+
+impl SearchKind {
+    pub const ALL: [SearchKind; 4] = [SearchKind::Task, SearchKind::Message, SearchKind::Comment, SearchKind::Group];
+
+    /// Accepts the singular or plural name, e.g. `task` or `tasks`.
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().trim_end_matches('s') {
+            "task" => Some(SearchKind::Task),
+            "message" => Some(SearchKind::Message),
+            "comment" => Some(SearchKind::Comment),
+            "group" => Some(SearchKind::Group),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct SearchHit {
+    pub kind: SearchKind,
+    /// ID of the task, message, comment or group
+    pub id: Uuid,
+    /// Task title, group name, or message sender's username
+    pub title: String,
+    /// HTML snippet with matches wrapped in `<mark>` tags
+    pub highlight: String,
+    pub rank: f32,
+    /// The task a task or comment hit belongs to
+    pub task_id: Option<Uuid>,
+    /// The group a group or group message hit belongs to
+    pub group_id: Option<Uuid>,
+    /// The other participant of a direct message hit
+    pub conversation_user_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SearchResponse {
+    pub query: String,
+    pub results: Vec<SearchHit>,
+}
+
+/// Turns free text into a `to_tsquery` expression that matches every word as a
+/// prefix, e.g. `"deploy stag"` becomes `deploy:* & stag:*`. Only letters and
+/// digits survive, so user input can never inject tsquery operators. Returns
+/// `None` when nothing searchable is left.
+pub fn to_prefix_tsquery(input: &str) -> Option<String> {
+    let terms: Vec<String> = input
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|term| !term.is_empty())
+        .take(MAX_QUERY_TERMS)
+        .map(|term| format!("{}:*", term.to_lowercase()))
+        .collect();
+
+    if terms.is_empty() {
+        None
+    } else {
+        Some(terms.join(" & "))
+    }
+}
+
+/// HTML-escapes a `ts_headline` snippet and swaps its match markers for `<mark>` tags.
+pub fn render_highlight(raw: &str) -> String {
+    let mut html = String::with_capacity(raw.len());
+    for c in raw.chars() {
+        match c {
+            HIGHLIGHT_START => html.push_str("<mark>"),
+            HIGHLIGHT_STOP => html.push_str("</mark>"),
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            '\'' => html.push_str("&#39;"),
+            _ => html.push(c),
+        }
+    }
+    html
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_prefix_tsquery_keeps_only_words() {
+        assert_eq!(to_prefix_tsquery("Deploy stag").as_deref(), Some("deploy:* & stag:*"));
+        assert_eq!(to_prefix_tsquery("a|b & !c:*").as_deref(), Some("a:* & b:* & c:*"));
+        assert_eq!(to_prefix_tsquery("café-2"), Some("café:* & 2:*".to_string()));
+        assert_eq!(to_prefix_tsquery(" ()&|! "), None);
+        assert_eq!(
+            to_prefix_tsquery("1 2 3 4 5 6 7 8 9 10").map(|q| q.matches(":*").count()),
+            Some(MAX_QUERY_TERMS)
+        );
+    }
+
+    #[test]
+    fn render_highlight_escapes_everything_but_markers() {
+        let raw = format!("<b>{}fix{}</b> & \"ship\" <mark>", HIGHLIGHT_START, HIGHLIGHT_STOP);
+        assert_eq!(
+            render_highlight(&raw),
+            "&lt;b&gt;<mark>fix</mark>&lt;/b&gt; &amp; &quot;ship&quot; &lt;mark&gt;"
+        );
+    }
+
+    #[test]
+    fn search_kind_accepts_singular_and_plural() {
+        assert_eq!(SearchKind::parse("Tasks"), Some(SearchKind::Task));
+        assert_eq!(SearchKind::parse(" comment "), Some(SearchKind::Comment));
+        assert_eq!(SearchKind::parse("users"), None);
+    }
+}
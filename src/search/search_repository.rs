@@ -0,0 +1,109 @@
+use crate::error::Result;
+use sqlx::PgPool;
+use uuid::Uuid;
+use super::search_models::{SearchHit, HIGHLIGHT_START, HIGHLIGHT_STOP};
+
+// Every search query binds: $1 user_id, $2 tsquery, $3 ts_headline options, $4 limit.
+
+const TASK_SEARCH: &str =
+    "SELECT 'task' AS kind, t.id, t.title,
+            ts_headline('english', t.title || E'\\n' || COALESCE(t.description, ''), q, $3) AS highlight,
+            ts_rank(t.search_vector, q) AS rank,
+            t.id AS task_id, NULL::uuid AS group_id, NULL::uuid AS conversation_user_id, t.created_at
+     FROM tasks t
+     CROSS JOIN to_tsquery('english', $2) q
+     WHERE t.search_vector @@ q
+     AND user_has_task_access(t.id, $1)
+     ORDER BY rank DESC, t.created_at DESC
+     LIMIT $4";
+
+// Direct messages are visible to their two participants, group messages to group members
+const MESSAGE_SEARCH: &str =
+    "SELECT 'message' AS kind, m.id, u.username AS title,
+            ts_headline('english', m.content, q, $3) AS highlight,
+            ts_rank(m.search_vector, q) AS rank,
+            NULL::uuid AS task_id, m.group_id,
+            CASE WHEN m.group_id IS NULL THEN
+                CASE WHEN m.sender_id = $1 THEN m.receiver_id ELSE m.sender_id END
+            END AS conversation_user_id,
+            m.created_at
+     FROM messages m
+     CROSS JOIN to_tsquery('english', $2) q
+     INNER JOIN users u ON u.id = m.sender_id
+     WHERE m.search_vector @@ q
+     AND (
+         (m.group_id IS NULL AND (m.sender_id = $1 OR m.receiver_id = $1))
+         OR EXISTS (SELECT 1 FROM group_members gm WHERE gm.group_id = m.group_id AND gm.user_id = $1)
+     )
+     ORDER BY rank DESC, m.created_at DESC
+     LIMIT $4";
+
+const COMMENT_SEARCH: &str =
+    "SELECT 'comment' AS kind, c.id, t.title,
+            ts_headline('english', c.content, q, $3) AS highlight,
+            ts_rank(c.search_vector, q) AS rank,
+            c.task_id, NULL::uuid AS group_id, NULL::uuid AS conversation_user_id, c.created_at
+     FROM task_comments c
+     CROSS JOIN to_tsquery('english', $2) q
+     INNER JOIN tasks t ON t.id = c.task_id
+     WHERE c.search_vector @@ q
+     AND c.deleted_at IS NULL
+     AND user_has_task_access(c.task_id, $1)
+     ORDER BY rank DESC, c.created_at DESC
+     LIMIT $4";
+
+const GROUP_SEARCH: &str =
+    "SELECT 'group' AS kind, g.id, g.name AS title,
+            ts_headline('english', g.name || E'\\n' || COALESCE(g.description, ''), q, $3) AS highlight,
+            ts_rank(g.search_vector, q) AS rank,
+            NULL::uuid AS task_id, g.id AS group_id, NULL::uuid AS conversation_user_id, g.created_at
+     FROM groups g
+     CROSS JOIN to_tsquery('english', $2) q
+     INNER JOIN group_members gm ON gm.group_id = g.id AND gm.user_id = $1
+     WHERE g.search_vector @@ q
+     ORDER BY rank DESC, g.created_at DESC
+     LIMIT $4";
+
+#[derive(Clone)]
+pub struct SearchRepository {
+    pool: PgPool,
+}
+
+impl SearchRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn search_tasks(&self, user_id: Uuid, tsquery: &str, limit: i64) -> Result<Vec<SearchHit>> {
+        self.run(TASK_SEARCH, user_id, tsquery, limit).await
+    }
+
+    pub async fn search_messages(&self, user_id: Uuid, tsquery: &str, limit: i64) -> Result<Vec<SearchHit>> {
+        self.run(MESSAGE_SEARCH, user_id, tsquery, limit).await
+    }
+
+    pub async fn search_comments(&self, user_id: Uuid, tsquery: &str, limit: i64) -> Result<Vec<SearchHit>> {
+        self.run(COMMENT_SEARCH, user_id, tsquery, limit).await
+    }
+
+    pub async fn search_groups(&self, user_id: Uuid, tsquery: &str, limit: i64) -> Result<Vec<SearchHit>> {
+        self.run(GROUP_SEARCH, user_id, tsquery, limit).await
+    }
+
+    async fn run(&self, sql: &str, user_id: Uuid, tsquery: &str, limit: i64) -> Result<Vec<SearchHit>> {
+        let headline_options = format!(
+            "StartSel={}, StopSel={}, MaxWords=30, MinWords=10, MaxFragments=2, FragmentDelimiter=\" … \"",
+            HIGHLIGHT_START, HIGHLIGHT_STOP
+        );
+
+        let hits = sqlx::query_as::<_, SearchHit>(sql)
+            .bind(user_id)
+            .bind(tsquery)
+            .bind(headline_options)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(hits)
+    }
+}
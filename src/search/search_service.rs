@@ -0,0 +1,58 @@
+use crate::error::{AppError, Result};
+use uuid::Uuid;
+use super::search_models::{render_highlight, to_prefix_tsquery, SearchHit, SearchKind, SearchResponse};
+use super::search_repository::SearchRepository;
+
+pub const DEFAULT_SEARCH_LIMIT: u32 = 20;
+pub const MAX_SEARCH_LIMIT: u32 = 50;
+
+#[derive(Clone)]
+pub struct SearchService {
+    repo: SearchRepository,
+}
+
+impl SearchService {
+    pub fn new(repo: SearchRepository) -> Self {
+        Self { repo }
+    }
+
+    /// Searches everything of the given kinds the user can see, best matches first.
+    pub async fn search(
+        &self,
+        user_id: Uuid,
+        query: &str,
+        kinds: &[SearchKind],
+        limit: Option<u32>,
+    ) -> Result<SearchResponse> {
+        let tsquery = to_prefix_tsquery(query)
+            .ok_or_else(|| AppError::BadRequest("Search query must contain letters or numbers".to_string()))?;
+        let limit = limit.unwrap_or(DEFAULT_SEARCH_LIMIT).clamp(1, MAX_SEARCH_LIMIT) as usize;
+
+        // Each kind is capped at the overall limit, so the merged list always holds the top hits
+        let mut results: Vec<SearchHit> = Vec::new();
+        for kind in kinds {
+            let hits = match kind {
+                SearchKind::Task => self.repo.search_tasks(user_id, &tsquery, limit as i64).await?,
+                SearchKind::Message => self.repo.search_messages(user_id, &tsquery, limit as i64).await?,
+                SearchKind::Comment => self.repo.search_comments(user_id, &tsquery, limit as i64).await?,
+                SearchKind::Group => self.repo.search_groups(user_id, &tsquery, limit as i64).await?,
+            };
+            results.extend(hits);
+        }
+
+        results.sort_by(|a, b| {
+            b.rank
+                .total_cmp(&a.rank)
+                .then_with(|| b.created_at.cmp(&a.created_at))
+        });
+        results.truncate(limit);
+        for hit in &mut results {
+            hit.highlight = render_highlight(&hit.highlight);
+        }
+
+        Ok(SearchResponse {
+            query: query.to_string(),
+            results,
+        })
+    }
+}
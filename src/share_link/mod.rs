@@ -0,0 +1,6 @@
+// Declare submodules
+pub mod share_link_models;
+pub mod share_link_dto;
+pub mod share_link_repository;
+pub mod share_link_service;
+pub mod share_link_handlers;
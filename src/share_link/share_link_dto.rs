@@ -0,0 +1,29 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+use super::share_link_models::ShareLinkAccess;
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateShareLinkRequest {
+    /// Defaults to read
+    pub access: Option<ShareLinkAccess>,
+    /// The link stops working after this time
+    pub expires_at: Option<DateTime<Utc>>,
+    /// Visitors must send this in the `X-Share-Password` header
+    #[validate(length(min = 4, max = 128))]
+    pub password: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateGuestCommentRequest {
+    /// Name shown next to the comment
+    #[validate(length(min = 1, max = 100))]
+    pub author_name: String,
+    #[validate(length(min = 1, max = 10000))]
+    pub content: String,
+    /// Reply to this comment instead of starting a new thread
+    pub parent_comment_id: Option<Uuid>,
+}
@@ -0,0 +1,170 @@
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    error::Result,
+    middleware::{AppJson, AuthUser},
+    state::AppState,
+    share_link::share_link_dto::{CreateGuestCommentRequest, CreateShareLinkRequest},
+};
+
+/// Header carrying the password of a protected share link
+pub const SHARE_PASSWORD_HEADER: &str = "x-share-password";
+
+fn share_password(headers: &HeaderMap) -> Option<&str> {
+    headers.get(SHARE_PASSWORD_HEADER).and_then(|v| v.to_str().ok())
+}
+
+/// Create a public share link for a task
+#[utoipa::path(
+    post,
+    path = "/api/tasks/{task_id}/share-links",
+    tag = "share-links",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID")
+    ),
+    request_body = CreateShareLinkRequest,
+    responses(
+        (status = 201, description = "Share link created", body = ShareLinkResponse),
+        (status = 400, description = "Validation error or expiry in the past"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Only task admins can share")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create_share_link(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(task_id): Path<Uuid>,
+    AppJson(payload): AppJson<CreateShareLinkRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+
+    let link = state.share_link_service.create_link(task_id, user_id, payload).await?;
+
+    Ok((StatusCode::CREATED, Json(link)))
+}
+
+/// List a task's share links, including revoked and expired ones
+#[utoipa::path(
+    get,
+    path = "/api/tasks/{task_id}/share-links",
+    tag = "share-links",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID")
+    ),
+    responses(
+        (status = 200, description = "Share links", body = Vec<ShareLinkResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Only task admins can see share links")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_share_links(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(task_id): Path<Uuid>,
+) -> Result<impl IntoResponse> {
+    let links = state.share_link_service.list_links(task_id, user_id).await?;
+
+    Ok((StatusCode::OK, Json(links)))
+}
+
+/// Revoke a share link
+#[utoipa::path(
+    delete,
+    path = "/api/tasks/{task_id}/share-links/{link_id}",
+    tag = "share-links",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID"),
+        ("link_id" = Uuid, Path, description = "Share link ID")
+    ),
+    responses(
+        (status = 204, description = "Share link revoked"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Only task admins can revoke share links"),
+        (status = 404, description = "Share link not found or already revoked")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn revoke_share_link(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path((task_id, link_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse> {
+    state.share_link_service.revoke_link(task_id, link_id, user_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// View a shared task without an account
+#[utoipa::path(
+    get,
+    path = "/api/public/share/{token}",
+    tag = "share-links",
+    params(
+        ("token" = String, Path, description = "Share link token"),
+        ("X-Share-Password" = Option<String>, Header, description = "Password, if the link has one")
+    ),
+    responses(
+        (status = 200, description = "The shared task", body = PublicTaskView),
+        (status = 401, description = "Missing or incorrect password"),
+        (status = 404, description = "Link not found, revoked or expired")
+    )
+)]
+pub async fn view_shared_task(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse> {
+    let task = state.share_link_service.view(&token, share_password(&headers)).await?;
+
+    Ok((StatusCode::OK, Json(task)))
+}
+
+/// Comment on a shared task through a commentable link
+#[utoipa::path(
+    post,
+    path = "/api/public/share/{token}/comments",
+    tag = "share-links",
+    params(
+        ("token" = String, Path, description = "Share link token"),
+        ("X-Share-Password" = Option<String>, Header, description = "Password, if the link has one")
+    ),
+    request_body = CreateGuestCommentRequest,
+    responses(
+        (status = 201, description = "Comment added", body = PublicComment),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Missing or incorrect password"),
+        (status = 403, description = "The link is read-only"),
+        (status = 404, description = "Link not found, revoked or expired")
+    )
+)]
+pub async fn add_guest_comment(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+    headers: HeaderMap,
+    AppJson(mut payload): AppJson<CreateGuestCommentRequest>,
+) -> Result<impl IntoResponse> {
+    // A name of only spaces would otherwise pass and be stored empty
+    payload.author_name = payload.author_name.trim().to_string();
+    payload.validate()?;
+
+    let comment = state.share_link_service
+        .add_guest_comment(&token, share_password(&headers), payload)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(comment)))
+}
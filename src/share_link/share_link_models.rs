@@ -0,0 +1,102 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::task::task_models::{TaskPriority, TaskStatus};
+
+/// What someone holding a share link can do with the task.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "text", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum ShareLinkAccess {
+    /// See the task
+    Read,
+    /// See the task and its comments, and add comments
+    Comment,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ShareLink {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub created_by: Option<Uuid>,
+    pub access: ShareLinkAccess,
+    pub password_hash: Option<String>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub last_accessed_at: Option<DateTime<Utc>>,
+    pub access_count: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ShareLink {
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.revoked_at.is_none() && self.expires_at.is_none_or(|expires_at| expires_at > now)
+    }
+}
+
+/// JWT claims of a share link token. Only the link id matters; the rest lets
+/// us reject tokens for the wrong task or past their expiry without a lookup.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ShareLinkClaims {
+    pub sub: Uuid,
+    pub task_id: Uuid,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exp: Option<i64>,
+}
+
+/// A share link as shown to the task's admins.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ShareLinkResponse {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub created_by: Option<Uuid>,
+    pub access: ShareLinkAccess,
+    pub has_password: bool,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub last_accessed_at: Option<DateTime<Utc>>,
+    pub access_count: i32,
+    pub created_at: DateTime<Utc>,
+    /// Signed token; the task is served at `/api/public/share/{token}`
+    pub token: String,
+}
+
+/// The task as seen through a share link: no member ids or e-mails.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PublicTaskView {
+    pub title: String,
+    pub description: Option<String>,
+    pub status: TaskStatus,
+    pub priority: TaskPriority,
+    pub due_date: Option<DateTime<Utc>>,
+    pub estimate_minutes: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub subtasks: Vec<PublicSubtask>,
+    pub access: ShareLinkAccess,
+    pub link_expires_at: Option<DateTime<Utc>>,
+    /// Only included for commentable links
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comments: Option<Vec<PublicComment>>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema, FromRow)]
+pub struct PublicSubtask {
+    pub title: String,
+    pub status: TaskStatus,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema, FromRow)]
+pub struct PublicComment {
+    pub id: Uuid,
+    pub parent_comment_id: Option<Uuid>,
+    /// Username of a member, or the name a guest gave
+    pub author_name: Option<String>,
+    pub is_guest: bool,
+    /// Empty once the comment has been deleted
+    pub content: String,
+    pub created_at: DateTime<Utc>,
+}
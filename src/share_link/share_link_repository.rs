@@ -0,0 +1,133 @@
+use crate::error::Result;
+use crate::task::task_models::Task;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+use super::share_link_models::{PublicComment, PublicSubtask, ShareLink, ShareLinkAccess};
+
+#[derive(Clone)]
+pub struct ShareLinkRepository {
+    pool: PgPool,
+}
+
+impl ShareLinkRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn create(
+        &self,
+        task_id: Uuid,
+        created_by: Uuid,
+        access: ShareLinkAccess,
+        password_hash: Option<&str>,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<ShareLink> {
+        let link = sqlx::query_as::<_, ShareLink>(
+            "INSERT INTO task_share_links (task_id, created_by, access, password_hash, expires_at)
+             VALUES ($1, $2, $3, $4, $5)
+             RETURNING *"
+        )
+        .bind(task_id)
+        .bind(created_by)
+        .bind(access)
+        .bind(password_hash)
+        .bind(expires_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(link)
+    }
+
+    pub async fn find_by_id(&self, id: Uuid) -> Result<Option<ShareLink>> {
+        let link = sqlx::query_as::<_, ShareLink>("SELECT * FROM task_share_links WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(link)
+    }
+
+    /// All links of a task, newest first, including revoked and expired ones
+    pub async fn find_for_task(&self, task_id: Uuid) -> Result<Vec<ShareLink>> {
+        let links = sqlx::query_as::<_, ShareLink>(
+            "SELECT * FROM task_share_links WHERE task_id = $1 ORDER BY created_at DESC"
+        )
+        .bind(task_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(links)
+    }
+
+    /// Revokes a live link. Returns `None` if the task has no such link or it
+    /// was already revoked.
+    pub async fn revoke(&self, task_id: Uuid, id: Uuid) -> Result<Option<ShareLink>> {
+        let link = sqlx::query_as::<_, ShareLink>(
+            "UPDATE task_share_links SET revoked_at = NOW()
+             WHERE id = $1 AND task_id = $2 AND revoked_at IS NULL
+             RETURNING *"
+        )
+        .bind(id)
+        .bind(task_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(link)
+    }
+
+    pub async fn record_access(&self, id: Uuid) -> Result<()> {
+        sqlx::query(
+            "UPDATE task_share_links
+             SET access_count = access_count + 1, last_accessed_at = NOW()
+             WHERE id = $1"
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// The linked task, unless it is in the trash
+    pub async fn find_live_task(&self, task_id: Uuid) -> Result<Option<Task>> {
+        let task = sqlx::query_as::<_, Task>("SELECT * FROM tasks WHERE id = $1 AND deleted_at IS NULL")
+            .bind(task_id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(task)
+    }
+
+    /// Live subtasks of a task in manual order
+    pub async fn find_public_subtasks(&self, task_id: Uuid) -> Result<Vec<PublicSubtask>> {
+        let subtasks = sqlx::query_as::<_, PublicSubtask>(
+            "SELECT title, status FROM tasks
+             WHERE parent_task_id = $1 AND deleted_at IS NULL
+             ORDER BY position ASC, created_at ASC"
+        )
+        .bind(task_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(subtasks)
+    }
+
+    /// Every comment of a task, threads and replies, oldest first
+    pub async fn find_public_comments(&self, task_id: Uuid) -> Result<Vec<PublicComment>> {
+        let comments = sqlx::query_as::<_, PublicComment>(
+            "SELECT c.id, c.parent_comment_id, COALESCE(u.username, c.guest_name) AS author_name,
+                    c.user_id IS NULL AND c.guest_name IS NOT NULL AS is_guest,
+                    c.content, c.created_at
+             FROM task_comments c
+             LEFT JOIN users u ON u.id = c.user_id
+             WHERE c.task_id = $1
+             ORDER BY c.created_at ASC, c.id ASC"
+        )
+        .bind(task_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(comments)
+    }
+}
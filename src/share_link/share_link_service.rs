@@ -0,0 +1,269 @@
+use crate::auth::password::{hash_password, verify_password};
+use crate::comment::comment_repository::CommentRepository;
+use crate::error::{AppError, Result};
+use crate::task::task_events::TaskEvents;
+use crate::task::task_models::{Task, TaskEventKind, TaskPermission};
+use crate::task::task_repository::TaskRepository;
+use crate::task::task_service::TaskService;
+use chrono::Utc;
+use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use uuid::Uuid;
+use super::share_link_dto::{CreateGuestCommentRequest, CreateShareLinkRequest};
+use super::share_link_models::{
+    PublicComment, PublicTaskView, ShareLink, ShareLinkAccess, ShareLinkClaims, ShareLinkResponse,
+};
+use super::share_link_repository::ShareLinkRepository;
+
+#[derive(Clone)]
+pub struct ShareLinkService {
+    repo: ShareLinkRepository,
+    task_repo: TaskRepository,
+    task_service: TaskService,
+    comment_repo: CommentRepository,
+    events: TaskEvents,
+    /// Derived from the JWT secret, so link tokens and access tokens can never
+    /// be swapped for one another
+    signing_key: String,
+}
+
+impl ShareLinkService {
+    pub fn new(
+        repo: ShareLinkRepository,
+        task_repo: TaskRepository,
+        task_service: TaskService,
+        comment_repo: CommentRepository,
+        events: TaskEvents,
+        jwt_secret: &str,
+    ) -> Self {
+        Self {
+            repo,
+            task_repo,
+            task_service,
+            comment_repo,
+            events,
+            signing_key: format!("share-link:{}", jwt_secret),
+        }
+    }
+
+    /// Creates a link to the task. Sharing publicly is member management, so
+    /// only task admins and the owner can do it.
+    pub async fn create_link(
+        &self,
+        task_id: Uuid,
+        user_id: Uuid,
+        payload: CreateShareLinkRequest,
+    ) -> Result<ShareLinkResponse> {
+        self.task_service.require_permission(task_id, user_id, TaskPermission::ManageMembers).await?;
+
+        if payload.expires_at.is_some_and(|expires_at| expires_at <= Utc::now()) {
+            return Err(AppError::BadRequest("expires_at must be in the future".to_string()));
+        }
+        let password_hash = payload.password.as_deref().map(hash_password).transpose()?;
+        let access = payload.access.unwrap_or(ShareLinkAccess::Read);
+
+        let link = self.repo
+            .create(task_id, user_id, access, password_hash.as_deref(), payload.expires_at)
+            .await?;
+
+        let _ = self.task_repo.log_task_activity(
+            task_id,
+            user_id,
+            "share_link_created",
+            Some(serde_json::json!({
+                "share_link_id": link.id,
+                "access": link.access,
+                "expires_at": link.expires_at,
+                "has_password": link.password_hash.is_some(),
+            })),
+        ).await;
+
+        self.to_response(link)
+    }
+
+    pub async fn list_links(&self, task_id: Uuid, user_id: Uuid) -> Result<Vec<ShareLinkResponse>> {
+        self.task_service.require_permission(task_id, user_id, TaskPermission::ManageMembers).await?;
+
+        self.repo
+            .find_for_task(task_id)
+            .await?
+            .into_iter()
+            .map(|link| self.to_response(link))
+            .collect()
+    }
+
+    pub async fn revoke_link(&self, task_id: Uuid, link_id: Uuid, user_id: Uuid) -> Result<()> {
+        self.task_service.require_permission(task_id, user_id, TaskPermission::ManageMembers).await?;
+
+        let link = self.repo
+            .revoke(task_id, link_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Share link not found".to_string()))?;
+
+        let _ = self.task_repo.log_task_activity(
+            task_id,
+            user_id,
+            "share_link_revoked",
+            Some(serde_json::json!({"share_link_id": link.id})),
+        ).await;
+
+        Ok(())
+    }
+
+    /// The task behind a link, stripped down for visitors. Every view is
+    /// counted on the link and logged in the task's activity.
+    pub async fn view(&self, token: &str, password: Option<&str>) -> Result<PublicTaskView> {
+        let (link, task) = self.open(token, password).await?;
+
+        self.repo.record_access(link.id).await?;
+        let _ = self.task_repo.log_task_activity(
+            task.id,
+            None,
+            "share_link_accessed",
+            Some(serde_json::json!({"share_link_id": link.id})),
+        ).await;
+
+        let subtasks = self.repo.find_public_subtasks(task.id).await?;
+        let comments = match link.access {
+            ShareLinkAccess::Comment => Some(self.repo.find_public_comments(task.id).await?),
+            ShareLinkAccess::Read => None,
+        };
+
+        Ok(PublicTaskView {
+            title: task.title,
+            description: task.description,
+            status: task.status,
+            priority: task.priority,
+            due_date: task.due_date,
+            estimate_minutes: task.estimate_minutes,
+            created_at: task.created_at,
+            updated_at: task.updated_at,
+            subtasks,
+            access: link.access,
+            link_expires_at: link.expires_at,
+            comments,
+        })
+    }
+
+    /// Posts a comment under the name the visitor gives, trimmed and validated by the
+    /// handler. Only commentable links allow it.
+    pub async fn add_guest_comment(
+        &self,
+        token: &str,
+        password: Option<&str>,
+        payload: CreateGuestCommentRequest,
+    ) -> Result<PublicComment> {
+        let (link, task) = self.open(token, password).await?;
+        if link.access != ShareLinkAccess::Comment {
+            return Err(AppError::Forbidden("This link does not allow comments".to_string()));
+        }
+
+        if let Some(parent_id) = payload.parent_comment_id {
+            let parent = self.comment_repo
+                .find_by_id(parent_id)
+                .await?
+                .filter(|c| c.task_id == task.id)
+                .ok_or_else(|| AppError::NotFound("Comment not found".to_string()))?;
+            if parent.deleted_at.is_some() {
+                return Err(AppError::BadRequest("Cannot reply to a deleted comment".to_string()));
+            }
+        }
+
+        let author_name = payload.author_name.as_str();
+        let comment = self.comment_repo
+            .create_guest(task.id, link.id, author_name, payload.parent_comment_id, &payload.content)
+            .await?;
+
+        let _ = self.task_repo.log_task_activity(
+            task.id,
+            None,
+            "comment_added",
+            Some(serde_json::json!({
+                "comment_id": comment.id,
+                "parent_comment_id": comment.parent_comment_id,
+                "share_link_id": link.id,
+                "guest_name": author_name,
+            })),
+        ).await;
+        self.events
+            .publish(
+                TaskEventKind::Commented,
+                &task,
+                None,
+                Some(serde_json::json!({"action": "added", "comment_id": comment.id, "guest_name": author_name})),
+            )
+            .await;
+
+        Ok(PublicComment {
+            id: comment.id,
+            parent_comment_id: comment.parent_comment_id,
+            author_name: Some(author_name.to_string()),
+            is_guest: true,
+            content: comment.content,
+            created_at: comment.created_at,
+        })
+    }
+
+    /// Checks the token, the link and its password. Bad, revoked and expired
+    /// links all look the same to the visitor.
+    async fn open(&self, token: &str, password: Option<&str>) -> Result<(ShareLink, Task)> {
+        let not_found = || AppError::NotFound("Share link not found or expired".to_string());
+
+        let mut validation = Validation::default();
+        validation.required_spec_claims.clear();
+        let claims = decode::<ShareLinkClaims>(
+            token,
+            &DecodingKey::from_secret(self.signing_key.as_bytes()),
+            &validation,
+        )
+        .map_err(|_| not_found())?
+        .claims;
+
+        let link = self.repo
+            .find_by_id(claims.sub)
+            .await?
+            .filter(|link| link.task_id == claims.task_id && link.is_active(Utc::now()))
+            .ok_or_else(not_found)?;
+        let task = self.repo.find_live_task(link.task_id).await?.ok_or_else(not_found)?;
+
+        if let Some(hash) = &link.password_hash {
+            let password = password
+                .ok_or_else(|| AppError::Unauthorized("This link is password protected".to_string()))?;
+            if !verify_password(password, hash)? {
+                return Err(AppError::Unauthorized("Incorrect link password".to_string()));
+            }
+        }
+
+        Ok((link, task))
+    }
+
+    fn sign(&self, link: &ShareLink) -> Result<String> {
+        let claims = ShareLinkClaims {
+            sub: link.id,
+            task_id: link.task_id,
+            exp: link.expires_at.map(|expires_at| expires_at.timestamp()),
+        };
+
+        encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(self.signing_key.as_bytes()),
+        )
+        .map_err(|_| AppError::InternalError)
+    }
+
+    fn to_response(&self, link: ShareLink) -> Result<ShareLinkResponse> {
+        Ok(ShareLinkResponse {
+            token: self.sign(&link)?,
+            id: link.id,
+            task_id: link.task_id,
+            created_by: link.created_by,
+            access: link.access,
+            has_password: link.password_hash.is_some(),
+            expires_at: link.expires_at,
+            revoked_at: link.revoked_at,
+            last_accessed_at: link.last_accessed_at,
+            access_count: link.access_count,
+            created_at: link.created_at,
+        })
+    }
+}
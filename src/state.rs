@@ -11,6 +11,7 @@ use crate::{
     auth::auth_repository::RefreshTokenRepository,
     user::user_service::UserService,
     task::task_service::TaskService,
+    task::task_events::TaskEvents,
     auth::auth_service::AuthService,
     message::message_service::MessageService,
     websocket::ConnectionManager,
@@ -26,6 +27,54 @@ use crate::{
         video_call_repository::VideoCallRepository,
         video_call_service::VideoCallService,
     },
+    label::{
+        label_repository::LabelRepository,
+        label_service::LabelService,
+    },
+    project::{
+        project_repository::ProjectRepository,
+        project_service::ProjectService,
+    },
+    comment::{
+        comment_repository::CommentRepository,
+        comment_service::CommentService,
+    },
+    time_entry::{
+        time_entry_repository::TimeEntryRepository,
+        time_entry_service::TimeEntryService,
+    },
+    search::{
+        search_repository::SearchRepository,
+        search_service::SearchService,
+    },
+    attachment::{
+        attachment_repository::AttachmentRepository,
+        attachment_service::AttachmentService,
+    },
+    checklist::{
+        checklist_repository::ChecklistRepository,
+        checklist_service::ChecklistService,
+    },
+    share_link::{
+        share_link_repository::ShareLinkRepository,
+        share_link_service::ShareLinkService,
+    },
+    template::{
+        template_repository::TemplateRepository,
+        template_service::TemplateService,
+    },
+    saved_view::{
+        saved_view_repository::SavedViewRepository,
+        saved_view_service::SavedViewService,
+    },
+    calendar::{
+        calendar_repository::CalendarRepository,
+        calendar_service::CalendarService,
+    },
+    transfer::{
+        transfer_repository::TransferRepository,
+        transfer_service::TransferService,
+    },
     notification::NotificationHelper,
 };
 
@@ -38,7 +87,7 @@ pub struct AppState {
     pub config: Arc<Config>,
     pub oauth_client: BasicClient,
     pub notification_tx: broadcast::Sender<String>,
-    pub task_tx: broadcast::Sender<(uuid::Uuid, crate::task::task_models::Task)>,
+    pub task_events: TaskEvents,
     pub ws_connections: ConnectionManager,
     /// Redis client for media relay. `None` when `REDIS_URL` is not configured.
     /// When absent, the `/video-calls/{id}/ws` media endpoint returns 503.
@@ -63,6 +112,42 @@ pub struct AppState {
     #[allow(dead_code)]
     pub video_call_repository: VideoCallRepository,
     pub video_call_service: VideoCallService,
+    #[allow(dead_code)]
+    pub label_repository: LabelRepository,
+    pub label_service: LabelService,
+    #[allow(dead_code)]
+    pub project_repository: ProjectRepository,
+    pub project_service: ProjectService,
+    #[allow(dead_code)]
+    pub comment_repository: CommentRepository,
+    pub comment_service: CommentService,
+    #[allow(dead_code)]
+    pub attachment_repository: AttachmentRepository,
+    pub attachment_service: AttachmentService,
+    #[allow(dead_code)]
+    pub time_entry_repository: TimeEntryRepository,
+    pub time_entry_service: TimeEntryService,
+    #[allow(dead_code)]
+    pub search_repository: SearchRepository,
+    pub search_service: SearchService,
+    #[allow(dead_code)]
+    pub checklist_repository: ChecklistRepository,
+    pub checklist_service: ChecklistService,
+    #[allow(dead_code)]
+    pub share_link_repository: ShareLinkRepository,
+    pub share_link_service: ShareLinkService,
+    #[allow(dead_code)]
+    pub template_repository: TemplateRepository,
+    pub template_service: TemplateService,
+    #[allow(dead_code)]
+    pub saved_view_repository: SavedViewRepository,
+    pub saved_view_service: SavedViewService,
+    #[allow(dead_code)]
+    pub calendar_repository: CalendarRepository,
+    pub calendar_service: CalendarService,
+    #[allow(dead_code)]
+    pub transfer_repository: TransferRepository,
+    pub transfer_service: TransferService,
     pub notification_helper: NotificationHelper,
 }
 
@@ -74,6 +159,8 @@ pub struct Config {
     pub google_client_id: String,
     pub google_client_secret: String,
     pub google_redirect_uri: String,
+    /// Days a deleted task stays in the trash before it is purged
+    pub trash_retention_days: i32,
 }
 
 impl Config {
@@ -91,6 +178,10 @@ impl Config {
                 .expect("GOOGLE_CLIENT_SECRET must be set"),
             google_redirect_uri: std::env::var("GOOGLE_REDIRECT_URI")
                 .expect("GOOGLE_REDIRECT_URI must be set"),
+            trash_retention_days: std::env::var("TRASH_RETENTION_DAYS")
+                .unwrap_or_else(|_| "30".to_string())
+                .parse()
+                .expect("TRASH_RETENTION_DAYS must be a number"),
         }
     }
 }
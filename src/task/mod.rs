@@ -4,3 +4,4 @@ pub mod task_dto;
 pub mod task_repository;
 pub mod task_handlers;
 pub mod task_service;
+pub mod task_events;
@@ -4,14 +4,25 @@ use utoipa::ToSchema;
 use validator::Validate;
 use uuid::Uuid;
 
+use super::task_handlers::TaskFilters;
+use super::task_models::{BulkTaskOperation, RecurrenceRule, TaskPriority, TaskRole, TaskStatus};
+
 #[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct CreateTaskRequest {
     #[validate(length(min = 1, max = 500))]
     pub title: String,
     pub description: Option<String>,
-    pub priority: Option<String>,
+    /// Defaults to Medium
+    pub priority: Option<TaskPriority>,
     pub due_date: Option<DateTime<Utc>>,
     pub reminder_time: Option<DateTime<Utc>>,
+    /// Expected effort in minutes
+    #[validate(range(min = 1))]
+    pub estimate_minutes: Option<i32>,
+    /// Repeat schedule; requires a due date
+    pub recurrence_rule: Option<RecurrenceRule>,
+    /// Project to add the task to; it lands in the first column for its status
+    pub project_id: Option<Uuid>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
@@ -19,15 +30,44 @@ pub struct UpdateTaskRequest {
     #[validate(length(min = 1, max = 500))]
     pub title: Option<String>,
     pub description: Option<String>,
-    pub status: Option<String>,
-    pub priority: Option<String>,
+    pub status: Option<TaskStatus>,
+    pub priority: Option<TaskPriority>,
     pub due_date: Option<DateTime<Utc>>,
     pub reminder_time: Option<DateTime<Utc>>,
+    /// Expected effort in minutes
+    #[validate(range(min = 1))]
+    pub estimate_minutes: Option<i32>,
+    /// Repeat schedule for this and future occurrences; requires a due date
+    pub recurrence_rule: Option<RecurrenceRule>,
 }
 
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct UpdateTaskStatusRequest {
-    pub status: String,
+    pub status: TaskStatus,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct ReorderSubtasksRequest {
+    /// Every subtask of the parent, in the desired order
+    #[validate(length(min = 1))]
+    pub subtask_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct AddTaskDependencyRequest {
+    /// Task that has to be finished first
+    pub blocked_by_task_id: Uuid,
+}
+
+/// Selects tasks either by ID or with the same filters as `GET /api/tasks`.
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct BulkTaskRequest {
+    pub task_ids: Option<Vec<Uuid>>,
+    #[schema(value_type = Option<Object>)]
+    pub filter: Option<TaskFilters>,
+    /// Applied to every selected task, in order
+    #[validate(length(min = 1, max = 10))]
+    pub operations: Vec<BulkTaskOperation>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -37,6 +77,9 @@ pub struct PaginatedResponse<T> {
     pub page: u32,
     pub limit: u32,
     pub total_pages: u32,
+    /// Cursor for the next page, when the list is in `created_at` order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
 }
 
 // Collaborative task DTOs
@@ -44,6 +87,20 @@ pub struct PaginatedResponse<T> {
 pub struct ShareTaskRequest {
     #[validate(length(min = 1))]
     pub user_ids: Vec<Uuid>,
+    /// Role to give the new members; defaults to editor
+    pub role: Option<TaskRole>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateTaskMemberRequest {
+    pub role: TaskRole,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct AssignTaskRequest {
+    /// Task members to assign
+    #[validate(length(min = 1))]
+    pub user_ids: Vec<Uuid>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -0,0 +1,86 @@
+use chrono::Utc;
+use tokio::sync::broadcast;
+use uuid::Uuid;
+
+use crate::task::task_models::{Task, TaskEvent, TaskEventKind};
+use crate::task::task_repository::TaskRepository;
+use crate::websocket::types::WsMessage;
+use crate::websocket::ConnectionManager;
+
+/// Events kept for each SSE subscriber before a slow one starts missing some
+const TASK_EVENT_BUFFER: usize = 256;
+
+/// Fans task changes out to everyone with access to the task: SSE clients
+/// subscribe to the broadcast channel, WebSocket clients are sent a
+/// `task_event` message directly.
+#[derive(Clone)]
+pub struct TaskEvents {
+    tx: broadcast::Sender<TaskEvent>,
+    repo: TaskRepository,
+    ws_manager: ConnectionManager,
+}
+
+impl TaskEvents {
+    pub fn new(repo: TaskRepository, ws_manager: ConnectionManager) -> Self {
+        let (tx, _) = broadcast::channel(TASK_EVENT_BUFFER);
+        Self { tx, repo, ws_manager }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<TaskEvent> {
+        self.tx.subscribe()
+    }
+
+    /// Everyone with access to the task right now. Trashed tasks have no
+    /// audience, so look it up before deleting a task or removing a member.
+    pub async fn audience(&self, task_id: Uuid) -> Vec<Uuid> {
+        match self.repo.get_audience(task_id).await {
+            Ok(audience) => audience,
+            Err(e) => {
+                tracing::warn!("Failed to load members of task {}: {:?}", task_id, e);
+                Vec::new()
+            }
+        }
+    }
+
+    /// Publishes a change to `task` to its current audience.
+    pub async fn publish(
+        &self,
+        kind: TaskEventKind,
+        task: &Task,
+        actor_id: Option<Uuid>,
+        data: Option<serde_json::Value>,
+    ) {
+        let audience = self.audience(task.id).await;
+        self.publish_to(audience, kind, task.id, Some(task.clone()), actor_id, data);
+    }
+
+    /// Publishes a change to an audience looked up beforehand. Pass no task
+    /// for a tombstone.
+    pub fn publish_to(
+        &self,
+        audience: Vec<Uuid>,
+        kind: TaskEventKind,
+        task_id: Uuid,
+        task: Option<Task>,
+        actor_id: Option<Uuid>,
+        data: Option<serde_json::Value>,
+    ) {
+        if audience.is_empty() {
+            return;
+        }
+
+        let event = TaskEvent {
+            kind,
+            task_id,
+            actor_id,
+            task,
+            data,
+            occurred_at: Utc::now(),
+            audience,
+        };
+
+        self.ws_manager.send_to_users(&event.audience, WsMessage::TaskEvent(Box::new(event.clone())));
+        // Sending fails only when no SSE client is listening
+        let _ = self.tx.send(event);
+    }
+}
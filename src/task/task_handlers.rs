@@ -1,31 +1,36 @@
 use axum::{
     extract::{Path, Query, State},
     http::StatusCode,
-    response::{IntoResponse, sse::{Event, KeepAlive, Sse}},
+    response::{IntoResponse, Response, sse::{Event, KeepAlive, Sse}},
     Extension, Json,
 };
 use chrono::{DateTime, Utc};
 use futures::stream::Stream;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use tokio_stream::{wrappers::BroadcastStream, StreamExt};
 use uuid::Uuid;
 use validator::Validate;
 
 use crate::{
     error::{AppError, Result},
+    middleware::{etag, AppJson, AppQuery, IfMatch},
+    pagination::{Cursor, CursorPage, CursorParams, MAX_CURSOR_LIMIT},
     state::AppState,
 };
 use super::{
-    task_dto::{CreateTaskRequest, UpdateTaskRequest, UpdateTaskStatusRequest, PaginatedResponse},
-    task_models::Task,
+    task_dto::{CreateTaskRequest, UpdateTaskRequest, UpdateTaskStatusRequest, PaginatedResponse, ReorderSubtasksRequest, AddTaskDependencyRequest, AssignTaskRequest, BulkTaskRequest},
+    task_models::{Task, TaskAssignee, TaskListItem, TaskPriority, TaskRole, TaskStatus, TaskVersionDiff, TrashedTask},
+    task_service::MAX_BULK_TASKS,
 };
+use crate::user::user_handlers::PaginationParams;
 
-#[derive(Deserialize)]
+/// Task list query parameters; also the shape stored by saved views.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct TaskFilters {
-    pub status: Option<String>,
-    pub statuses: Option<Vec<String>>,
-    pub priority: Option<String>,
-    pub priorities: Option<Vec<String>>,
+    pub status: Option<TaskStatus>,
+    pub statuses: Option<Vec<TaskStatus>>,
+    pub priority: Option<TaskPriority>,
+    pub priorities: Option<Vec<TaskPriority>>,
     pub search: Option<String>,
     pub created_from: Option<DateTime<Utc>>,
     pub created_to: Option<DateTime<Utc>>,
@@ -35,6 +40,127 @@ pub struct TaskFilters {
     pub sort_order: Option<String>,
     pub page: Option<u32>,
     pub limit: Option<u32>,
+    pub include_subtasks: Option<bool>,
+    pub project_id: Option<Uuid>,
+    pub labels: Option<String>,
+    pub labels_mode: Option<String>,
+    pub assignee: Option<String>,
+}
+
+impl TaskFilters {
+    /// These filters, with anything left out taken from `saved`. A status or
+    /// priority given here replaces both the single and multi-value saved ones.
+    pub fn or(self, saved: TaskFilters) -> TaskFilters {
+        let (status, statuses) = if self.status.is_some() || self.statuses.is_some() {
+            (self.status, self.statuses)
+        } else {
+            (saved.status, saved.statuses)
+        };
+        let (priority, priorities) = if self.priority.is_some() || self.priorities.is_some() {
+            (self.priority, self.priorities)
+        } else {
+            (saved.priority, saved.priorities)
+        };
+
+        TaskFilters {
+            status,
+            statuses,
+            priority,
+            priorities,
+            search: self.search.or(saved.search),
+            created_from: self.created_from.or(saved.created_from),
+            created_to: self.created_to.or(saved.created_to),
+            due_from: self.due_from.or(saved.due_from),
+            due_to: self.due_to.or(saved.due_to),
+            sort_by: self.sort_by.or(saved.sort_by),
+            sort_order: self.sort_order.or(saved.sort_order),
+            page: self.page.or(saved.page),
+            limit: self.limit.or(saved.limit),
+            include_subtasks: self.include_subtasks.or(saved.include_subtasks),
+            project_id: self.project_id.or(saved.project_id),
+            labels: self.labels.or(saved.labels),
+            labels_mode: self.labels_mode.or(saved.labels_mode),
+            assignee: self.assignee.or(saved.assignee),
+        }
+    }
+
+    /// Rejects filters that `get_tasks` would refuse.
+    pub fn check(&self, user_id: Uuid) -> Result<()> {
+        to_repo_filters(self.clone(), user_id, 1, 1).map(|_| ())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ViewParam {
+    pub view_id: Option<Uuid>,
+}
+
+/// Parses the comma-separated `labels` query parameter.
+pub fn parse_label_filter(labels: Option<&str>) -> Result<Option<Vec<Uuid>>> {
+    let Some(labels) = labels.filter(|l| !l.trim().is_empty()) else {
+        return Ok(None);
+    };
+
+    let mut ids = labels
+        .split(',')
+        .map(|id| id.trim().parse::<Uuid>())
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(|_| AppError::BadRequest("labels must be a comma-separated list of label IDs".to_string()))?;
+    ids.sort_unstable();
+    ids.dedup();
+
+    Ok(Some(ids))
+}
+
+/// Parses the `assignee` query parameter: a user ID, or `me`.
+pub fn parse_assignee_filter(assignee: Option<&str>, user_id: Uuid) -> Result<Option<Uuid>> {
+    match assignee.map(str::trim).filter(|a| !a.is_empty()) {
+        None => Ok(None),
+        Some("me") => Ok(Some(user_id)),
+        Some(id) => id
+            .parse()
+            .map(Some)
+            .map_err(|_| AppError::BadRequest("assignee must be a user ID or 'me'".to_string())),
+    }
+}
+
+/// Builds the repository filters for the task list query parameters.
+fn to_repo_filters(
+    filters: TaskFilters,
+    user_id: Uuid,
+    page: u32,
+    limit: u32,
+) -> Result<crate::task::task_repository::TaskFilters> {
+    let labels = parse_label_filter(filters.labels.as_deref())?;
+    let labels_match_all = match filters.labels_mode.as_deref() {
+        None | Some("any") => false,
+        Some("all") => true,
+        Some(_) => return Err(AppError::BadRequest("labels_mode must be 'any' or 'all'".to_string())),
+    };
+    let assignee_id = parse_assignee_filter(filters.assignee.as_deref(), user_id)?;
+
+    Ok(crate::task::task_repository::TaskFilters {
+        status: filters.status,
+        statuses: filters.statuses,
+        priority: filters.priority,
+        priorities: filters.priorities,
+        search: filters.search,
+        created_from: filters.created_from,
+        created_to: filters.created_to,
+        due_from: filters.due_from,
+        due_to: filters.due_to,
+        sort_by: filters.sort_by,
+        sort_order: filters.sort_order,
+        page: Some(page),
+        limit: Some(limit),
+        user_id: None,
+        project_id: filters.project_id,
+        include_subtasks: filters.include_subtasks.unwrap_or(false),
+        labels,
+        labels_match_all,
+        assignee_id,
+        cursor: None,
+    })
 }
 
 /// Get all tasks for the authenticated user
@@ -42,11 +168,11 @@ pub struct TaskFilters {
     get,
     path = "/api/tasks",
     params(
-        ("status" = Option<String>, Query, description = "Filter by status"),
-        ("statuses" = Option<[String]>, Query, description = "Multiple statuses"),
-        ("priority" = Option<String>, Query, description = "Filter by priority"),
-        ("priorities" = Option<[String]>, Query, description = "Multiple priorities"),
-        ("search" = Option<String>, Query, description = "Search by title or description"),
+        ("status" = Option<TaskStatus>, Query, description = "Filter by status"),
+        ("statuses" = Option<[TaskStatus]>, Query, description = "Multiple statuses"),
+        ("priority" = Option<TaskPriority>, Query, description = "Filter by priority"),
+        ("priorities" = Option<[TaskPriority]>, Query, description = "Multiple priorities"),
+        ("search" = Option<String>, Query, description = "Full-text search over title and description"),
         ("created_from" = Option<DateTime<Utc>>, Query, description = "Filter by creation date (from)"),
         ("created_to" = Option<DateTime<Utc>>, Query, description = "Filter by creation date (to)"),
         ("due_from" = Option<DateTime<Utc>>, Query, description = "Filter by due date (from)"),
@@ -54,11 +180,22 @@ pub struct TaskFilters {
         ("sort_by" = Option<String>, Query, description = "Sort by field (priority, due_date, created_at)"),
         ("sort_order" = Option<String>, Query, description = "Sort order (asc, desc)"),
         ("page" = Option<u32>, Query, description = "Page number"),
-        ("limit" = Option<u32>, Query, description = "Items per page")
+        ("limit" = Option<u32>, Query, description = "Items per page"),
+        ("include_subtasks" = Option<bool>, Query, description = "Also list subtasks (default: top-level tasks only)"),
+        ("project_id" = Option<Uuid>, Query, description = "Only tasks in this project"),
+        ("labels" = Option<String>, Query, description = "Comma-separated label IDs"),
+        ("labels_mode" = Option<String>, Query, description = "Match any (default) or all of the labels"),
+        ("assignee" = Option<String>, Query, description = "Only tasks assigned to this user ID, or `me`"),
+        ("view_id" = Option<Uuid>, Query, description = "Saved view to start from; the other parameters override it"),
+        ("after" = Option<String>, Query, description = "Cursor: the page after this one (sorting by created_at only)"),
+        ("before" = Option<String>, Query, description = "Cursor: the page before this one (sorting by created_at only)")
     ),
     responses(
-        (status = 200, description = "List of tasks", body = PaginatedResponse<Task>),
-        (status = 401, description = "Unauthorized")
+        (status = 200, description = "List of tasks; a `CursorPage<TaskListItem>` when `after` or `before` is given", body = PaginatedResponse<TaskListItem>),
+        (status = 400, description = "Invalid filter or cursor"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Saved view not found"),
+        (status = 422, description = "Unknown status or priority; the error lists the allowed values")
     ),
     tag = "tasks",
     security(("bearer_auth" = []))
@@ -66,27 +203,95 @@ pub struct TaskFilters {
 pub async fn get_tasks(
     State(state): State<AppState>,
     Extension(user_id): Extension<Uuid>,
-    Query(filters): Query<TaskFilters>,
-) -> Result<Json<PaginatedResponse<Task>>> {
+    AppQuery(filters): AppQuery<TaskFilters>,
+    Query(view): Query<ViewParam>,
+    Query(cursor): Query<CursorParams>,
+) -> Result<Response> {
+    let filters = match view.view_id {
+        Some(view_id) => {
+            let view = state.saved_view_service.get_view(view_id, user_id).await?;
+            filters.or(view.filters.0)
+        }
+        None => filters,
+    };
+    let position = cursor.position()?;
+    let keyset_sort = matches!(filters.sort_by.as_deref(), None | Some("created_at"));
+    if position.is_some() && !keyset_sort {
+        return Err(AppError::BadRequest("Cursors only work when sorting by created_at".to_string()));
+    }
+
+    let page = filters.page.unwrap_or(1);
+    let limit = match position {
+        Some(_) => filters.limit.unwrap_or(10).clamp(1, MAX_CURSOR_LIMIT),
+        None => filters.limit.unwrap_or(10),
+    };
+
+    let mut repo_filters = to_repo_filters(filters, user_id, page, limit)?;
+    repo_filters.cursor = position;
+
+    let (tasks, total) = state.task_service.list_tasks(user_id, repo_filters).await?;
+    let key = |item: &TaskListItem| Cursor::new(item.task.created_at, item.task.id);
+
+    if position.is_some() {
+        return Ok(Json(CursorPage::from_rows(tasks, limit, position.as_ref(), key)).into_response());
+    }
+
+    let total_pages = (total as f64 / limit as f64).ceil() as u32;
+    // Lets a client switch to cursors after the first page
+    let next_cursor = tasks
+        .last()
+        .filter(|_| keyset_sort && page < total_pages)
+        .map(|item| key(item).encode());
+
+    Ok(Json(PaginatedResponse {
+        data: tasks,
+        total,
+        page,
+        limit,
+        total_pages,
+        next_cursor,
+    })
+    .into_response())
+}
+
+/// Get the tasks assigned to the authenticated user
+#[utoipa::path(
+    get,
+    path = "/api/tasks/assigned",
+    params(
+        ("status" = Option<TaskStatus>, Query, description = "Filter by status"),
+        ("priority" = Option<TaskPriority>, Query, description = "Filter by priority"),
+        ("search" = Option<String>, Query, description = "Full-text search over title and description"),
+        ("due_from" = Option<DateTime<Utc>>, Query, description = "Filter by due date (from)"),
+        ("due_to" = Option<DateTime<Utc>>, Query, description = "Filter by due date (to)"),
+        ("sort_by" = Option<String>, Query, description = "Sort by field (priority, due_date, created_at)"),
+        ("sort_order" = Option<String>, Query, description = "Sort order (asc, desc)"),
+        ("page" = Option<u32>, Query, description = "Page number"),
+        ("limit" = Option<u32>, Query, description = "Items per page"),
+        ("include_subtasks" = Option<bool>, Query, description = "Also list assigned subtasks (default: true)"),
+        ("project_id" = Option<Uuid>, Query, description = "Only tasks in this project")
+    ),
+    responses(
+        (status = 200, description = "Tasks assigned to you", body = PaginatedResponse<TaskListItem>),
+        (status = 400, description = "Invalid filter"),
+        (status = 401, description = "Unauthorized"),
+        (status = 422, description = "Unknown status or priority; the error lists the allowed values")
+    ),
+    tag = "tasks",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_assigned_tasks(
+    State(state): State<AppState>,
+    Extension(user_id): Extension<Uuid>,
+    AppQuery(filters): AppQuery<TaskFilters>,
+) -> Result<Json<PaginatedResponse<TaskListItem>>> {
     let page = filters.page.unwrap_or(1);
     let limit = filters.limit.unwrap_or(10);
+    let include_subtasks = filters.include_subtasks.unwrap_or(true);
 
-    let repo_filters = crate::task::task_repository::TaskFilters {
-        status: filters.status,
-        statuses: filters.statuses,
-        priority: filters.priority,
-        priorities: filters.priorities,
-        search: filters.search,
-        created_from: filters.created_from,
-        created_to: filters.created_to,
-        due_from: filters.due_from,
-        due_to: filters.due_to,
-        sort_by: filters.sort_by,
-        sort_order: filters.sort_order,
-        page: Some(page),
-        limit: Some(limit),
-        user_id: None,
-    };
+    let mut repo_filters = to_repo_filters(filters, user_id, page, limit)?;
+    repo_filters.assignee_id = Some(user_id);
+    repo_filters.include_subtasks = include_subtasks;
 
     let (tasks, total) = state.task_service.list_tasks(user_id, repo_filters).await?;
 
@@ -98,6 +303,7 @@ pub async fn get_tasks(
         page,
         limit,
         total_pages,
+        next_cursor: None,
     }))
 }
 
@@ -112,7 +318,8 @@ pub async fn get_tasks(
         ("task_id" = Uuid, Path, description = "Task ID")
     ),
     responses(
-        (status = 200, description = "Task details", body = Task),
+        (status = 200, description = "Task details with dependencies", body = TaskDetail,
+            headers(("ETag" = String, description = "Current version of the task, for If-Match"))),
         (status = 401, description = "Unauthorized"),
         (status = 404, description = "Task not found")
     ),
@@ -123,9 +330,9 @@ pub async fn get_task(
     State(state): State<AppState>,
     Extension(user_id): Extension<Uuid>,
     Path(task_id): Path<Uuid>,
-) -> Result<Json<Task>> {
-    let task = state.task_service.get_task(user_id, task_id).await?;
-    Ok(Json(task))
+) -> Result<impl IntoResponse> {
+    let task = state.task_service.get_task_detail(user_id, task_id).await?;
+    Ok((etag(task.task.row_version), Json(task)))
 }
 
 // ... (create_task)
@@ -136,7 +343,8 @@ pub async fn get_task(
     responses(
         (status = 201, description = "Task created", body = Task),
         (status = 401, description = "Unauthorized"),
-        (status = 400, description = "Validation error")
+        (status = 400, description = "Validation error"),
+        (status = 422, description = "Unknown priority; the error lists the allowed values")
     ),
     tag = "tasks",
     security(("bearer_auth" = []))
@@ -144,16 +352,13 @@ pub async fn get_task(
 pub async fn create_task(
     State(state): State<AppState>,
     Extension(user_id): Extension<Uuid>,
-    Json(payload): Json<CreateTaskRequest>,
+    AppJson(payload): AppJson<CreateTaskRequest>,
 ) -> Result<impl IntoResponse> {
     payload.validate()
         .map_err(|e| AppError::Validation(e.to_string()))?;
 
     let task = state.task_service.create_task(user_id, payload).await?;
 
-    // Broadcast task creation
-    let _ = state.task_tx.send((user_id, task.clone()));
-
     Ok((StatusCode::CREATED, Json(task)))
 }
 
@@ -162,14 +367,19 @@ pub async fn create_task(
     put,
     path = "/api/tasks/{task_id}",
     params(
-        ("task_id" = Uuid, Path, description = "Task ID")
+        ("task_id" = Uuid, Path, description = "Task ID"),
+        ("If-Match" = Option<String>, Header, description = "ETag from an earlier read; the update fails if the task changed since")
     ),
     request_body = UpdateTaskRequest,
     responses(
-        (status = 200, description = "Task updated", body = Task),
+        (status = 200, description = "Task updated", body = Task,
+            headers(("ETag" = String, description = "New version of the task"))),
         (status = 401, description = "Unauthorized"),
         (status = 404, description = "Task not found"),
-        (status = 400, description = "Validation error")
+        (status = 400, description = "Validation error"),
+        (status = 409, description = "Task is blocked by unfinished tasks"),
+        (status = 412, description = "If-Match is stale; the body carries the current task"),
+        (status = 422, description = "Unknown status or priority; the error lists the allowed values")
     ),
     tag = "tasks",
     security(("bearer_auth" = []))
@@ -178,17 +388,15 @@ pub async fn update_task(
     State(state): State<AppState>,
     Extension(user_id): Extension<Uuid>,
     Path(task_id): Path<Uuid>,
-    Json(payload): Json<UpdateTaskRequest>,
-) -> Result<Json<Task>> {
+    IfMatch(expected_version): IfMatch,
+    AppJson(payload): AppJson<UpdateTaskRequest>,
+) -> Result<impl IntoResponse> {
     payload.validate()
         .map_err(|e| AppError::Validation(e.to_string()))?;
 
-    let task = state.task_service.update_task(user_id, task_id, payload).await?;
-
-    // Broadcast task update
-    let _ = state.task_tx.send((user_id, task.clone()));
+    let task = state.task_service.update_task(user_id, task_id, payload, expected_version).await?;
 
-    Ok(Json(task))
+    Ok((etag(task.row_version), Json(task)))
 }
 
 // ... (delete_task)
@@ -199,8 +407,9 @@ pub async fn update_task(
         ("task_id" = Uuid, Path, description = "Task ID")
     ),
     responses(
-        (status = 204, description = "Task deleted"),
+        (status = 204, description = "Task and its subtasks moved to the trash"),
         (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Not the owner"),
         (status = 404, description = "Task not found")
     ),
     tag = "tasks",
@@ -220,19 +429,140 @@ pub async fn delete_task(
     Ok(StatusCode::NO_CONTENT)
 }
 
+/// Apply the same changes to many tasks at once
+///
+/// Select tasks with `task_ids` or with a `filter` taking the same fields as
+/// the `GET /api/tasks` query. The changes are made in one transaction; tasks
+/// you can't change are reported as failed and left alone.
+#[utoipa::path(
+    post,
+    path = "/api/tasks/bulk",
+    request_body = BulkTaskRequest,
+    responses(
+        (status = 200, description = "Outcome for each selected task", body = BulkTaskResponse),
+        (status = 400, description = "Invalid selection or operations, or too many tasks"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Label belongs to someone else"),
+        (status = 422, description = "Unknown status or priority; the error lists the allowed values")
+    ),
+    tag = "tasks",
+    security(("bearer_auth" = []))
+)]
+pub async fn bulk_update_tasks(
+    State(state): State<AppState>,
+    Extension(user_id): Extension<Uuid>,
+    AppJson(payload): AppJson<BulkTaskRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let task_ids = match (payload.task_ids, payload.filter) {
+        (Some(task_ids), None) => {
+            if task_ids.is_empty() || task_ids.len() > MAX_BULK_TASKS as usize {
+                return Err(AppError::BadRequest(format!(
+                    "task_ids must list between 1 and {} tasks",
+                    MAX_BULK_TASKS
+                )));
+            }
+            task_ids
+        }
+        (None, Some(filter)) => {
+            let repo_filters = to_repo_filters(filter, user_id, 1, MAX_BULK_TASKS)?;
+            state.task_service.resolve_bulk_filter(user_id, repo_filters).await?
+        }
+        _ => {
+            return Err(AppError::BadRequest("Give either task_ids or filter".to_string()));
+        }
+    };
+
+    let response = state.task_service
+        .bulk_update(user_id, task_ids, payload.operations)
+        .await?;
+
+    Ok((StatusCode::OK, Json(response)))
+}
+
+// ... (get_trash)
+#[utoipa::path(
+    get,
+    path = "/api/tasks/trash",
+    params(
+        ("page" = Option<u32>, Query, description = "Page number"),
+        ("limit" = Option<u32>, Query, description = "Items per page")
+    ),
+    responses(
+        (status = 200, description = "Your deleted tasks, newest first", body = PaginatedResponse<TrashedTask>),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "tasks",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_trash(
+    State(state): State<AppState>,
+    Extension(user_id): Extension<Uuid>,
+    Query(params): Query<PaginationParams>,
+) -> Result<Json<PaginatedResponse<TrashedTask>>> {
+    let page = params.page.unwrap_or(1).max(1);
+    let limit = params.limit.unwrap_or(10).clamp(1, 100);
+
+    let (tasks, total) = state.task_service.list_trash(user_id, page, limit).await?;
+
+    let total_pages = (total as f64 / limit as f64).ceil() as u32;
+
+    Ok(Json(PaginatedResponse {
+        data: tasks,
+        total,
+        page,
+        limit,
+        total_pages,
+        next_cursor: None,
+    }))
+}
+
+// ... (restore_task)
+#[utoipa::path(
+    post,
+    path = "/api/tasks/{task_id}/restore",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID")
+    ),
+    responses(
+        (status = 200, description = "Task and its subtasks restored", body = Task),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Task not in your trash"),
+        (status = 409, description = "The parent task is still in the trash")
+    ),
+    tag = "tasks",
+    security(("bearer_auth" = []))
+)]
+pub async fn restore_task(
+    State(state): State<AppState>,
+    Extension(user_id): Extension<Uuid>,
+    Path(task_id): Path<Uuid>,
+) -> Result<Json<Task>> {
+    let task = state.task_service.restore_task(user_id, task_id).await?;
+
+    Ok(Json(task))
+}
+
 // ... (update_task_status)
 #[utoipa::path(
     patch,
     path = "/api/tasks/{task_id}/status",
     params(
-        ("task_id" = Uuid, Path, description = "Task ID")
+        ("task_id" = Uuid, Path, description = "Task ID"),
+        ("If-Match" = Option<String>, Header, description = "ETag from an earlier read; the update fails if the task changed since")
     ),
     request_body = UpdateTaskStatusRequest,
     responses(
-        (status = 200, description = "Task status updated", body = Task),
+        (status = 200, description = "Task status updated", body = Task,
+            headers(("ETag" = String, description = "New version of the task"))),
         (status = 401, description = "Unauthorized"),
         (status = 404, description = "Task not found"),
-        (status = 400, description = "Validation error")
+        (status = 400, description = "Validation error"),
+        (status = 409, description = "Task is blocked by unfinished tasks"),
+        (status = 412, description = "If-Match is stale; the body carries the current task"),
+        (status = 422, description = "Unknown status; the error lists the allowed values")
     ),
     tag = "tasks",
     security(("bearer_auth" = []))
@@ -241,23 +571,24 @@ pub async fn update_task_status(
     State(state): State<AppState>,
     Extension(user_id): Extension<Uuid>,
     Path(task_id): Path<Uuid>,
-    Json(payload): Json<UpdateTaskStatusRequest>,
-) -> Result<Json<Task>> {
-    let task = state.task_service.update_status(user_id, task_id, payload).await?;
-
-    // Broadcast task status update
-    let _ = state.task_tx.send((user_id, task.clone()));
+    IfMatch(expected_version): IfMatch,
+    AppJson(payload): AppJson<UpdateTaskStatusRequest>,
+) -> Result<impl IntoResponse> {
+    let task = state.task_service.update_status(user_id, task_id, payload, expected_version).await?;
 
-    Ok(Json(task))
+    Ok((etag(task.row_version), Json(task)))
 }
 
 /// Real-time task stream (SSE)
+///
+/// Sends a `TaskEvent` for every change to a task you have access to,
+/// whoever made it. Deletes arrive as events without a task.
 #[utoipa::path(
     get,
     path = "/api/tasks/stream",
     tag = "tasks",
     responses(
-        (status = 200, description = "Task stream established"),
+        (status = 200, description = "Task stream established", body = TaskEvent),
         (status = 401, description = "Unauthorized")
     ),
     security(
@@ -268,11 +599,11 @@ pub async fn task_stream(
     State(state): State<AppState>,
     Extension(user_id): Extension<Uuid>,
 ) -> Sse<impl Stream<Item = std::result::Result<Event, std::convert::Infallible>>> {
-    let rx = state.task_tx.subscribe();
+    let rx = state.task_events.subscribe();
     let stream = BroadcastStream::new(rx)
         .filter_map(move |result| match result {
-            Ok((task_user_id, task)) if task_user_id == user_id => {
-                let json = serde_json::to_string(&task).ok()?;
+            Ok(event) if event.audience.contains(&user_id) => {
+                let json = serde_json::to_string(&event).ok()?;
                 Some(Ok(Event::default().data(json)))
             }
             _ => None,
@@ -283,7 +614,7 @@ pub async fn task_stream(
 
 // Collaboration endpoints
 
-/// Share task with other users
+/// Share task with other users as viewers, editors or admins
 #[utoipa::path(
     post,
     path = "/api/tasks/{task_id}/share",
@@ -292,9 +623,10 @@ pub async fn task_stream(
     ),
     request_body = super::task_dto::ShareTaskRequest,
     responses(
-        (status = 200, description = "Task shared successfully"),
+        (status = 200, description = "Task shared; users who were already members keep their role"),
+        (status = 400, description = "Validation error or owner role requested"),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Only task owner can share"),
+        (status = 403, description = "Forbidden - Only admins can share, and only the owner can add admins"),
         (status = 404, description = "Task not found")
     ),
     tag = "tasks",
@@ -304,12 +636,16 @@ pub async fn share_task(
     State(state): State<AppState>,
     Extension(user_id): Extension<Uuid>,
     Path(task_id): Path<Uuid>,
-    Json(payload): Json<super::task_dto::ShareTaskRequest>,
+    AppJson(payload): AppJson<super::task_dto::ShareTaskRequest>,
 ) -> Result<impl IntoResponse> {
     payload.validate()
         .map_err(|e| AppError::Validation(e.to_string()))?;
 
-    state.task_service.share_task(task_id, payload.user_ids.clone(), user_id).await?;
+    let role = payload.role.unwrap_or(TaskRole::Editor);
+    let added = state.task_service.share_task(task_id, payload.user_ids, role, user_id).await?;
+    if added.is_empty() {
+        return Ok(StatusCode::OK);
+    }
 
     // Get task and sharer username for notifications
     let task = state.task_service.get_task(user_id, task_id).await?;
@@ -317,7 +653,7 @@ pub async fn share_task(
         .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
 
     // Broadcast task shared event via WebSocket and send notifications
-    for shared_user_id in payload.user_ids.clone() {
+    for shared_user_id in added {
         let ws_message = crate::websocket::types::WsMessage::TaskShared(
             crate::websocket::types::TaskSharedPayload {
                 task_id,
@@ -347,9 +683,10 @@ pub async fn share_task(
     ),
     responses(
         (status = 204, description = "Collaborator removed successfully"),
+        (status = 400, description = "Cannot remove the task owner"),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Only task owner can remove collaborators"),
-        (status = 404, description = "Task not found")
+        (status = 403, description = "Forbidden - Only admins can remove members, and only the owner can remove admins"),
+        (status = 404, description = "Task not found or user is not a member")
     ),
     tag = "tasks",
     security(("bearer_auth" = []))
@@ -384,6 +721,39 @@ pub async fn remove_task_member(
     Ok(StatusCode::NO_CONTENT)
 }
 
+/// Change a task member's role
+#[utoipa::path(
+    patch,
+    path = "/api/tasks/{task_id}/members/{user_id}",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID"),
+        ("user_id" = Uuid, Path, description = "Member's user ID")
+    ),
+    request_body = super::task_dto::UpdateTaskMemberRequest,
+    responses(
+        (status = 200, description = "Role changed; returns the task's members", body = Vec<super::task_models::TaskMemberInfo>),
+        (status = 400, description = "The owner's role cannot be given or changed"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Only admins can change roles, and only the owner can manage admins"),
+        (status = 404, description = "Task not found or user is not a member")
+    ),
+    tag = "tasks",
+    security(("bearer_auth" = []))
+)]
+pub async fn update_task_member(
+    State(state): State<AppState>,
+    Extension(requesting_user): Extension<Uuid>,
+    Path((task_id, user_id)): Path<(Uuid, Uuid)>,
+    AppJson(payload): AppJson<super::task_dto::UpdateTaskMemberRequest>,
+) -> Result<Json<Vec<super::task_models::TaskMemberInfo>>> {
+    state.task_service
+        .change_member_role(task_id, user_id, payload.role, requesting_user)
+        .await?;
+
+    let members = state.task_service.get_task_members(task_id, requesting_user).await?;
+    Ok(Json(members))
+}
+
 /// Get task members
 #[utoipa::path(
     get,
@@ -409,6 +779,89 @@ pub async fn get_task_members(
     Ok(Json(members))
 }
 
+/// Assign task members to a task
+#[utoipa::path(
+    post,
+    path = "/api/tasks/{task_id}/assignees",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID")
+    ),
+    request_body = AssignTaskRequest,
+    responses(
+        (status = 200, description = "Users assigned; returns every assignee", body = Vec<TaskAssignee>),
+        (status = 400, description = "Validation error or user is not a task member"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Access denied")
+    ),
+    tag = "tasks",
+    security(("bearer_auth" = []))
+)]
+pub async fn assign_task(
+    State(state): State<AppState>,
+    Extension(user_id): Extension<Uuid>,
+    Path(task_id): Path<Uuid>,
+    AppJson(payload): AppJson<AssignTaskRequest>,
+) -> Result<Json<Vec<TaskAssignee>>> {
+    payload.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    state.task_service.assign_users(task_id, payload.user_ids, user_id).await?;
+
+    let assignees = state.task_service.get_assignees(task_id, user_id).await?;
+    Ok(Json(assignees))
+}
+
+/// Unassign a user from a task
+#[utoipa::path(
+    delete,
+    path = "/api/tasks/{task_id}/assignees/{user_id}",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID"),
+        ("user_id" = Uuid, Path, description = "User ID to unassign")
+    ),
+    responses(
+        (status = 204, description = "User unassigned"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Access denied"),
+        (status = 404, description = "User is not assigned to this task")
+    ),
+    tag = "tasks",
+    security(("bearer_auth" = []))
+)]
+pub async fn unassign_task(
+    State(state): State<AppState>,
+    Extension(requesting_user): Extension<Uuid>,
+    Path((task_id, user_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode> {
+    state.task_service.unassign_user(task_id, user_id, requesting_user).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Get the users assigned to a task
+#[utoipa::path(
+    get,
+    path = "/api/tasks/{task_id}/assignees",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID")
+    ),
+    responses(
+        (status = 200, description = "Task assignees retrieved successfully", body = Vec<TaskAssignee>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Access denied")
+    ),
+    tag = "tasks",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_task_assignees(
+    State(state): State<AppState>,
+    Extension(user_id): Extension<Uuid>,
+    Path(task_id): Path<Uuid>,
+) -> Result<Json<Vec<TaskAssignee>>> {
+    let assignees = state.task_service.get_assignees(task_id, user_id).await?;
+    Ok(Json(assignees))
+}
+
 /// Get task activity log
 #[utoipa::path(
     get,
@@ -433,3 +886,234 @@ pub async fn get_task_activity(
     let activity = state.task_service.get_task_activity(task_id, user_id).await?;
     Ok(Json(activity))
 }
+
+// Version endpoints
+
+/// Get a task's version history, newest first, with the fields each version changed
+#[utoipa::path(
+    get,
+    path = "/api/tasks/{task_id}/versions",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID")
+    ),
+    responses(
+        (status = 200, description = "Task versions retrieved successfully", body = Vec<TaskVersionDiff>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Access denied")
+    ),
+    tag = "tasks",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_task_versions(
+    State(state): State<AppState>,
+    Extension(user_id): Extension<Uuid>,
+    Path(task_id): Path<Uuid>,
+) -> Result<Json<Vec<TaskVersionDiff>>> {
+    let versions = state.task_service.list_versions(user_id, task_id).await?;
+    Ok(Json(versions))
+}
+
+/// Get one version of a task and the fields it changed
+#[utoipa::path(
+    get,
+    path = "/api/tasks/{task_id}/versions/{version}",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID"),
+        ("version" = i32, Path, description = "Version number")
+    ),
+    responses(
+        (status = 200, description = "Task version retrieved successfully", body = TaskVersionDiff),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Access denied"),
+        (status = 404, description = "Version not found")
+    ),
+    tag = "tasks",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_task_version(
+    State(state): State<AppState>,
+    Extension(user_id): Extension<Uuid>,
+    Path((task_id, version)): Path<(Uuid, i32)>,
+) -> Result<Json<TaskVersionDiff>> {
+    let version = state.task_service.get_version(user_id, task_id, version).await?;
+    Ok(Json(version))
+}
+
+/// Revert a task's fields to an earlier version
+#[utoipa::path(
+    post,
+    path = "/api/tasks/{task_id}/versions/{version}/revert",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID"),
+        ("version" = i32, Path, description = "Version to revert to")
+    ),
+    responses(
+        (status = 200, description = "Task reverted successfully", body = Task),
+        (status = 400, description = "The version's recurrence rule is no longer valid"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Access denied"),
+        (status = 404, description = "Version not found"),
+        (status = 409, description = "The reverted status is blocked by unfinished tasks")
+    ),
+    tag = "tasks",
+    security(("bearer_auth" = []))
+)]
+pub async fn revert_task_version(
+    State(state): State<AppState>,
+    Extension(user_id): Extension<Uuid>,
+    Path((task_id, version)): Path<(Uuid, i32)>,
+) -> Result<Json<Task>> {
+    let task = state.task_service.revert_to_version(user_id, task_id, version).await?;
+    Ok(Json(task))
+}
+
+// Subtask endpoints
+
+/// Create a subtask under a task
+#[utoipa::path(
+    post,
+    path = "/api/tasks/{task_id}/subtasks",
+    params(
+        ("task_id" = Uuid, Path, description = "Parent task ID")
+    ),
+    request_body = CreateTaskRequest,
+    responses(
+        (status = 201, description = "Subtask created", body = Task),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Parent task not found"),
+        (status = 422, description = "Unknown priority; the error lists the allowed values")
+    ),
+    tag = "tasks",
+    security(("bearer_auth" = []))
+)]
+pub async fn create_subtask(
+    State(state): State<AppState>,
+    Extension(user_id): Extension<Uuid>,
+    Path(task_id): Path<Uuid>,
+    AppJson(payload): AppJson<CreateTaskRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let subtask = state.task_service.create_subtask(user_id, task_id, payload).await?;
+
+    Ok((StatusCode::CREATED, Json(subtask)))
+}
+
+/// List the subtasks of a task in manual order
+#[utoipa::path(
+    get,
+    path = "/api/tasks/{task_id}/subtasks",
+    params(
+        ("task_id" = Uuid, Path, description = "Parent task ID")
+    ),
+    responses(
+        (status = 200, description = "Subtasks in order", body = Vec<TaskListItem>),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Parent task not found")
+    ),
+    tag = "tasks",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_subtasks(
+    State(state): State<AppState>,
+    Extension(user_id): Extension<Uuid>,
+    Path(task_id): Path<Uuid>,
+) -> Result<Json<Vec<TaskListItem>>> {
+    let subtasks = state.task_service.list_subtasks(user_id, task_id).await?;
+    Ok(Json(subtasks))
+}
+
+/// Reorder the subtasks of a task
+#[utoipa::path(
+    put,
+    path = "/api/tasks/{task_id}/subtasks/order",
+    params(
+        ("task_id" = Uuid, Path, description = "Parent task ID")
+    ),
+    request_body = ReorderSubtasksRequest,
+    responses(
+        (status = 200, description = "Subtasks reordered", body = Vec<TaskListItem>),
+        (status = 400, description = "subtask_ids does not match the task's subtasks"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Parent task not found")
+    ),
+    tag = "tasks",
+    security(("bearer_auth" = []))
+)]
+pub async fn reorder_subtasks(
+    State(state): State<AppState>,
+    Extension(user_id): Extension<Uuid>,
+    Path(task_id): Path<Uuid>,
+    AppJson(payload): AppJson<ReorderSubtasksRequest>,
+) -> Result<Json<Vec<TaskListItem>>> {
+    payload.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let subtasks = state.task_service
+        .reorder_subtasks(user_id, task_id, payload.subtask_ids)
+        .await?;
+    Ok(Json(subtasks))
+}
+
+// Dependency endpoints
+
+/// Mark a task as blocked by another task
+#[utoipa::path(
+    post,
+    path = "/api/tasks/{task_id}/dependencies",
+    params(
+        ("task_id" = Uuid, Path, description = "ID of the task that is blocked")
+    ),
+    request_body = AddTaskDependencyRequest,
+    responses(
+        (status = 201, description = "Dependency added", body = TaskDetail),
+        (status = 400, description = "A task cannot block itself"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Task not found"),
+        (status = 409, description = "Dependency would create a cycle")
+    ),
+    tag = "tasks",
+    security(("bearer_auth" = []))
+)]
+pub async fn add_task_dependency(
+    State(state): State<AppState>,
+    Extension(user_id): Extension<Uuid>,
+    Path(task_id): Path<Uuid>,
+    AppJson(payload): AppJson<AddTaskDependencyRequest>,
+) -> Result<impl IntoResponse> {
+    let detail = state.task_service
+        .add_dependency(user_id, task_id, payload.blocked_by_task_id)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(detail)))
+}
+
+/// Remove a blocking dependency from a task
+#[utoipa::path(
+    delete,
+    path = "/api/tasks/{task_id}/dependencies/{blocked_by_task_id}",
+    params(
+        ("task_id" = Uuid, Path, description = "ID of the task that is blocked"),
+        ("blocked_by_task_id" = Uuid, Path, description = "ID of the blocking task")
+    ),
+    responses(
+        (status = 204, description = "Dependency removed"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Task or dependency not found")
+    ),
+    tag = "tasks",
+    security(("bearer_auth" = []))
+)]
+pub async fn remove_task_dependency(
+    State(state): State<AppState>,
+    Extension(user_id): Extension<Uuid>,
+    Path((task_id, blocked_by_task_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode> {
+    state.task_service
+        .remove_dependency(user_id, task_id, blocked_by_task_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
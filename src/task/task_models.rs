@@ -1,12 +1,17 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Datelike, Duration, Months, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type, ToSchema)]
+use crate::checklist::checklist_models::ChecklistProgress;
+use crate::label::label_models::Label;
+
+/// Stored as the variant name; the `tasks` table only accepts these values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, sqlx::Type, ToSchema)]
 #[sqlx(type_name = "text")]
 pub enum TaskStatus {
+    #[default]
     Pending,
     InProgress,
     Completed,
@@ -24,10 +29,12 @@ impl std::fmt::Display for TaskStatus {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type, ToSchema)]
+/// Stored as the variant name; the `tasks` table only accepts these values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, sqlx::Type, ToSchema)]
 #[sqlx(type_name = "text")]
 pub enum TaskPriority {
     Low,
+    #[default]
     Medium,
     High,
     Urgent,
@@ -50,13 +57,187 @@ pub struct Task {
     pub user_id: Uuid,
     pub title: String,
     pub description: Option<String>,
-    pub status: String,
-    pub priority: String,
+    pub status: TaskStatus,
+    pub priority: TaskPriority,
     pub due_date: Option<DateTime<Utc>>,
     pub reminder_time: Option<DateTime<Utc>>,
     pub notified: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub parent_task_id: Option<Uuid>,
+    pub position: i32,
+    #[schema(value_type = Option<RecurrenceRule>)]
+    pub recurrence_rule: Option<sqlx::types::Json<RecurrenceRule>>,
+    pub recurrence_series_id: Option<Uuid>,
+    pub recurrence_index: Option<i32>,
+    pub project_id: Option<Uuid>,
+    /// Board column within the project
+    pub column_id: Option<Uuid>,
+    /// Manual order within the board column
+    pub board_rank: f64,
+    /// Expected effort in minutes
+    pub estimate_minutes: Option<i32>,
+    /// When the task was moved to the trash
+    pub deleted_at: Option<DateTime<Utc>>,
+    /// Goes up on every change; served as the task's ETag
+    pub row_version: i32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum RecurrenceFrequency {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum RecurrenceWeekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl RecurrenceWeekday {
+    fn days_from_monday(self) -> i64 {
+        match self {
+            RecurrenceWeekday::Monday => 0,
+            RecurrenceWeekday::Tuesday => 1,
+            RecurrenceWeekday::Wednesday => 2,
+            RecurrenceWeekday::Thursday => 3,
+            RecurrenceWeekday::Friday => 4,
+            RecurrenceWeekday::Saturday => 5,
+            RecurrenceWeekday::Sunday => 6,
+        }
+    }
+}
+
+/// RRULE-style repeat schedule for a task, anchored on its due date.
+///
+/// Weekdays are evaluated in UTC. Monthly rules repeat on `by_month_day`,
+/// clamped to the last day of shorter months.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct RecurrenceRule {
+    pub frequency: RecurrenceFrequency,
+    /// Repeat every `interval` days/weeks/months
+    #[serde(default = "default_recurrence_interval")]
+    pub interval: u32,
+    /// Weekly only: the days of the week the task repeats on
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub by_weekday: Vec<RecurrenceWeekday>,
+    /// Monthly only: the day of the month the task repeats on. Defaults to
+    /// the day of the due date the rule was set on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub by_month_day: Option<u32>,
+    /// Stop after this many occurrences in total
+    pub count: Option<u32>,
+    /// Stop once occurrences would be due after this time
+    pub until: Option<DateTime<Utc>>,
+}
+
+fn default_recurrence_interval() -> u32 {
+    1
+}
+
+/// Largest accepted `interval`, so occurrence dates stay in range
+pub const MAX_RECURRENCE_INTERVAL: u32 = 1000;
+
+impl RecurrenceRule {
+    /// Checks the parts of a rule serde can't express.
+    pub fn check(&self) -> std::result::Result<(), &'static str> {
+        if self.interval == 0 {
+            return Err("recurrence interval must be at least 1");
+        }
+        if self.interval > MAX_RECURRENCE_INTERVAL {
+            return Err("recurrence interval must be at most 1000");
+        }
+        if self.count == Some(0) {
+            return Err("recurrence count must be at least 1");
+        }
+        if !self.by_weekday.is_empty() && self.frequency != RecurrenceFrequency::Weekly {
+            return Err("by_weekday is only supported for weekly recurrence");
+        }
+        if let Some(day) = self.by_month_day {
+            if self.frequency != RecurrenceFrequency::Monthly {
+                return Err("by_month_day is only supported for monthly recurrence");
+            }
+            if !(1..=31).contains(&day) {
+                return Err("by_month_day must be between 1 and 31");
+            }
+        }
+        Ok(())
+    }
+
+    /// The rule as stored for a series starting at `due`: monthly rules are
+    /// pinned to its day of month, so a clamped occurrence (Feb 28 after
+    /// Jan 31) doesn't move the ones after it.
+    pub fn anchored_at(&self, due: DateTime<Utc>) -> RecurrenceRule {
+        let mut rule = self.clone();
+        if rule.frequency == RecurrenceFrequency::Monthly && rule.by_month_day.is_none() {
+            rule.by_month_day = Some(due.day());
+        }
+        rule
+    }
+
+    /// Due date of the occurrence right after one due at `due`, or `None`
+    /// if it would be out of range.
+    fn step(&self, due: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self.frequency {
+            RecurrenceFrequency::Daily => due.checked_add_signed(Duration::days(self.interval as i64)),
+            RecurrenceFrequency::Monthly => {
+                let next = due.checked_add_months(Months::new(self.interval))?;
+                match self.by_month_day {
+                    // Back on the anchor day, or the last day of a shorter month
+                    Some(day) => (1..=day).rev().find_map(|day| next.with_day(day)),
+                    None => Some(next),
+                }
+            }
+            RecurrenceFrequency::Weekly if self.by_weekday.is_empty() => {
+                due.checked_add_signed(Duration::weeks(self.interval as i64))
+            }
+            RecurrenceFrequency::Weekly => {
+                let today = due.weekday().num_days_from_monday() as i64;
+                let mut days: Vec<i64> = self.by_weekday.iter().map(|d| d.days_from_monday()).collect();
+                days.sort_unstable();
+
+                match days.iter().find(|d| **d > today) {
+                    // Later the same week
+                    Some(day) => due.checked_add_signed(Duration::days(day - today)),
+                    // First listed day of the next week in the cycle
+                    None => due.checked_add_signed(Duration::days(7 * self.interval as i64 - today + days[0])),
+                }
+            }
+        }
+    }
+
+    /// First occurrence after the one numbered `index` (due at `due`) that is
+    /// due after `not_before`, as `(due_date, index)`. Occurrences that would
+    /// already be overdue are skipped. Returns `None` once the rule has ended.
+    pub fn next_occurrence(
+        &self,
+        due: DateTime<Utc>,
+        index: i32,
+        not_before: DateTime<Utc>,
+    ) -> Option<(DateTime<Utc>, i32)> {
+        let (mut next_due, mut next_index) = (due, index);
+        loop {
+            next_due = self.step(next_due)?;
+            next_index += 1;
+
+            if self.count.is_some_and(|count| next_index as i64 > count as i64) {
+                return None;
+            }
+            if self.until.is_some_and(|until| next_due > until) {
+                return None;
+            }
+            if next_due > not_before {
+                return Some((next_due, next_index));
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
@@ -90,6 +271,58 @@ pub struct TaskWithMembers {
     pub is_owner: bool,
 }
 
+/// A task in its owner's trash.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct TrashedTask {
+    #[serde(flatten)]
+    #[sqlx(flatten)]
+    pub task: Task,
+    pub deleted_by: Option<Uuid>,
+    /// When the task will be removed for good
+    pub purge_at: DateTime<Utc>,
+}
+
+/// Completed/total counts of a task's direct subtasks.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, ToSchema)]
+pub struct SubtaskProgress {
+    pub completed: i64,
+    pub total: i64,
+}
+
+/// Task as returned by list endpoints, with roll-up data for its children.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TaskListItem {
+    #[serde(flatten)]
+    pub task: Task,
+    pub subtask_progress: SubtaskProgress,
+    pub checklist_progress: ChecklistProgress,
+    pub labels: Vec<Label>,
+}
+
+/// Single task view including its dependency links.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TaskDetail {
+    #[serde(flatten)]
+    pub task: Task,
+    /// Tasks that must be finished before this one can start
+    pub blocked_by: Vec<TaskDependencyInfo>,
+    /// Tasks waiting on this one
+    pub blocking: Vec<TaskDependencyInfo>,
+    pub labels: Vec<Label>,
+    /// Members responsible for the task
+    pub assignees: Vec<TaskAssignee>,
+}
+
+/// The task on the other end of a dependency link.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, sqlx::FromRow)]
+pub struct TaskDependencyInfo {
+    pub task_id: Uuid,
+    pub title: String,
+    pub status: TaskStatus,
+    pub user_id: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema, sqlx::FromRow)]
 pub struct TaskMemberInfo {
     pub user_id: Uuid,
@@ -99,6 +332,310 @@ pub struct TaskMemberInfo {
     pub added_at: DateTime<Utc>,
 }
 
+/// A user's role on a task, ordered from least to most privileged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "text", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum TaskRole {
+    Viewer,
+    Editor,
+    Admin,
+    Owner,
+}
+
+impl std::fmt::Display for TaskRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TaskRole::Viewer => write!(f, "viewer"),
+            TaskRole::Editor => write!(f, "editor"),
+            TaskRole::Admin => write!(f, "admin"),
+            TaskRole::Owner => write!(f, "owner"),
+        }
+    }
+}
+
+/// Something a user can do to a task, each needing a minimum role.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskPermission {
+    /// Read the task, its members, history and activity
+    View,
+    /// Change the task, its subtasks, dependencies and assignees
+    Edit,
+    /// Share the task and change or remove members
+    ManageMembers,
+    /// Move the task to the trash
+    Delete,
+}
+
+impl TaskPermission {
+    pub fn required_role(self) -> TaskRole {
+        match self {
+            TaskPermission::View => TaskRole::Viewer,
+            TaskPermission::Edit => TaskRole::Editor,
+            TaskPermission::ManageMembers => TaskRole::Admin,
+            TaskPermission::Delete => TaskRole::Owner,
+        }
+    }
+
+    fn describe(self) -> &'static str {
+        match self {
+            TaskPermission::View => "view this task",
+            TaskPermission::Edit => "edit this task",
+            TaskPermission::ManageMembers => "manage this task's members",
+            TaskPermission::Delete => "delete this task",
+        }
+    }
+}
+
+impl TaskRole {
+    pub fn can(self, permission: TaskPermission) -> bool {
+        self >= permission.required_role()
+    }
+
+    /// Whether this role may give `role` to someone, or take it away. Owners
+    /// manage everyone else; admins manage editors and viewers. There is only
+    /// ever one owner.
+    pub fn can_manage(self, role: TaskRole) -> bool {
+        match self {
+            TaskRole::Owner => role != TaskRole::Owner,
+            TaskRole::Admin => role <= TaskRole::Editor,
+            TaskRole::Editor | TaskRole::Viewer => false,
+        }
+    }
+
+    /// The message for a user with this role attempting something it doesn't allow.
+    pub fn denied(self, permission: TaskPermission) -> String {
+        format!("A task {} cannot {}", self, permission.describe())
+    }
+}
+
+/// A member who is responsible for a task.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, sqlx::FromRow)]
+pub struct TaskAssignee {
+    pub user_id: Uuid,
+    pub username: String,
+    pub avatar_url: Option<String>,
+    pub assigned_by: Option<Uuid>,
+    pub assigned_at: DateTime<Utc>,
+}
+
+/// One change the bulk endpoint applies to every selected task.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum BulkTaskOperation {
+    SetStatus { status: TaskStatus },
+    SetPriority { priority: TaskPriority },
+    /// Moves the due date, and the reminder with it, by this many minutes;
+    /// tasks without a due date are left alone
+    ShiftDueDate { minutes: i32 },
+    /// Attaches one of your own labels
+    AddLabel { label_id: Uuid },
+    RemoveLabel { label_id: Uuid },
+    /// Same as setting the status to Archived
+    Archive,
+    /// Moves the tasks to the trash; can't be combined with other operations
+    Delete,
+}
+
+impl BulkTaskOperation {
+    pub fn required_permission(&self) -> TaskPermission {
+        match self {
+            BulkTaskOperation::Delete => TaskPermission::Delete,
+            _ => TaskPermission::Edit,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            BulkTaskOperation::SetStatus { .. } => "set_status",
+            BulkTaskOperation::SetPriority { .. } => "set_priority",
+            BulkTaskOperation::ShiftDueDate { .. } => "shift_due_date",
+            BulkTaskOperation::AddLabel { .. } => "add_label",
+            BulkTaskOperation::RemoveLabel { .. } => "remove_label",
+            BulkTaskOperation::Archive => "archive",
+            BulkTaskOperation::Delete => "delete",
+        }
+    }
+
+    /// The status this operation sets, if any.
+    pub fn new_status(&self) -> Option<TaskStatus> {
+        match self {
+            BulkTaskOperation::SetStatus { status } => Some(*status),
+            BulkTaskOperation::Archive => Some(TaskStatus::Archived),
+            _ => None,
+        }
+    }
+}
+
+/// How one task of a bulk request fared.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BulkTaskResult {
+    pub task_id: Uuid,
+    pub success: bool,
+    /// Why the task was skipped
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// The task after the change
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task: Option<Task>,
+}
+
+impl BulkTaskResult {
+    pub fn failed(task_id: Uuid, error: impl Into<String>) -> Self {
+        Self {
+            task_id,
+            success: false,
+            error: Some(error.into()),
+            task: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BulkTaskResponse {
+    pub succeeded: usize,
+    pub failed: usize,
+    /// One entry per selected task, in request order
+    pub results: Vec<BulkTaskResult>,
+}
+
+/// What happened to a task in a [`TaskEvent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskEventKind {
+    Created,
+    Updated,
+    StatusChanged,
+    /// Moved to the trash or purged; the event is a tombstone without a task
+    Deleted,
+    Restored,
+    Shared,
+    MemberRoleChanged,
+    MemberRemoved,
+    Assigned,
+    Unassigned,
+    Commented,
+    /// A checklist item was added, edited, ticked, removed or moved
+    ChecklistChanged,
+    LabelsChanged,
+    DependenciesChanged,
+    SubtasksReordered,
+}
+
+/// A change to a task, delivered to everyone with access to it over the task
+/// SSE stream and as a `task_event` WebSocket message.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TaskEvent {
+    pub kind: TaskEventKind,
+    pub task_id: Uuid,
+    /// `None` for changes made by the system, such as a new recurrence
+    pub actor_id: Option<Uuid>,
+    /// The task after the change; `None` for deletes
+    pub task: Option<Task>,
+    /// Details of the change, depending on `kind`
+    #[schema(value_type = Option<Object>)]
+    pub data: Option<serde_json::Value>,
+    pub occurred_at: DateTime<Utc>,
+    /// Who the event is for
+    #[serde(skip)]
+    pub audience: Vec<Uuid>,
+}
+
+/// The editable fields of a task as they were at one version.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct TaskSnapshot {
+    pub title: String,
+    pub description: Option<String>,
+    pub status: TaskStatus,
+    pub priority: TaskPriority,
+    pub due_date: Option<DateTime<Utc>>,
+    pub reminder_time: Option<DateTime<Utc>>,
+    pub estimate_minutes: Option<i32>,
+    pub recurrence_rule: Option<RecurrenceRule>,
+}
+
+impl From<&Task> for TaskSnapshot {
+    fn from(task: &Task) -> Self {
+        Self {
+            title: task.title.clone(),
+            description: task.description.clone(),
+            status: task.status,
+            priority: task.priority,
+            due_date: task.due_date,
+            reminder_time: task.reminder_time,
+            estimate_minutes: task.estimate_minutes,
+            recurrence_rule: task.recurrence_rule.as_ref().map(|rule| rule.0.clone()),
+        }
+    }
+}
+
+/// Snapshot fields in display order.
+const SNAPSHOT_FIELDS: [&str; 8] = [
+    "title",
+    "description",
+    "status",
+    "priority",
+    "due_date",
+    "reminder_time",
+    "estimate_minutes",
+    "recurrence_rule",
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, sqlx::FromRow)]
+pub struct TaskVersion {
+    pub task_id: Uuid,
+    /// Starts at 1 and goes up by one with every change
+    pub version: i32,
+    #[schema(value_type = TaskSnapshot)]
+    pub snapshot: sqlx::types::Json<TaskSnapshot>,
+    /// What produced this version, e.g. `updated` or `reverted`
+    pub action: String,
+    /// `None` for changes made by the system
+    pub changed_by: Option<Uuid>,
+    pub changed_by_username: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// One field's value before and after a change.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct FieldChange {
+    pub field: String,
+    pub before: serde_json::Value,
+    pub after: serde_json::Value,
+}
+
+/// A version together with what it changed compared to the version before it.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TaskVersionDiff {
+    #[serde(flatten)]
+    pub version: TaskVersion,
+    pub changes: Vec<FieldChange>,
+}
+
+/// Field-level differences between two snapshots. Without a `before`
+/// snapshot, every field that has a value counts as changed.
+pub fn diff_snapshots(before: Option<&TaskSnapshot>, after: &TaskSnapshot) -> Vec<FieldChange> {
+    let to_map = |snapshot: &TaskSnapshot| match serde_json::to_value(snapshot) {
+        Ok(serde_json::Value::Object(map)) => map,
+        _ => serde_json::Map::new(),
+    };
+    let before = before.map(to_map).unwrap_or_default();
+    let after = to_map(after);
+
+    SNAPSHOT_FIELDS
+        .iter()
+        .filter_map(|&field| {
+            let old = before.get(field).cloned().unwrap_or(serde_json::Value::Null);
+            let new = after.get(field).cloned().unwrap_or(serde_json::Value::Null);
+            (old != new).then(|| FieldChange {
+                field: field.to_string(),
+                before: old,
+                after: new,
+            })
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -118,4 +655,197 @@ mod tests {
         assert_eq!(TaskPriority::High.to_string(), "High");
         assert_eq!(TaskPriority::Urgent.to_string(), "Urgent");
     }
+
+    #[test]
+    fn test_unknown_status_and_priority_are_rejected() {
+        let status = serde_json::from_value::<TaskStatus>(serde_json::json!("done")).unwrap_err();
+        assert!(status.to_string().contains("expected one of `Pending`, `InProgress`, `Completed`, `Archived`"));
+
+        let priority = serde_json::from_value::<TaskPriority>(serde_json::json!("high")).unwrap_err();
+        assert!(priority.to_string().contains("`Low`, `Medium`, `High`, `Urgent`"));
+
+        assert_eq!(TaskStatus::default(), TaskStatus::Pending);
+        assert_eq!(TaskPriority::default(), TaskPriority::Medium);
+    }
+
+    fn at(s: &str) -> DateTime<Utc> {
+        s.parse().unwrap()
+    }
+
+    fn rule(frequency: RecurrenceFrequency, interval: u32) -> RecurrenceRule {
+        RecurrenceRule {
+            frequency,
+            interval,
+            by_weekday: Vec::new(),
+            by_month_day: None,
+            count: None,
+            until: None,
+        }
+    }
+
+    #[test]
+    fn test_recurrence_next_occurrence() {
+        let due = at("2026-01-31T09:00:00Z");
+
+        let daily = rule(RecurrenceFrequency::Daily, 2);
+        assert_eq!(daily.next_occurrence(due, 1, due), Some((at("2026-02-02T09:00:00Z"), 2)));
+
+        // Missed occurrences are skipped, but still counted
+        let weekly = rule(RecurrenceFrequency::Weekly, 1);
+        assert_eq!(
+            weekly.next_occurrence(due, 1, at("2026-02-10T00:00:00Z")),
+            Some((at("2026-02-14T09:00:00Z"), 3))
+        );
+
+        // Clamped to the end of February
+        let monthly = rule(RecurrenceFrequency::Monthly, 1);
+        assert_eq!(monthly.next_occurrence(due, 1, due), Some((at("2026-02-28T09:00:00Z"), 2)));
+    }
+
+    #[test]
+    fn test_monthly_recurrence_returns_to_anchor_day() {
+        let due = at("2026-01-31T09:00:00Z");
+        let monthly = rule(RecurrenceFrequency::Monthly, 1).anchored_at(due);
+        assert_eq!(monthly.by_month_day, Some(31));
+
+        let (feb, index) = monthly.next_occurrence(due, 1, due).unwrap();
+        assert_eq!(feb, at("2026-02-28T09:00:00Z"));
+        let (mar, index) = monthly.next_occurrence(feb, index, feb).unwrap();
+        assert_eq!(mar, at("2026-03-31T09:00:00Z"));
+        let (apr, _) = monthly.next_occurrence(mar, index, mar).unwrap();
+        assert_eq!(apr, at("2026-04-30T09:00:00Z"));
+
+        // An explicit day is kept
+        let mut fifteenth = rule(RecurrenceFrequency::Monthly, 1);
+        fifteenth.by_month_day = Some(15);
+        assert_eq!(fifteenth.anchored_at(due).by_month_day, Some(15));
+        assert!(rule(RecurrenceFrequency::Weekly, 1).anchored_at(due).by_month_day.is_none());
+    }
+
+    #[test]
+    fn test_recurrence_out_of_range_ends_series() {
+        let due = DateTime::<Utc>::MAX_UTC - Duration::days(1);
+        assert!(rule(RecurrenceFrequency::Daily, 2).next_occurrence(due, 1, due).is_none());
+        assert!(rule(RecurrenceFrequency::Weekly, 1).next_occurrence(due, 1, due).is_none());
+        assert!(rule(RecurrenceFrequency::Monthly, 1).next_occurrence(due, 1, due).is_none());
+
+        assert!(rule(RecurrenceFrequency::Daily, MAX_RECURRENCE_INTERVAL).check().is_ok());
+        assert!(rule(RecurrenceFrequency::Daily, MAX_RECURRENCE_INTERVAL + 1).check().is_err());
+        let mut daily_on_day = rule(RecurrenceFrequency::Daily, 1);
+        daily_on_day.by_month_day = Some(3);
+        assert!(daily_on_day.check().is_err());
+    }
+
+    #[test]
+    fn test_recurrence_by_weekday_and_end_conditions() {
+        // 2026-01-31 is a Saturday
+        let due = at("2026-01-31T09:00:00Z");
+        let mut every_other_week = rule(RecurrenceFrequency::Weekly, 2);
+        every_other_week.by_weekday = vec![RecurrenceWeekday::Saturday, RecurrenceWeekday::Tuesday];
+
+        let (next, _) = every_other_week.next_occurrence(due, 1, due).unwrap();
+        assert_eq!(next, at("2026-02-10T09:00:00Z"));
+        let (next, _) = every_other_week.next_occurrence(next, 2, next).unwrap();
+        assert_eq!(next, at("2026-02-14T09:00:00Z"));
+
+        let mut limited = rule(RecurrenceFrequency::Daily, 1);
+        limited.count = Some(2);
+        assert!(limited.next_occurrence(due, 1, due).is_some());
+        assert!(limited.next_occurrence(due, 2, due).is_none());
+
+        limited.count = None;
+        limited.until = Some(at("2026-02-01T00:00:00Z"));
+        assert!(limited.next_occurrence(due, 1, due).is_none());
+
+        assert!(rule(RecurrenceFrequency::Daily, 0).check().is_err());
+        every_other_week.frequency = RecurrenceFrequency::Monthly;
+        assert!(every_other_week.check().is_err());
+    }
+
+    #[test]
+    fn test_diff_snapshots_lists_changed_fields_in_order() {
+        let v1 = TaskSnapshot {
+            title: "Draft".to_string(),
+            description: None,
+            status: TaskStatus::Pending,
+            priority: TaskPriority::Medium,
+            due_date: None,
+            reminder_time: None,
+            estimate_minutes: Some(30),
+            recurrence_rule: None,
+        };
+        let mut v2 = v1.clone();
+        v2.status = TaskStatus::InProgress;
+        v2.title = "Final".to_string();
+        v2.estimate_minutes = None;
+
+        let changes = diff_snapshots(Some(&v1), &v2);
+        let fields: Vec<&str> = changes.iter().map(|c| c.field.as_str()).collect();
+        assert_eq!(fields, vec!["title", "status", "estimate_minutes"]);
+        assert_eq!(changes[2].before, serde_json::json!(30));
+        assert_eq!(changes[2].after, serde_json::Value::Null);
+
+        assert!(diff_snapshots(Some(&v2), &v2).is_empty());
+
+        // The first version shows every field that was set
+        let created: Vec<String> = diff_snapshots(None, &v1).into_iter().map(|c| c.field).collect();
+        assert_eq!(created, vec!["title", "status", "priority", "estimate_minutes"]);
+    }
+
+    #[test]
+    fn test_task_role_permissions() {
+        use TaskPermission::*;
+
+        assert!(TaskRole::Viewer.can(View));
+        assert!(!TaskRole::Viewer.can(Edit));
+        assert!(TaskRole::Editor.can(Edit));
+        assert!(!TaskRole::Editor.can(ManageMembers));
+        assert!(TaskRole::Admin.can(ManageMembers));
+        assert!(!TaskRole::Admin.can(Delete));
+        assert!(TaskRole::Owner.can(Delete));
+
+        assert!(TaskRole::Admin.can_manage(TaskRole::Editor));
+        assert!(!TaskRole::Admin.can_manage(TaskRole::Admin));
+        assert!(TaskRole::Owner.can_manage(TaskRole::Admin));
+        assert!(!TaskRole::Owner.can_manage(TaskRole::Owner));
+        assert!(!TaskRole::Editor.can_manage(TaskRole::Viewer));
+    }
+
+    #[test]
+    fn test_bulk_operation_parsing() {
+        let ops: Vec<BulkTaskOperation> = serde_json::from_value(serde_json::json!([
+            {"op": "set_status", "status": "Completed"},
+            {"op": "shift_due_date", "minutes": -1440},
+            {"op": "archive"},
+            {"op": "delete"}
+        ]))
+        .unwrap();
+
+        assert!(matches!(ops[0].new_status(), Some(TaskStatus::Completed)));
+        assert!(matches!(ops[1], BulkTaskOperation::ShiftDueDate { minutes: -1440 }));
+        assert!(matches!(ops[2].new_status(), Some(TaskStatus::Archived)));
+        assert_eq!(ops[2].required_permission(), TaskPermission::Edit);
+        assert_eq!(ops[3].required_permission(), TaskPermission::Delete);
+
+        let unknown = serde_json::from_value::<BulkTaskOperation>(serde_json::json!({"op": "set_status", "status": "Done"}));
+        assert!(unknown.is_err());
+    }
+
+    #[test]
+    fn test_task_event_tombstone_keeps_audience_private() {
+        let event = TaskEvent {
+            kind: TaskEventKind::Deleted,
+            task_id: Uuid::new_v4(),
+            actor_id: Some(Uuid::new_v4()),
+            task: None,
+            data: Some(serde_json::json!({"tasks_trashed": 3})),
+            occurred_at: Utc::now(),
+            audience: vec![Uuid::new_v4()],
+        };
+
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["kind"], "deleted");
+        assert!(json["task"].is_null());
+        assert!(json.get("audience").is_none());
+    }
 }
@@ -1,8 +1,101 @@
 use crate::error::Result;
+use crate::pagination::{keyset_sql, CursorPosition};
+use crate::search::search_models::to_prefix_tsquery;
 use sqlx::PgPool;
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
-use super::task_models::Task;
+use std::collections::HashMap;
+use super::task_models::{
+    BulkTaskOperation, RecurrenceRule, SubtaskProgress, Task, TaskAssignee, TaskDependencyInfo, TaskPriority, TaskRole,
+    TaskSnapshot, TaskStatus, TaskVersion, TrashedTask,
+};
+
+/// Moves a task ($1) and its live descendants to the trash, recording who did it ($2).
+/// All rows share the same `deleted_at`, which is how a restore finds them again.
+pub(crate) const SOFT_DELETE_TASK_TREE: &str =
+    "WITH RECURSIVE tree AS (
+        SELECT id FROM tasks WHERE id = $1 AND deleted_at IS NULL
+        UNION ALL
+        SELECT t.id FROM tasks t JOIN tree ON t.parent_task_id = tree.id
+        WHERE t.deleted_at IS NULL
+     )
+     UPDATE tasks SET deleted_at = NOW(), deleted_by = $2
+     WHERE id IN (SELECT id FROM tree)";
+
+/// Live tasks `t` that user $1 can see: their own, those shared with them
+/// through `task_members`, and subtasks and project tasks reached through a
+/// parent or project. Follows `SELECT ...`; add further conditions with `AND`.
+/// Tasks with several members are joined more than once, so select DISTINCT.
+pub(crate) const ACCESSIBLE_TASKS: &str =
+    "FROM tasks t
+     LEFT JOIN task_members tm ON t.id = tm.task_id
+     WHERE t.deleted_at IS NULL
+     AND (t.user_id = $1 OR tm.user_id = $1
+            OR ((t.parent_task_id IS NOT NULL OR t.project_id IS NOT NULL)
+                AND user_has_task_access(t.id, $1)))";
+
+/// Permanently deletes the tasks in $1 and their descendants. Returns the number
+/// of rows removed and the storage keys of their attachments, which the caller
+/// must remove from file storage.
+pub(crate) const PURGE_TASK_TREES: &str =
+    "WITH RECURSIVE tree AS (
+        SELECT id FROM tasks WHERE id = ANY($1)
+        UNION
+        SELECT t.id FROM tasks t JOIN tree ON t.parent_task_id = tree.id
+     ),
+     files AS (
+        SELECT storage_key FROM attachments WHERE task_id IN (SELECT id FROM tree)
+     ),
+     purged AS (
+        DELETE FROM tasks WHERE id IN (SELECT id FROM tree) RETURNING id
+     )
+     SELECT (SELECT COUNT(*) FROM purged), ARRAY(SELECT storage_key FROM files)";
+
+/// The editable fields of a `tasks` row `t` as a JSON object, matching `TaskSnapshot`.
+const TASK_SNAPSHOT: &str =
+    "jsonb_build_object(
+        'title', t.title,
+        'description', t.description,
+        'status', t.status,
+        'priority', t.priority,
+        'due_date', t.due_date,
+        'reminder_time', t.reminder_time,
+        'estimate_minutes', t.estimate_minutes,
+        'recurrence_rule', t.recurrence_rule
+     )";
+
+/// Records the current state of each task in `ids` as its next version; see
+/// `TaskRepository::record_versions`. Runs on any executor so it can join a
+/// transaction.
+async fn insert_versions<'e, E: sqlx::PgExecutor<'e>>(
+    executor: E,
+    ids: &[Uuid],
+    changed_by: Option<Uuid>,
+    action: &str,
+) -> Result<u64> {
+    let result = sqlx::query(&format!(
+        "INSERT INTO task_versions (task_id, version, snapshot, action, changed_by)
+         SELECT t.id, COALESCE(latest.version, 0) + 1, {snapshot}, $3, $2
+         FROM tasks t
+         LEFT JOIN LATERAL (
+            SELECT version, snapshot FROM task_versions
+            WHERE task_id = t.id
+            ORDER BY version DESC
+            LIMIT 1
+         ) latest ON TRUE
+         WHERE t.id = ANY($1)
+         AND latest.snapshot IS DISTINCT FROM {snapshot}
+         ON CONFLICT (task_id, version) DO NOTHING",
+        snapshot = TASK_SNAPSHOT
+    ))
+    .bind(ids)
+    .bind(changed_by)
+    .bind(action)
+    .execute(executor)
+    .await?;
+
+    Ok(result.rows_affected())
+}
 
 #[derive(Clone)]
 pub struct TaskRepository {
@@ -10,10 +103,10 @@ pub struct TaskRepository {
 }
 
 pub struct TaskFilters {
-    pub status: Option<String>,
-    pub statuses: Option<Vec<String>>,
-    pub priority: Option<String>,
-    pub priorities: Option<Vec<String>>,
+    pub status: Option<TaskStatus>,
+    pub statuses: Option<Vec<TaskStatus>>,
+    pub priority: Option<TaskPriority>,
+    pub priorities: Option<Vec<TaskPriority>>,
     pub search: Option<String>,
     pub created_from: Option<DateTime<Utc>>,
     pub created_to: Option<DateTime<Utc>>,
@@ -24,6 +117,17 @@ pub struct TaskFilters {
     pub page: Option<u32>,
     pub limit: Option<u32>,
     pub user_id: Option<Uuid>,
+    pub project_id: Option<Uuid>,
+    pub include_subtasks: bool,
+    /// Only tasks carrying these labels
+    pub labels: Option<Vec<Uuid>>,
+    /// Require every label in `labels` instead of any of them
+    pub labels_match_all: bool,
+    /// Only tasks assigned to this user
+    pub assignee_id: Option<Uuid>,
+    /// Read the page next to this cursor instead of using `page`; only valid
+    /// when sorting by `created_at`
+    pub cursor: Option<CursorPosition>,
 }
 
 impl TaskRepository {
@@ -33,9 +137,11 @@ impl TaskRepository {
 
     #[allow(dead_code)]
     pub async fn find_all(&self, user_id: Uuid, filters: TaskFilters) -> Result<(Vec<Task>, i64)> {
-        let mut query = "SELECT * FROM tasks WHERE user_id = $1".to_string();
-        let mut count_query = "SELECT COUNT(*) FROM tasks WHERE user_id = $1".to_string();
+        let mut query = "SELECT * FROM tasks WHERE user_id = $1 AND deleted_at IS NULL".to_string();
+        let mut count_query = "SELECT COUNT(*) FROM tasks WHERE user_id = $1 AND deleted_at IS NULL".to_string();
         let mut params_count: usize = 1;
+        // Free text becomes a prefix tsquery; punctuation-only input matches everything
+        let search = filters.search.as_deref().and_then(to_prefix_tsquery);
 
         // Status filters
         if let Some(ref statuses) = filters.statuses {
@@ -70,9 +176,9 @@ impl TaskRepository {
         }
 
         // Search filter
-        if let Some(ref _search) = filters.search {
+        if search.is_some() {
             params_count += 1;
-            let filter = format!(" AND (title ILIKE ${} OR description ILIKE ${})", params_count, params_count);
+            let filter = format!(" AND search_vector @@ to_tsquery('english', ${})", params_count);
             query.push_str(&filter);
             count_query.push_str(&filter);
         }
@@ -119,8 +225,8 @@ impl TaskRepository {
             count_db_query = count_db_query.bind(priority);
         }
 
-        if let Some(search) = &filters.search {
-            count_db_query = count_db_query.bind(format!("%{}%", search));
+        if let Some(search) = &search {
+            count_db_query = count_db_query.bind(search);
         }
 
         if let Some(from) = filters.created_from { count_db_query = count_db_query.bind(from); }
@@ -165,8 +271,8 @@ impl TaskRepository {
             db_query = db_query.bind(priority);
         }
 
-        if let Some(search) = filters.search {
-            db_query = db_query.bind(format!("%{}%", search));
+        if let Some(search) = search {
+            db_query = db_query.bind(search);
         }
 
         if let Some(from) = filters.created_from { db_query = db_query.bind(from); }
@@ -180,7 +286,7 @@ impl TaskRepository {
 
     #[allow(dead_code)]
     pub async fn find_by_id(&self, id: Uuid, user_id: Uuid) -> Result<Option<Task>> {
-        let task = sqlx::query_as::<_, Task>("SELECT * FROM tasks WHERE id = $1 AND user_id = $2")
+        let task = sqlx::query_as::<_, Task>("SELECT * FROM tasks WHERE id = $1 AND user_id = $2 AND deleted_at IS NULL")
             .bind(id)
             .bind(user_id)
             .fetch_optional(&self.pool)
@@ -188,18 +294,26 @@ impl TaskRepository {
         Ok(task)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn create(
         &self,
         user_id: Uuid,
         title: &str,
         description: Option<&str>,
-        priority: &str,
+        priority: TaskPriority,
         due_date: Option<DateTime<Utc>>,
         reminder_time: Option<DateTime<Utc>>,
+        estimate_minutes: Option<i32>,
+        parent_task_id: Option<Uuid>,
     ) -> Result<Task> {
+        // Subtasks are appended after their existing siblings
         let task = sqlx::query_as::<_, Task>(
-            "INSERT INTO tasks (user_id, title, description, priority, due_date, reminder_time)
-             VALUES ($1, $2, $3, $4, $5, $6)
+            "INSERT INTO tasks (user_id, title, description, priority, due_date, reminder_time, estimate_minutes,
+                                parent_task_id, position)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8,
+                     CASE WHEN $8::uuid IS NULL THEN 0
+                          ELSE COALESCE((SELECT MAX(position) + 1 FROM tasks WHERE parent_task_id = $8), 0)
+                     END)
              RETURNING *"
         )
         .bind(user_id)
@@ -208,23 +322,29 @@ impl TaskRepository {
         .bind(priority)
         .bind(due_date)
         .bind(reminder_time)
+        .bind(estimate_minutes)
+        .bind(parent_task_id)
         .fetch_one(&self.pool)
         .await?;
 
         Ok(task)
     }
 
+    /// Updates the given fields. With an `expected_version`, only updates the
+    /// task if it is still at that version; returns `None` otherwise.
+    #[allow(clippy::too_many_arguments)]
     pub async fn update(
         &self,
         id: Uuid,
-        user_id: Uuid,
         title: Option<&str>,
         description: Option<&str>,
-        status: Option<&str>,
-        priority: Option<&str>,
+        status: Option<TaskStatus>,
+        priority: Option<TaskPriority>,
         due_date: Option<DateTime<Utc>>,
         reminder_time: Option<DateTime<Utc>>,
-    ) -> Result<Task> {
+        estimate_minutes: Option<i32>,
+        expected_version: Option<i32>,
+    ) -> Result<Option<Task>> {
         let task = sqlx::query_as::<_, Task>(
             "UPDATE tasks SET
                 title = COALESCE($1, title),
@@ -234,8 +354,10 @@ impl TaskRepository {
                 due_date = COALESCE($5, due_date),
                 reminder_time = COALESCE($6, reminder_time),
                 notified = CASE WHEN $6 IS NOT NULL THEN false ELSE notified END,
+                estimate_minutes = COALESCE($7, estimate_minutes),
                 updated_at = NOW()
-             WHERE id = $7 AND user_id = $8
+             WHERE id = $8
+             AND ($9::int IS NULL OR row_version = $9)
              RETURNING *"
         )
         .bind(title)
@@ -244,33 +366,134 @@ impl TaskRepository {
         .bind(priority)
         .bind(due_date)
         .bind(reminder_time)
+        .bind(estimate_minutes)
         .bind(id)
-        .bind(user_id)
-        .fetch_one(&self.pool)
+        .bind(expected_version)
+        .fetch_optional(&self.pool)
         .await?;
 
         Ok(task)
     }
 
-    pub async fn delete(&self, id: Uuid, user_id: Uuid) -> Result<u64> {
-        let result = sqlx::query("DELETE FROM tasks WHERE id = $1 AND user_id = $2")
+    /// Moves a task and its subtasks to the trash. Returns the number of tasks trashed.
+    pub async fn soft_delete(&self, id: Uuid, deleted_by: Uuid) -> Result<u64> {
+        let result = sqlx::query(SOFT_DELETE_TASK_TREE)
             .bind(id)
-            .bind(user_id)
+            .bind(deleted_by)
             .execute(&self.pool)
             .await?;
-        
+
         Ok(result.rows_affected())
     }
 
-    pub async fn update_status(&self, id: Uuid, user_id: Uuid, status: &str) -> Result<Option<Task>> {
+    // Trash methods
+
+    pub async fn find_trashed(&self, id: Uuid) -> Result<Option<Task>> {
+        let task = sqlx::query_as::<_, Task>(
+            "SELECT * FROM tasks WHERE id = $1 AND deleted_at IS NOT NULL"
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(task)
+    }
+
+    /// Takes a task out of the trash along with the subtasks that were trashed with it.
+    pub async fn restore(&self, id: Uuid) -> Result<Option<Task>> {
+        let task = sqlx::query_as::<_, Task>(
+            "WITH RECURSIVE tree AS (
+                SELECT id, deleted_at FROM tasks WHERE id = $1 AND deleted_at IS NOT NULL
+                UNION ALL
+                SELECT t.id, t.deleted_at FROM tasks t JOIN tree ON t.parent_task_id = tree.id
+                WHERE t.deleted_at = tree.deleted_at
+             ),
+             restored AS (
+                UPDATE tasks SET deleted_at = NULL, deleted_by = NULL, updated_at = NOW()
+                WHERE id IN (SELECT id FROM tree)
+                RETURNING *
+             )
+             SELECT * FROM restored WHERE id = $1"
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(task)
+    }
+
+    /// The user's trashed tasks, newest first. Subtasks trashed along with their
+    /// parent are left out; they come back when the parent is restored.
+    pub async fn find_trash(
+        &self,
+        user_id: Uuid,
+        retention_days: i32,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<TrashedTask>, i64)> {
+        const TRASH_ROOTS: &str =
+            "FROM tasks t
+             WHERE t.user_id = $1 AND t.deleted_at IS NOT NULL
+             AND NOT EXISTS (
+                SELECT 1 FROM tasks p WHERE p.id = t.parent_task_id AND p.deleted_at = t.deleted_at
+             )";
+
+        let tasks = sqlx::query_as::<_, TrashedTask>(&format!(
+            "SELECT t.*, t.deleted_at + make_interval(days => $2) AS purge_at
+             {}
+             ORDER BY t.deleted_at DESC
+             LIMIT $3 OFFSET $4",
+            TRASH_ROOTS
+        ))
+        .bind(user_id)
+        .bind(retention_days)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let total: i64 = sqlx::query_scalar(&format!("SELECT COUNT(*) {}", TRASH_ROOTS))
+            .bind(user_id)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok((tasks, total))
+    }
+
+    /// Trashed tasks whose retention period has run out.
+    pub async fn find_expired_trash(&self, retention_days: i32) -> Result<Vec<Uuid>> {
+        let ids: Vec<Uuid> = sqlx::query_scalar(
+            "SELECT id FROM tasks WHERE deleted_at < NOW() - make_interval(days => $1)"
+        )
+        .bind(retention_days)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(ids)
+    }
+
+    /// Permanently deletes tasks and their subtasks. Returns how many rows went
+    /// and the storage keys of the attachments that went with them.
+    pub async fn purge(&self, ids: &[Uuid]) -> Result<(i64, Vec<String>)> {
+        let purged: (i64, Vec<String>) = sqlx::query_as(PURGE_TASK_TREES)
+            .bind(ids)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(purged)
+    }
+
+    /// With an `expected_version`, only updates the task if it is still at that version.
+    pub async fn update_status(&self, id: Uuid, status: TaskStatus, expected_version: Option<i32>) -> Result<Option<Task>> {
         let task = sqlx::query_as::<_, Task>(
             "UPDATE tasks SET status = $1, updated_at = NOW()
-             WHERE id = $2 AND user_id = $3
+             WHERE id = $2
+             AND ($3::int IS NULL OR row_version = $3)
              RETURNING *"
         )
         .bind(status)
         .bind(id)
-        .bind(user_id)
+        .bind(expected_version)
         .fetch_optional(&self.pool)
         .await?;
 
@@ -284,7 +507,8 @@ impl TaskRepository {
             "SELECT * FROM tasks 
              WHERE reminder_time <= $1 
              AND notified = false 
-             AND reminder_time IS NOT NULL"
+             AND reminder_time IS NOT NULL
+             AND deleted_at IS NULL"
         )
         .bind(now)
         .fetch_all(&self.pool)
@@ -303,62 +527,62 @@ impl TaskRepository {
     }
 
     pub async fn get_user_stats(&self, user_id: Uuid) -> Result<(i64, i64, i64, i64, i64, i64, i64, i64, i64)> {
-        let total_tasks: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM tasks WHERE user_id = $1")
+        let total_tasks: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM tasks WHERE user_id = $1 AND deleted_at IS NULL")
             .bind(user_id)
             .fetch_one(&self.pool)
             .await?;
 
         let pending_tasks: i64 = sqlx::query_scalar(
-            "SELECT COUNT(*) FROM tasks WHERE user_id = $1 AND status = 'Pending'"
+            "SELECT COUNT(*) FROM tasks WHERE user_id = $1 AND status = 'Pending' AND deleted_at IS NULL"
         )
         .bind(user_id)
         .fetch_one(&self.pool)
         .await?;
 
         let in_progress_tasks: i64 = sqlx::query_scalar(
-            "SELECT COUNT(*) FROM tasks WHERE user_id = $1 AND status = 'InProgress'"
+            "SELECT COUNT(*) FROM tasks WHERE user_id = $1 AND status = 'InProgress' AND deleted_at IS NULL"
         )
         .bind(user_id)
         .fetch_one(&self.pool)
         .await?;
 
         let completed_tasks: i64 = sqlx::query_scalar(
-            "SELECT COUNT(*) FROM tasks WHERE user_id = $1 AND status = 'Completed'"
+            "SELECT COUNT(*) FROM tasks WHERE user_id = $1 AND status = 'Completed' AND deleted_at IS NULL"
         )
         .bind(user_id)
         .fetch_one(&self.pool)
         .await?;
 
         let archived_tasks: i64 = sqlx::query_scalar(
-            "SELECT COUNT(*) FROM tasks WHERE user_id = $1 AND status = 'Archived'"
+            "SELECT COUNT(*) FROM tasks WHERE user_id = $1 AND status = 'Archived' AND deleted_at IS NULL"
         )
         .bind(user_id)
         .fetch_one(&self.pool)
         .await?;
 
         let low_priority_tasks: i64 = sqlx::query_scalar(
-            "SELECT COUNT(*) FROM tasks WHERE user_id = $1 AND priority = 'Low'"
+            "SELECT COUNT(*) FROM tasks WHERE user_id = $1 AND priority = 'Low' AND deleted_at IS NULL"
         )
         .bind(user_id)
         .fetch_one(&self.pool)
         .await?;
 
         let medium_priority_tasks: i64 = sqlx::query_scalar(
-            "SELECT COUNT(*) FROM tasks WHERE user_id = $1 AND priority = 'Medium'"
+            "SELECT COUNT(*) FROM tasks WHERE user_id = $1 AND priority = 'Medium' AND deleted_at IS NULL"
         )
         .bind(user_id)
         .fetch_one(&self.pool)
         .await?;
 
         let high_priority_tasks: i64 = sqlx::query_scalar(
-            "SELECT COUNT(*) FROM tasks WHERE user_id = $1 AND priority = 'High'"
+            "SELECT COUNT(*) FROM tasks WHERE user_id = $1 AND priority = 'High' AND deleted_at IS NULL"
         )
         .bind(user_id)
         .fetch_one(&self.pool)
         .await?;
 
         let urgent_priority_tasks: i64 = sqlx::query_scalar(
-            "SELECT COUNT(*) FROM tasks WHERE user_id = $1 AND priority = 'Urgent'"
+            "SELECT COUNT(*) FROM tasks WHERE user_id = $1 AND priority = 'Urgent' AND deleted_at IS NULL"
         )
         .bind(user_id)
         .fetch_one(&self.pool)
@@ -378,14 +602,15 @@ impl TaskRepository {
     }
 
     // Collaborative task methods
+    /// Returns false, leaving the row alone, if the user is already a member.
     pub async fn add_task_member(
         &self,
         task_id: Uuid,
         user_id: Uuid,
-        role: &str,
+        role: TaskRole,
         added_by: Uuid,
-    ) -> Result<()> {
-        sqlx::query(
+    ) -> Result<bool> {
+        let result = sqlx::query(
             "INSERT INTO task_members (task_id, user_id, role, added_by)
              VALUES ($1, $2, $3, $4)
              ON CONFLICT (task_id, user_id) DO NOTHING"
@@ -397,7 +622,7 @@ impl TaskRepository {
         .execute(&self.pool)
         .await?;
 
-        Ok(())
+        Ok(result.rows_affected() > 0)
     }
 
     pub async fn remove_task_member(&self, task_id: Uuid, user_id: Uuid) -> Result<()> {
@@ -410,6 +635,30 @@ impl TaskRepository {
         Ok(())
     }
 
+    /// The role the user was given directly on this task, if they are a member of it.
+    pub async fn get_member_role(&self, task_id: Uuid, user_id: Uuid) -> Result<Option<TaskRole>> {
+        let role = sqlx::query_scalar::<_, TaskRole>(
+            "SELECT role::text FROM task_members WHERE task_id = $1 AND user_id = $2"
+        )
+        .bind(task_id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(role)
+    }
+
+    pub async fn update_member_role(&self, task_id: Uuid, user_id: Uuid, role: TaskRole) -> Result<u64> {
+        let result = sqlx::query("UPDATE task_members SET role = $3 WHERE task_id = $1 AND user_id = $2")
+            .bind(task_id)
+            .bind(user_id)
+            .bind(role)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
     pub async fn get_task_members(&self, task_id: Uuid) -> Result<Vec<super::task_models::TaskMemberInfo>> {
         let members = sqlx::query_as::<_, super::task_models::TaskMemberInfo>(
             "SELECT tm.user_id, u.username, u.avatar_url, tm.role, tm.added_at
@@ -425,16 +674,97 @@ impl TaskRepository {
         Ok(members)
     }
 
+    // Assignee methods
+
+    /// Assigns users to a task. Returns the ones that weren't assigned already.
+    pub async fn add_assignees(&self, task_id: Uuid, user_ids: &[Uuid], assigned_by: Uuid) -> Result<Vec<Uuid>> {
+        let added = sqlx::query_scalar::<_, Uuid>(
+            "INSERT INTO task_assignees (task_id, user_id, assigned_by)
+             SELECT $1, user_id, $3 FROM UNNEST($2::uuid[]) AS user_id
+             ON CONFLICT (task_id, user_id) DO NOTHING
+             RETURNING user_id"
+        )
+        .bind(task_id)
+        .bind(user_ids)
+        .bind(assigned_by)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(added)
+    }
+
+    pub async fn remove_assignee(&self, task_id: Uuid, user_id: Uuid) -> Result<u64> {
+        let result = sqlx::query("DELETE FROM task_assignees WHERE task_id = $1 AND user_id = $2")
+            .bind(task_id)
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    pub async fn get_assignees(&self, task_id: Uuid) -> Result<Vec<TaskAssignee>> {
+        let assignees = sqlx::query_as::<_, TaskAssignee>(
+            "SELECT ta.user_id, u.username, u.avatar_url, ta.assigned_by, ta.assigned_at
+             FROM task_assignees ta
+             JOIN users u ON u.id = ta.user_id
+             WHERE ta.task_id = $1
+             ORDER BY ta.assigned_at ASC"
+        )
+        .bind(task_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(assignees)
+    }
+
+    /// Drops a user's assignments on a task and its subtasks that they can no
+    /// longer edit, e.g. after being removed from the task or made a viewer.
+    pub async fn remove_uneditable_assignments(&self, task_id: Uuid, user_id: Uuid) -> Result<u64> {
+        self.remove_uneditable_assignments_under("id = $1", task_id, user_id).await
+    }
+
+    /// Same as `remove_uneditable_assignments`, for every task of a project
+    /// the user has left.
+    pub async fn remove_uneditable_project_assignments(&self, project_id: Uuid, user_id: Uuid) -> Result<u64> {
+        self.remove_uneditable_assignments_under("project_id = $1", project_id, user_id).await
+    }
+
+    async fn remove_uneditable_assignments_under(&self, roots: &str, root_id: Uuid, user_id: Uuid) -> Result<u64> {
+        let query = format!(
+            "WITH RECURSIVE tree AS (
+                SELECT id FROM tasks WHERE {roots} AND deleted_at IS NULL
+                UNION
+                SELECT t.id FROM tasks t JOIN tree ON t.parent_task_id = tree.id
+                WHERE t.deleted_at IS NULL
+             )
+             DELETE FROM task_assignees ta
+             WHERE ta.task_id IN (SELECT id FROM tree)
+             AND ta.user_id = $2
+             AND COALESCE(user_task_role(ta.task_id, $2), 'viewer') = 'viewer'"
+        );
+        let result = sqlx::query(&query)
+            .bind(root_id)
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
     pub async fn get_user_tasks_including_shared(&self, user_id: Uuid, filters: TaskFilters) -> Result<(Vec<Task>, i64)> {
-        let mut query = "SELECT DISTINCT t.* FROM tasks t 
-                         LEFT JOIN task_members tm ON t.id = tm.task_id
-                         WHERE (t.user_id = $1 OR tm.user_id = $1)".to_string();
-        
-        let mut count_query = "SELECT COUNT(DISTINCT t.id) FROM tasks t
-                               LEFT JOIN task_members tm ON t.id = tm.task_id
-                               WHERE (t.user_id = $1 OR tm.user_id = $1)".to_string();
+        let mut query = format!("SELECT DISTINCT t.* {}", ACCESSIBLE_TASKS);
+        let mut count_query = format!("SELECT COUNT(DISTINCT t.id) {}", ACCESSIBLE_TASKS);
         
         let mut params_count: usize = 1;
+        let search = filters.search.as_deref().and_then(to_prefix_tsquery);
+
+        // Only top-level tasks unless subtasks are explicitly requested
+        if !filters.include_subtasks {
+            let filter = " AND t.parent_task_id IS NULL";
+            query.push_str(filter);
+            count_query.push_str(filter);
+        }
 
         // Status filters
         if let Some(ref statuses) = filters.statuses {
@@ -469,9 +799,9 @@ impl TaskRepository {
         }
 
         // Search filter
-        if let Some(ref _search) = filters.search {
+        if search.is_some() {
             params_count += 1;
-            let filter = format!(" AND (t.title ILIKE ${} OR t.description ILIKE ${})", params_count, params_count);
+            let filter = format!(" AND t.search_vector @@ to_tsquery('english', ${})", params_count);
             query.push_str(&filter);
             count_query.push_str(&filter);
         }
@@ -502,6 +832,44 @@ impl TaskRepository {
             count_query.push_str(&filter);
         }
 
+        // Project filter
+        if let Some(ref _project_id) = filters.project_id {
+            params_count += 1;
+            let filter = format!(" AND t.project_id = ${}", params_count);
+            query.push_str(&filter);
+            count_query.push_str(&filter);
+        }
+
+        // Label filter
+        if let Some(ref _labels) = filters.labels {
+            params_count += 1;
+            let filter = if filters.labels_match_all {
+                format!(
+                    " AND (SELECT COUNT(DISTINCT tl.label_id) FROM task_labels tl
+                           WHERE tl.task_id = t.id AND tl.label_id = ANY(${0})) = cardinality(${0}::uuid[])",
+                    params_count
+                )
+            } else {
+                format!(
+                    " AND EXISTS (SELECT 1 FROM task_labels tl WHERE tl.task_id = t.id AND tl.label_id = ANY(${}))",
+                    params_count
+                )
+            };
+            query.push_str(&filter);
+            count_query.push_str(&filter);
+        }
+
+        // Assignee filter
+        if let Some(ref _assignee_id) = filters.assignee_id {
+            params_count += 1;
+            let filter = format!(
+                " AND EXISTS (SELECT 1 FROM task_assignees ta WHERE ta.task_id = t.id AND ta.user_id = ${})",
+                params_count
+            );
+            query.push_str(&filter);
+            count_query.push_str(&filter);
+        }
+
         // Calculate total count
         let mut count_db_query = sqlx::query_scalar::<_, i64>(&count_query).bind(user_id);
         
@@ -518,14 +886,17 @@ impl TaskRepository {
             count_db_query = count_db_query.bind(priority);
         }
 
-        if let Some(search) = &filters.search {
-            count_db_query = count_db_query.bind(format!("%{}%", search));
+        if let Some(search) = &search {
+            count_db_query = count_db_query.bind(search);
         }
 
         if let Some(from) = filters.created_from { count_db_query = count_db_query.bind(from); }
         if let Some(to) = filters.created_to { count_db_query = count_db_query.bind(to); }
         if let Some(from) = filters.due_from { count_db_query = count_db_query.bind(from); }
         if let Some(to) = filters.due_to { count_db_query = count_db_query.bind(to); }
+        if let Some(project_id) = filters.project_id { count_db_query = count_db_query.bind(project_id); }
+        if let Some(labels) = &filters.labels { count_db_query = count_db_query.bind(labels); }
+        if let Some(assignee_id) = filters.assignee_id { count_db_query = count_db_query.bind(assignee_id); }
 
         let total_count = count_db_query.fetch_one(&self.pool).await?;
 
@@ -540,13 +911,22 @@ impl TaskRepository {
             Some("asc") => "ASC",
             _ => "DESC",
         };
-        query.push_str(&format!(" ORDER BY {} {}", sort_column, sort_direction));
 
-        // Pagination
-        let page = filters.page.unwrap_or(1);
+        // Pagination: keyset after a cursor (one extra row tells whether more
+        // follow), otherwise by page
         let limit = filters.limit.unwrap_or(10);
-        let offset = (page - 1) * limit;
-        query.push_str(&format!(" LIMIT {} OFFSET {}", limit, offset));
+        if filters.cursor.is_some() {
+            let (keyset, order) = keyset_sql("t", filters.cursor.as_ref(), sort_direction == "DESC", params_count + 1);
+            query.push_str(&keyset);
+            query.push_str(&order);
+            query.push_str(&format!(" LIMIT {}", limit + 1));
+        } else {
+            query.push_str(&format!(" ORDER BY {} {}, t.id {}", sort_column, sort_direction, sort_direction));
+
+            let page = filters.page.unwrap_or(1);
+            let offset = (page - 1) * limit;
+            query.push_str(&format!(" LIMIT {} OFFSET {}", limit, offset));
+        }
 
         // Create main query
         let mut db_query = sqlx::query_as::<_, Task>(&query).bind(user_id);
@@ -564,14 +944,21 @@ impl TaskRepository {
             db_query = db_query.bind(priority);
         }
 
-        if let Some(search) = filters.search {
-            db_query = db_query.bind(format!("%{}%", search));
+        if let Some(search) = search {
+            db_query = db_query.bind(search);
         }
 
         if let Some(from) = filters.created_from { db_query = db_query.bind(from); }
         if let Some(to) = filters.created_to { db_query = db_query.bind(to); }
         if let Some(from) = filters.due_from { db_query = db_query.bind(from); }
         if let Some(to) = filters.due_to { db_query = db_query.bind(to); }
+        if let Some(project_id) = filters.project_id { db_query = db_query.bind(project_id); }
+        if let Some(labels) = filters.labels { db_query = db_query.bind(labels); }
+        if let Some(assignee_id) = filters.assignee_id { db_query = db_query.bind(assignee_id); }
+        if let Some(position) = filters.cursor {
+            let cursor = position.cursor();
+            db_query = db_query.bind(cursor.created_at).bind(cursor.id);
+        }
 
         let tasks = db_query.fetch_all(&self.pool).await?;
         Ok((tasks, total_count))
@@ -592,7 +979,7 @@ impl TaskRepository {
 
     pub async fn is_task_owner(&self, task_id: Uuid, user_id: Uuid) -> Result<bool> {
         let count: i64 = sqlx::query_scalar(
-            "SELECT COUNT(*) FROM tasks WHERE id = $1 AND user_id = $2"
+            "SELECT COUNT(*) FROM tasks WHERE id = $1 AND user_id = $2 AND deleted_at IS NULL"
         )
         .bind(task_id)
         .bind(user_id)
@@ -602,24 +989,58 @@ impl TaskRepository {
         Ok(count > 0)
     }
 
-    pub async fn has_task_access(&self, task_id: Uuid, user_id: Uuid) -> Result<bool> {
-        let count: i64 = sqlx::query_scalar(
-            "SELECT COUNT(*) FROM tasks t
-             LEFT JOIN task_members tm ON t.id = tm.task_id
-             WHERE t.id = $1 AND (t.user_id = $2 OR tm.user_id = $2)"
+    /// The user's effective role on the task, including roles inherited from
+    /// parent tasks and the project. `None` if they have no access.
+    pub async fn get_task_role(&self, task_id: Uuid, user_id: Uuid) -> Result<Option<TaskRole>> {
+        let role: Option<TaskRole> = sqlx::query_scalar("SELECT user_task_role($1, $2)")
+            .bind(task_id)
+            .bind(user_id)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(role)
+    }
+
+    /// Everyone with access to the task: owners, members and project members
+    /// of the task or any of its ancestors.
+    pub async fn get_audience(&self, task_id: Uuid) -> Result<Vec<Uuid>> {
+        let user_ids = sqlx::query_scalar::<_, Uuid>(
+            "WITH RECURSIVE lineage AS (
+                 SELECT id, parent_task_id, user_id, project_id FROM tasks WHERE id = $1 AND deleted_at IS NULL
+                 UNION ALL
+                 SELECT t.id, t.parent_task_id, t.user_id, t.project_id
+                 FROM tasks t
+                 JOIN lineage l ON t.id = l.parent_task_id
+             )
+             SELECT user_id FROM lineage
+             UNION
+             SELECT tm.user_id FROM task_members tm JOIN lineage l ON tm.task_id = l.id
+             UNION
+             SELECT pm.user_id FROM project_members pm JOIN lineage l ON pm.project_id = l.project_id"
         )
         .bind(task_id)
-        .bind(user_id)
-        .fetch_one(&self.pool)
+        .fetch_all(&self.pool)
         .await?;
 
-        Ok(count > 0)
+        Ok(user_ids)
+    }
+
+    pub async fn has_task_access(&self, task_id: Uuid, user_id: Uuid) -> Result<bool> {
+        let has_access: bool = sqlx::query_scalar("SELECT user_has_task_access($1, $2)")
+            .bind(task_id)
+            .bind(user_id)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(has_access)
     }
 
+    /// Records an action in the task's audit log. `user_id` is `None` for
+    /// visitors without an account, e.g. through a share link.
     pub async fn log_task_activity(
         &self,
         task_id: Uuid,
-        user_id: Uuid,
+        user_id: impl Into<Option<Uuid>>,
         action: &str,
         details: Option<serde_json::Value>,
     ) -> Result<()> {
@@ -628,7 +1049,7 @@ impl TaskRepository {
              VALUES ($1, $2, $3, $4)"
         )
         .bind(task_id)
-        .bind(user_id)
+        .bind(user_id.into())
         .bind(action)
         .bind(details)
         .execute(&self.pool)
@@ -654,9 +1075,7 @@ impl TaskRepository {
 
     pub async fn find_by_id_with_access(&self, id: Uuid, user_id: Uuid) -> Result<Option<Task>> {
         let task = sqlx::query_as::<_, Task>(
-            "SELECT DISTINCT t.* FROM tasks t
-             LEFT JOIN task_members tm ON t.id = tm.task_id
-             WHERE t.id = $1 AND (t.user_id = $2 OR tm.user_id = $2)"
+            "SELECT * FROM tasks WHERE id = $1 AND user_has_task_access(id, $2)"
         )
         .bind(id)
         .bind(user_id)
@@ -665,4 +1084,505 @@ impl TaskRepository {
         
         Ok(task)
     }
+
+    // Subtask methods
+    pub async fn find_subtasks(&self, parent_task_id: Uuid) -> Result<Vec<Task>> {
+        let tasks = sqlx::query_as::<_, Task>(
+            "SELECT * FROM tasks WHERE parent_task_id = $1 AND deleted_at IS NULL ORDER BY position ASC, created_at ASC"
+        )
+        .bind(parent_task_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(tasks)
+    }
+
+    pub async fn get_subtask_progress(&self, task_ids: &[Uuid]) -> Result<HashMap<Uuid, SubtaskProgress>> {
+        let rows: Vec<(Uuid, i64, i64)> = sqlx::query_as(
+            "SELECT parent_task_id,
+                    COUNT(*) FILTER (WHERE status = 'Completed'),
+                    COUNT(*)
+             FROM tasks
+             WHERE parent_task_id = ANY($1) AND deleted_at IS NULL
+             GROUP BY parent_task_id"
+        )
+        .bind(task_ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, completed, total)| (id, SubtaskProgress { completed, total }))
+            .collect())
+    }
+
+    /// Rewrites the positions of a parent's children to match `ordered_ids`.
+    pub async fn reorder_subtasks(&self, parent_task_id: Uuid, ordered_ids: &[Uuid]) -> Result<()> {
+        let positions: Vec<i32> = (0..ordered_ids.len() as i32).collect();
+
+        sqlx::query(
+            "UPDATE tasks SET position = ordering.position, updated_at = NOW()
+             FROM UNNEST($1::uuid[], $2::int[]) AS ordering(id, position)
+             WHERE tasks.id = ordering.id AND tasks.parent_task_id = $3"
+        )
+        .bind(ordered_ids)
+        .bind(&positions)
+        .bind(parent_task_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Sets `status` on every open descendant of a task. Returns the ids that changed.
+    pub async fn cascade_status_to_descendants(&self, task_id: Uuid, status: TaskStatus) -> Result<Vec<Uuid>> {
+        let ids: Vec<Uuid> = sqlx::query_scalar(
+            "WITH RECURSIVE descendants AS (
+                SELECT id FROM tasks WHERE parent_task_id = $1
+                UNION ALL
+                SELECT t.id FROM tasks t JOIN descendants d ON t.parent_task_id = d.id
+             )
+             UPDATE tasks SET status = $2, updated_at = NOW()
+             WHERE id IN (SELECT id FROM descendants)
+             AND status NOT IN ('Completed', 'Archived')
+             AND deleted_at IS NULL
+             RETURNING id"
+        )
+        .bind(task_id)
+        .bind(status)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(ids)
+    }
+
+    /// Moves every ancestor of a task whose status is in `from_statuses` back to InProgress.
+    /// Returns the ids that changed.
+    pub async fn reopen_ancestors(&self, task_id: Uuid, from_statuses: &[TaskStatus]) -> Result<Vec<Uuid>> {
+        let from_statuses: Vec<String> = from_statuses.iter().map(TaskStatus::to_string).collect();
+        let ids: Vec<Uuid> = sqlx::query_scalar(
+            "WITH RECURSIVE ancestors AS (
+                SELECT parent_task_id AS id FROM tasks WHERE id = $1 AND parent_task_id IS NOT NULL
+                UNION ALL
+                SELECT t.parent_task_id FROM tasks t
+                JOIN ancestors a ON t.id = a.id
+                WHERE t.parent_task_id IS NOT NULL
+             )
+             UPDATE tasks SET status = 'InProgress', updated_at = NOW()
+             WHERE id IN (SELECT id FROM ancestors)
+             AND status = ANY($2)
+             RETURNING id"
+        )
+        .bind(task_id)
+        .bind(&from_statuses)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(ids)
+    }
+
+    // Dependency methods
+
+    /// Adds "task_id is blocked by blocked_by_task_id" unless it would close a cycle.
+    /// Returns false when the link would create a cycle.
+    pub async fn add_dependency(&self, task_id: Uuid, blocked_by_task_id: Uuid, created_by: Uuid) -> Result<bool> {
+        let mut tx = self.pool.begin().await?;
+
+        // Serialize graph changes so two concurrent inserts can't form a cycle together
+        sqlx::query("SELECT pg_advisory_xact_lock(hashtext('task_dependencies'))")
+            .execute(&mut *tx)
+            .await?;
+
+        // Walk everything the blocker (transitively) waits on; if that includes
+        // task_id, the new edge would close a loop.
+        let creates_cycle: bool = sqlx::query_scalar(
+            "WITH RECURSIVE upstream AS (
+                SELECT blocked_by_task_id AS id FROM task_dependencies WHERE task_id = $1
+                UNION
+                SELECT d.blocked_by_task_id FROM task_dependencies d
+                JOIN upstream u ON d.task_id = u.id
+             )
+             SELECT EXISTS (SELECT 1 FROM upstream WHERE id = $2)"
+        )
+        .bind(blocked_by_task_id)
+        .bind(task_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        if creates_cycle {
+            return Ok(false);
+        }
+
+        sqlx::query(
+            "INSERT INTO task_dependencies (task_id, blocked_by_task_id, created_by)
+             VALUES ($1, $2, $3)
+             ON CONFLICT (task_id, blocked_by_task_id) DO NOTHING"
+        )
+        .bind(task_id)
+        .bind(blocked_by_task_id)
+        .bind(created_by)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(true)
+    }
+
+    pub async fn remove_dependency(&self, task_id: Uuid, blocked_by_task_id: Uuid) -> Result<u64> {
+        let result = sqlx::query(
+            "DELETE FROM task_dependencies WHERE task_id = $1 AND blocked_by_task_id = $2"
+        )
+        .bind(task_id)
+        .bind(blocked_by_task_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Blockers of a task that `user_id` is allowed to see.
+    pub async fn get_blockers(&self, task_id: Uuid, user_id: Uuid) -> Result<Vec<TaskDependencyInfo>> {
+        let blockers = sqlx::query_as::<_, TaskDependencyInfo>(
+            "SELECT t.id AS task_id, t.title, t.status, t.user_id, d.created_at
+             FROM task_dependencies d
+             JOIN tasks t ON t.id = d.blocked_by_task_id
+             WHERE d.task_id = $1 AND user_has_task_access(t.id, $2)
+             ORDER BY d.created_at ASC"
+        )
+        .bind(task_id)
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(blockers)
+    }
+
+    /// Tasks held up by a task that `user_id` is allowed to see.
+    pub async fn get_blocking(&self, task_id: Uuid, user_id: Uuid) -> Result<Vec<TaskDependencyInfo>> {
+        let blocking = sqlx::query_as::<_, TaskDependencyInfo>(
+            "SELECT t.id AS task_id, t.title, t.status, t.user_id, d.created_at
+             FROM task_dependencies d
+             JOIN tasks t ON t.id = d.task_id
+             WHERE d.blocked_by_task_id = $1 AND user_has_task_access(t.id, $2)
+             ORDER BY d.created_at ASC"
+        )
+        .bind(task_id)
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(blocking)
+    }
+
+    /// Titles of the blockers of a task that are not finished yet, regardless of who can see them.
+    pub async fn get_unfinished_blocker_titles(&self, task_id: Uuid) -> Result<Vec<String>> {
+        let titles: Vec<String> = sqlx::query_scalar(
+            "SELECT t.title FROM task_dependencies d
+             JOIN tasks t ON t.id = d.blocked_by_task_id
+             WHERE d.task_id = $1 AND t.status NOT IN ('Completed', 'Archived')
+             AND t.deleted_at IS NULL
+             ORDER BY t.title"
+        )
+        .bind(task_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(titles)
+    }
+
+    /// Open tasks blocked by any of `blocker_ids` whose blockers are now all finished.
+    pub async fn find_unblocked_dependents(&self, blocker_ids: &[Uuid]) -> Result<Vec<Task>> {
+        let tasks = sqlx::query_as::<_, Task>(
+            "SELECT DISTINCT t.* FROM task_dependencies d
+             JOIN tasks t ON t.id = d.task_id
+             WHERE d.blocked_by_task_id = ANY($1)
+             AND t.status NOT IN ('Completed', 'Archived')
+             AND t.deleted_at IS NULL
+             AND NOT EXISTS (
+                SELECT 1 FROM task_dependencies other
+                JOIN tasks b ON b.id = other.blocked_by_task_id
+                WHERE other.task_id = t.id AND b.status NOT IN ('Completed', 'Archived')
+                AND b.deleted_at IS NULL
+             )"
+        )
+        .bind(blocker_ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(tasks)
+    }
+
+    // Recurrence methods
+
+    /// Sets the repeat schedule of a task, starting a new series if it isn't part of one.
+    pub async fn set_recurrence(&self, id: Uuid, rule: &RecurrenceRule) -> Result<Task> {
+        let task = sqlx::query_as::<_, Task>(
+            "UPDATE tasks SET
+                recurrence_rule = $1,
+                recurrence_series_id = COALESCE(recurrence_series_id, id),
+                recurrence_index = COALESCE(recurrence_index, 1),
+                updated_at = NOW()
+             WHERE id = $2
+             RETURNING *"
+        )
+        .bind(sqlx::types::Json(rule))
+        .bind(id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(task)
+    }
+
+    /// Copies `previous` into occurrence number `index` of its series, along with its members.
+    /// Returns `None` if that occurrence (or a later one) already exists.
+    pub async fn create_occurrence(
+        &self,
+        previous_id: Uuid,
+        index: i32,
+        due_date: DateTime<Utc>,
+        reminder_time: Option<DateTime<Utc>>,
+    ) -> Result<Option<Task>> {
+        let mut tx = self.pool.begin().await?;
+
+        let task = sqlx::query_as::<_, Task>(
+            "INSERT INTO tasks (user_id, title, description, priority, due_date, reminder_time, estimate_minutes, project_id,
+                                parent_task_id, position, recurrence_rule, recurrence_series_id, recurrence_index)
+             SELECT p.user_id, p.title, p.description, p.priority, $2, $3, p.estimate_minutes, p.project_id,
+                    p.parent_task_id,
+                    CASE WHEN p.parent_task_id IS NULL THEN 0
+                         ELSE COALESCE((SELECT MAX(position) + 1 FROM tasks WHERE parent_task_id = p.parent_task_id), 0)
+                    END,
+                    p.recurrence_rule, p.recurrence_series_id, $4
+             FROM tasks p
+             WHERE p.id = $1
+             -- Only the latest occurrence of a series spawns the next one
+             AND NOT EXISTS (
+                SELECT 1 FROM tasks o
+                WHERE o.recurrence_series_id = p.recurrence_series_id AND o.recurrence_index >= $4
+             )
+             ON CONFLICT (recurrence_series_id, recurrence_index) DO NOTHING
+             RETURNING *"
+        )
+        .bind(previous_id)
+        .bind(due_date)
+        .bind(reminder_time)
+        .bind(index)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        if let Some(task) = &task {
+            sqlx::query(
+                "INSERT INTO task_members (task_id, user_id, role, added_by)
+                 SELECT $1, user_id, role, added_by FROM task_members WHERE task_id = $2
+                 ON CONFLICT (task_id, user_id) DO NOTHING"
+            )
+            .bind(task.id)
+            .bind(previous_id)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(task)
+    }
+
+    /// Latest occurrence of every recurring series that is still active.
+    pub async fn find_recurring_series_heads(&self) -> Result<Vec<Task>> {
+        let tasks = sqlx::query_as::<_, Task>(
+            "SELECT * FROM (
+                SELECT DISTINCT ON (recurrence_series_id) * FROM tasks
+                WHERE recurrence_series_id IS NOT NULL
+                ORDER BY recurrence_series_id, recurrence_index DESC
+             ) latest
+             WHERE recurrence_rule IS NOT NULL
+             AND due_date IS NOT NULL
+             AND status <> 'Archived'
+             AND deleted_at IS NULL"
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(tasks)
+    }
+
+    // Version methods
+
+    /// Records the current state of each task in `ids` as its next version.
+    /// Tasks whose state matches their latest version are skipped, so callers
+    /// can record after any write without creating empty versions.
+    pub async fn record_versions(&self, ids: &[Uuid], changed_by: Option<Uuid>, action: &str) -> Result<u64> {
+        insert_versions(&self.pool, ids, changed_by, action).await
+    }
+
+    /// All versions of a task, newest first.
+    pub async fn find_versions(&self, task_id: Uuid) -> Result<Vec<TaskVersion>> {
+        let versions = sqlx::query_as::<_, TaskVersion>(
+            "SELECT v.task_id, v.version, v.snapshot, v.action, v.changed_by,
+                    u.username AS changed_by_username, v.created_at
+             FROM task_versions v
+             LEFT JOIN users u ON u.id = v.changed_by
+             WHERE v.task_id = $1
+             ORDER BY v.version DESC"
+        )
+        .bind(task_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(versions)
+    }
+
+    /// A version of a task followed by the one before it, if any.
+    /// Empty if the task has no such version.
+    pub async fn find_version(&self, task_id: Uuid, version: i32) -> Result<Vec<TaskVersion>> {
+        let versions = sqlx::query_as::<_, TaskVersion>(
+            "SELECT v.task_id, v.version, v.snapshot, v.action, v.changed_by,
+                    u.username AS changed_by_username, v.created_at
+             FROM task_versions v
+             LEFT JOIN users u ON u.id = v.changed_by
+             WHERE v.task_id = $1
+             AND v.version <= $2
+             ORDER BY v.version DESC
+             LIMIT 2"
+        )
+        .bind(task_id)
+        .bind(version)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(versions.into_iter().skip_while(|v| v.version != version).collect())
+    }
+
+    /// Overwrites the editable fields of a task with a snapshot, clearing
+    /// the fields the snapshot leaves empty.
+    pub async fn apply_snapshot(&self, id: Uuid, snapshot: &TaskSnapshot) -> Result<Task> {
+        let task = sqlx::query_as::<_, Task>(
+            "UPDATE tasks SET
+                title = $2,
+                description = $3,
+                status = $4,
+                priority = $5,
+                due_date = $6,
+                notified = CASE WHEN reminder_time IS DISTINCT FROM $7 THEN false ELSE notified END,
+                reminder_time = $7,
+                estimate_minutes = $8,
+                recurrence_rule = $9,
+                recurrence_series_id = CASE WHEN $9 IS NULL THEN recurrence_series_id
+                                            ELSE COALESCE(recurrence_series_id, id) END,
+                recurrence_index = CASE WHEN $9 IS NULL THEN recurrence_index
+                                        ELSE COALESCE(recurrence_index, 1) END,
+                updated_at = NOW()
+             WHERE id = $1
+             RETURNING *"
+        )
+        .bind(id)
+        .bind(&snapshot.title)
+        .bind(&snapshot.description)
+        .bind(snapshot.status)
+        .bind(snapshot.priority)
+        .bind(snapshot.due_date)
+        .bind(snapshot.reminder_time)
+        .bind(snapshot.estimate_minutes)
+        .bind(snapshot.recurrence_rule.as_ref().map(sqlx::types::Json))
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(task)
+    }
+
+    // Bulk methods
+
+    /// Applies `operations`, in order, to every task in `ids` in a single
+    /// transaction, logging one `action` activity entry and at most one new
+    /// version per task. Returns the tasks afterwards.
+    pub async fn bulk_apply(
+        &self,
+        ids: &[Uuid],
+        operations: &[BulkTaskOperation],
+        user_id: Uuid,
+        action: &str,
+    ) -> Result<Vec<Task>> {
+        let mut tx = self.pool.begin().await?;
+
+        for operation in operations {
+            match operation {
+                BulkTaskOperation::SetStatus { .. } | BulkTaskOperation::Archive => {
+                    sqlx::query("UPDATE tasks SET status = $2, updated_at = NOW() WHERE id = ANY($1)")
+                        .bind(ids)
+                        .bind(operation.new_status())
+                        .execute(&mut *tx)
+                        .await?;
+                }
+                BulkTaskOperation::SetPriority { priority } => {
+                    sqlx::query("UPDATE tasks SET priority = $2, updated_at = NOW() WHERE id = ANY($1)")
+                        .bind(ids)
+                        .bind(*priority)
+                        .execute(&mut *tx)
+                        .await?;
+                }
+                BulkTaskOperation::ShiftDueDate { minutes } => {
+                    sqlx::query(
+                        "UPDATE tasks SET
+                            due_date = due_date + make_interval(mins => $2),
+                            reminder_time = reminder_time + make_interval(mins => $2),
+                            notified = CASE WHEN reminder_time IS NOT NULL THEN false ELSE notified END,
+                            updated_at = NOW()
+                         WHERE id = ANY($1) AND due_date IS NOT NULL"
+                    )
+                    .bind(ids)
+                    .bind(minutes)
+                    .execute(&mut *tx)
+                    .await?;
+                }
+                BulkTaskOperation::AddLabel { label_id } => {
+                    sqlx::query(
+                        "INSERT INTO task_labels (task_id, label_id, added_by)
+                         SELECT task_id, $2, $3 FROM UNNEST($1::uuid[]) AS task_id
+                         ON CONFLICT (task_id, label_id) DO NOTHING"
+                    )
+                    .bind(ids)
+                    .bind(label_id)
+                    .bind(user_id)
+                    .execute(&mut *tx)
+                    .await?;
+                }
+                BulkTaskOperation::RemoveLabel { label_id } => {
+                    sqlx::query("DELETE FROM task_labels WHERE task_id = ANY($1) AND label_id = $2")
+                        .bind(ids)
+                        .bind(label_id)
+                        .execute(&mut *tx)
+                        .await?;
+                }
+                BulkTaskOperation::Delete => {
+                    for &id in ids {
+                        sqlx::query(SOFT_DELETE_TASK_TREE)
+                            .bind(id)
+                            .bind(user_id)
+                            .execute(&mut *tx)
+                            .await?;
+                    }
+                }
+            }
+        }
+
+        insert_versions(&mut *tx, ids, Some(user_id), action).await?;
+
+        sqlx::query(
+            "INSERT INTO task_activity (task_id, user_id, action, details)
+             SELECT task_id, $2, $3, $4 FROM UNNEST($1::uuid[]) AS task_id"
+        )
+        .bind(ids)
+        .bind(user_id)
+        .bind(action)
+        .bind(serde_json::json!({"operations": operations}))
+        .execute(&mut *tx)
+        .await?;
+
+        let tasks = sqlx::query_as::<_, Task>("SELECT * FROM tasks WHERE id = ANY($1)")
+            .bind(ids)
+            .fetch_all(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+        Ok(tasks)
+    }
 }
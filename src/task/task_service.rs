@@ -1,24 +1,66 @@
 // src/task/task.service.rs
 use crate::error::Result;
 use crate::task::task_repository::TaskRepository;
-use crate::task::task_models::Task;
+use crate::task::task_events::TaskEvents;
+use crate::task::task_models::{
+    diff_snapshots, BulkTaskOperation, BulkTaskResponse, BulkTaskResult, RecurrenceRule, Task, TaskAssignee,
+    TaskDetail, TaskEventKind, TaskListItem, TaskPermission, TaskRole, TaskSnapshot, TaskStatus, TaskVersionDiff,
+    TrashedTask,
+};
 use crate::task::task_dto::{CreateTaskRequest, UpdateTaskRequest, UpdateTaskStatusRequest};
 use crate::notification::NotificationHelper;
+use crate::checklist::checklist_repository::ChecklistRepository;
+use crate::label::label_repository::LabelRepository;
+use crate::project::project_repository::ProjectRepository;
+use crate::user::user_repository::UserRepository;
+use crate::websocket::types::{TaskAssignmentPayload, WsMessage};
+use crate::websocket::ConnectionManager;
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
 use uuid::Uuid;
 
+/// Most tasks a single bulk request can change
+pub const MAX_BULK_TASKS: u32 = 200;
+
 
 /// Service layer for task‑related business logic.
 #[derive(Clone)]
 pub struct TaskService {
     repo: TaskRepository,
+    label_repo: LabelRepository,
+    checklist_repo: ChecklistRepository,
+    project_repo: ProjectRepository,
+    user_repo: UserRepository,
+    ws_manager: ConnectionManager,
     notification_helper: NotificationHelper,
+    events: TaskEvents,
+    /// Days a deleted task stays in the trash before it is purged
+    trash_retention_days: i32,
 }
 
 impl TaskService {
-    pub fn new(repo: TaskRepository, notification_helper: NotificationHelper) -> Self {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        repo: TaskRepository,
+        label_repo: LabelRepository,
+        checklist_repo: ChecklistRepository,
+        project_repo: ProjectRepository,
+        user_repo: UserRepository,
+        ws_manager: ConnectionManager,
+        notification_helper: NotificationHelper,
+        events: TaskEvents,
+        trash_retention_days: i32,
+    ) -> Self {
         Self { 
             repo,
+            label_repo,
+            checklist_repo,
+            project_repo,
+            user_repo,
+            ws_manager,
             notification_helper,
+            events,
+            trash_retention_days,
         }
     }
 
@@ -26,9 +68,29 @@ impl TaskService {
         &self,
         user_id: Uuid,
         filters: crate::task::task_repository::TaskFilters,
-    ) -> Result<(Vec<Task>, i64)> {
+    ) -> Result<(Vec<TaskListItem>, i64)> {
         // Use the method that includes shared tasks
-        self.repo.get_user_tasks_including_shared(user_id, filters).await
+        let (tasks, total) = self.repo.get_user_tasks_including_shared(user_id, filters).await?;
+        let items = self.to_list_items(tasks).await?;
+        Ok((items, total))
+    }
+
+    /// Attach subtask and checklist roll-up progress and labels to a page of tasks.
+    pub async fn to_list_items(&self, tasks: Vec<Task>) -> Result<Vec<TaskListItem>> {
+        let ids: Vec<Uuid> = tasks.iter().map(|t| t.id).collect();
+        let progress = self.repo.get_subtask_progress(&ids).await?;
+        let checklists = self.checklist_repo.get_progress(&ids).await?;
+        let mut labels = self.label_repo.find_for_tasks(&ids).await?;
+
+        Ok(tasks
+            .into_iter()
+            .map(|task| TaskListItem {
+                subtask_progress: progress.get(&task.id).copied().unwrap_or_default(),
+                checklist_progress: checklists.get(&task.id).copied().unwrap_or_default(),
+                labels: labels.remove(&task.id).unwrap_or_default(),
+                task,
+            })
+            .collect())
     }
 
     pub async fn get_task(&self, user_id: Uuid, task_id: Uuid) -> Result<Task> {
@@ -38,23 +100,78 @@ impl TaskService {
             .ok_or_else(|| crate::error::AppError::NotFound("Task not found".into()))
     }
 
+    /// Task plus its labels and the dependency links the user is allowed to see.
+    pub async fn get_task_detail(&self, user_id: Uuid, task_id: Uuid) -> Result<TaskDetail> {
+        let task = self.get_task(user_id, task_id).await?;
+        let blocked_by = self.repo.get_blockers(task.id, user_id).await?;
+        let blocking = self.repo.get_blocking(task.id, user_id).await?;
+        let labels = self.label_repo
+            .find_for_tasks(&[task.id])
+            .await?
+            .remove(&task.id)
+            .unwrap_or_default();
+        let assignees = self.repo.get_assignees(task.id).await?;
+
+        Ok(TaskDetail {
+            task,
+            blocked_by,
+            blocking,
+            labels,
+            assignees,
+        })
+    }
+
     pub async fn create_task(
         &self,
         user_id: Uuid,
         payload: CreateTaskRequest,
     ) -> Result<Task> {
-        let priority = payload.priority.unwrap_or_else(|| "Medium".to_string());
+        self.insert_task(user_id, payload, None).await
+    }
+
+    async fn insert_task(
+        &self,
+        user_id: Uuid,
+        payload: CreateTaskRequest,
+        parent_task_id: Option<Uuid>,
+    ) -> Result<Task> {
+        if let Some(rule) = &payload.recurrence_rule {
+            Self::check_recurrence(rule, payload.due_date)?;
+        }
+        if let Some(project_id) = payload.project_id {
+            if parent_task_id.is_some() {
+                return Err(crate::error::AppError::BadRequest(
+                    "Subtasks belong to their parent's project".to_string(),
+                ));
+            }
+            if !self.project_repo.is_member(project_id, user_id).await? {
+                return Err(crate::error::AppError::NotFound("Project not found".to_string()));
+            }
+        }
+
         let task = self.repo
             .create(
                 user_id,
                 &payload.title,
                 payload.description.as_deref(),
-                &priority,
+                payload.priority.unwrap_or_default(),
                 payload.due_date,
                 payload.reminder_time,
+                payload.estimate_minutes,
+                parent_task_id,
             )
             .await?;
 
+        let task = match (&payload.recurrence_rule, task.due_date) {
+            (Some(rule), Some(due)) => self.repo.set_recurrence(task.id, &rule.anchored_at(due)).await?,
+            _ => task,
+        };
+        let task = match payload.project_id {
+            Some(project_id) => self.project_repo.add_task_to_project(task.id, project_id, task.status).await?,
+            None => task,
+        };
+        self.repo.record_versions(&[task.id], Some(user_id), "created").await?;
+
         // Log activity
         let _ = self.repo.log_task_activity(
             task.id,
@@ -64,13 +181,15 @@ impl TaskService {
         ).await;
 
         // Add creator as owner
-        let _ = self.repo.add_task_member(task.id, user_id, "owner", user_id).await;
+        let _ = self.repo.add_task_member(task.id, user_id, TaskRole::Owner, user_id).await;
 
         // Send notification for task creation
         let _ = self.notification_helper
             .notify_task_created(user_id, &task.title, task.id)
             .await;
 
+        self.events.publish(TaskEventKind::Created, &task, Some(user_id), None).await;
+
         Ok(task)
     }
 
@@ -79,24 +198,41 @@ impl TaskService {
         user_id: Uuid,
         task_id: Uuid,
         payload: UpdateTaskRequest,
+        expected_version: Option<i32>,
     ) -> Result<Task> {
-        // Check access
-        if !self.repo.has_task_access(task_id, user_id).await? {
-            return Err(crate::error::AppError::Forbidden("Access denied".to_string()));
+        self.require_permission(task_id, user_id, TaskPermission::Edit).await?;
+
+        let existing = self.get_task(user_id, task_id).await?;
+        if let Some(status) = payload.status {
+            self.ensure_not_blocked(task_id, status).await?;
+        }
+        if let Some(rule) = &payload.recurrence_rule {
+            Self::check_recurrence(rule, payload.due_date.or(existing.due_date))?;
         }
 
-        let task = self.repo
+        let task = match self.repo
             .update(
                 task_id,
-                user_id,
                 payload.title.as_deref(),
                 payload.description.as_deref(),
-                payload.status.as_deref(),
-                payload.priority.as_deref(),
+                payload.status,
+                payload.priority,
                 payload.due_date,
                 payload.reminder_time,
+                payload.estimate_minutes,
+                expected_version,
             )
-            .await?;
+            .await?
+        {
+            Some(task) => task,
+            None => return Err(self.stale_task_error(user_id, task_id).await),
+        };
+
+        let task = match (&payload.recurrence_rule, task.due_date) {
+            (Some(rule), Some(due)) => self.repo.set_recurrence(task.id, &rule.anchored_at(due)).await?,
+            _ => task,
+        };
+        self.repo.record_versions(&[task_id], Some(user_id), "updated").await?;
 
         // Log activity
         let _ = self.repo.log_task_activity(
@@ -106,6 +242,11 @@ impl TaskService {
             Some(serde_json::json!(payload)),
         ).await;
 
+        let task = match payload.status {
+            Some(status) => self.after_status_change(user_id, task, existing.status, status).await?,
+            None => task,
+        };
+
         // Send notification for task update
         let changes: Vec<String> = vec![
             payload.title.as_ref().map(|_| "title".to_string()),
@@ -113,6 +254,7 @@ impl TaskService {
             payload.status.as_ref().map(|_| "status".to_string()),
             payload.priority.as_ref().map(|_| "priority".to_string()),
             payload.due_date.as_ref().map(|_| "due_date".to_string()),
+            payload.estimate_minutes.as_ref().map(|_| "estimate".to_string()),
         ]
         .into_iter()
         .flatten()
@@ -125,16 +267,78 @@ impl TaskService {
                 .await;
         }
 
+        self.events
+            .publish(TaskEventKind::Updated, &task, Some(user_id), Some(serde_json::json!({"changes": changes})))
+            .await;
+
         Ok(task)
     }
 
+    /// Moves a task and its subtasks to the trash. Only the owner can delete.
     pub async fn delete_task(&self, user_id: Uuid, task_id: Uuid) -> Result<u64> {
-        // Only owner can delete
-        if !self.repo.is_task_owner(task_id, user_id).await? {
-            return Err(crate::error::AppError::Forbidden("Only task owner can delete".to_string()));
+        self.require_permission(task_id, user_id, TaskPermission::Delete).await?;
+
+        let audience = self.events.audience(task_id).await;
+        let trashed = self.repo.soft_delete(task_id, user_id).await?;
+        if trashed > 0 {
+            let details = serde_json::json!({"tasks_trashed": trashed});
+            let _ = self.repo.log_task_activity(task_id, user_id, "task_deleted", Some(details.clone())).await;
+            self.events.publish_to(audience, TaskEventKind::Deleted, task_id, None, Some(user_id), Some(details));
+        }
+
+        Ok(trashed)
+    }
+
+    pub async fn list_trash(&self, user_id: Uuid, page: u32, limit: u32) -> Result<(Vec<TrashedTask>, i64)> {
+        let offset = (page.saturating_sub(1) as i64) * limit as i64;
+        self.repo.find_trash(user_id, self.trash_retention_days, limit as i64, offset).await
+    }
+
+    /// Takes one of the owner's tasks out of the trash, with the subtasks trashed alongside it.
+    pub async fn restore_task(&self, user_id: Uuid, task_id: Uuid) -> Result<Task> {
+        let trashed = self.repo
+            .find_trashed(task_id)
+            .await?
+            .filter(|task| task.user_id == user_id)
+            .ok_or_else(|| crate::error::AppError::NotFound("Task not found in trash".to_string()))?;
+
+        if let Some(parent_id) = trashed.parent_task_id {
+            if self.repo.find_trashed(parent_id).await?.is_some() {
+                return Err(crate::error::AppError::Conflict(
+                    "The parent task is in the trash; restore it first".to_string(),
+                ));
+            }
+        }
+
+        let task = self.repo
+            .restore(task_id)
+            .await?
+            .ok_or_else(|| crate::error::AppError::NotFound("Task not found in trash".to_string()))?;
+
+        // Its board column may have been deleted while it was in the trash
+        let task = match (task.project_id, task.parent_task_id, task.column_id) {
+            (Some(project_id), None, None) => {
+                self.project_repo.add_task_to_project(task.id, project_id, task.status).await?
+            }
+            _ => task,
+        };
+
+        let _ = self.repo.log_task_activity(task_id, user_id, "task_restored", None).await;
+        self.events.publish(TaskEventKind::Restored, &task, Some(user_id), None).await;
+
+        Ok(task)
+    }
+
+    /// Permanently deletes tasks that have been in the trash longer than the
+    /// retention period. Returns the number purged and the storage keys of
+    /// their attachments.
+    pub async fn purge_expired_trash(&self) -> Result<(i64, Vec<String>)> {
+        let expired = self.repo.find_expired_trash(self.trash_retention_days).await?;
+        if expired.is_empty() {
+            return Ok((0, Vec::new()));
         }
 
-        self.repo.delete(task_id, user_id).await
+        self.repo.purge(&expired).await
     }
 
     pub async fn update_status(
@@ -142,16 +346,18 @@ impl TaskService {
         user_id: Uuid,
         task_id: Uuid,
         payload: UpdateTaskStatusRequest,
+        expected_version: Option<i32>,
     ) -> Result<Task> {
-        // Check access
-        if !self.repo.has_task_access(task_id, user_id).await? {
-            return Err(crate::error::AppError::Forbidden("Access denied".to_string()));
-        }
+        self.require_permission(task_id, user_id, TaskPermission::Edit).await?;
 
-        let task = self.repo
-            .update_status(task_id, user_id, &payload.status)
-            .await?
-            .ok_or_else(|| crate::error::AppError::NotFound("Task not found".into()))?;
+        self.ensure_not_blocked(task_id, payload.status).await?;
+        let previous = self.get_task(user_id, task_id).await?;
+
+        let task = match self.repo.update_status(task_id, payload.status, expected_version).await? {
+            Some(task) => task,
+            None => return Err(self.stale_task_error(user_id, task_id).await),
+        };
+        self.repo.record_versions(&[task_id], Some(user_id), "status_updated").await?;
 
         // Log activity
         let _ = self.repo.log_task_activity(
@@ -161,8 +367,10 @@ impl TaskService {
             Some(serde_json::json!({"new_status": payload.status})),
         ).await;
 
+        let task = self.after_status_change(user_id, task, previous.status, payload.status).await?;
+
         // Send notification if task was completed
-        if payload.status.to_lowercase() == "completed" {
+        if payload.status == TaskStatus::Completed {
             let _ = self.notification_helper
                 .notify_task_completed(user_id, &task.title, task.id)
                 .await;
@@ -173,33 +381,346 @@ impl TaskService {
                 .await;
         }
 
+        self.events
+            .publish(
+                TaskEventKind::StatusChanged,
+                &task,
+                Some(user_id),
+                Some(serde_json::json!({"from": previous.status, "to": task.status})),
+            )
+            .await;
+
+        Ok(task)
+    }
+
+    // Bulk methods
+
+    /// IDs of the tasks a bulk request's filter selects, refusing filters that
+    /// match more than `MAX_BULK_TASKS`.
+    pub async fn resolve_bulk_filter(
+        &self,
+        user_id: Uuid,
+        filters: crate::task::task_repository::TaskFilters,
+    ) -> Result<Vec<Uuid>> {
+        let (tasks, total) = self.repo.get_user_tasks_including_shared(user_id, filters).await?;
+        if total > MAX_BULK_TASKS as i64 {
+            return Err(crate::error::AppError::BadRequest(format!(
+                "The filter matches {} tasks; at most {} can be changed at once",
+                total, MAX_BULK_TASKS
+            )));
+        }
+
+        Ok(tasks.into_iter().map(|task| task.id).collect())
+    }
+
+    /// Applies `operations` to every task in `task_ids` the user is allowed to
+    /// change, all in one transaction. Tasks the user can't change, or that a
+    /// status change would leave blocked, are reported as failed and left alone.
+    ///
+    /// Everyone with access to a changed task gets one task event for it.
+    pub async fn bulk_update(
+        &self,
+        user_id: Uuid,
+        task_ids: Vec<Uuid>,
+        operations: Vec<BulkTaskOperation>,
+    ) -> Result<BulkTaskResponse> {
+        if operations.len() > 1 && operations.iter().any(|op| matches!(op, BulkTaskOperation::Delete)) {
+            return Err(crate::error::AppError::BadRequest(
+                "delete can't be combined with other operations".to_string(),
+            ));
+        }
+        for operation in &operations {
+            if let BulkTaskOperation::AddLabel { label_id } = operation {
+                match self.label_repo.find_by_id(*label_id).await? {
+                    Some(label) if label.user_id == user_id => {}
+                    Some(_) => {
+                        return Err(crate::error::AppError::Forbidden("You can only use your own labels".to_string()));
+                    }
+                    None => return Err(crate::error::AppError::NotFound("Label not found".to_string())),
+                }
+            }
+        }
+        let new_status = operations.iter().rev().find_map(|op| op.new_status());
+
+        let mut seen = std::collections::HashSet::new();
+        let task_ids: Vec<Uuid> = task_ids.into_iter().filter(|id| seen.insert(*id)).collect();
+
+        let mut failures = HashMap::new();
+        let mut previous_statuses = HashMap::new();
+        let mut audiences = HashMap::new();
+        for &task_id in &task_ids {
+            let role = match self.repo.get_task_role(task_id, user_id).await? {
+                Some(role) => role,
+                None => {
+                    failures.insert(task_id, BulkTaskResult::failed(task_id, "Task not found"));
+                    continue;
+                }
+            };
+            if let Some(denied) = operations.iter().find(|op| !role.can(op.required_permission())) {
+                failures.insert(task_id, BulkTaskResult::failed(task_id, role.denied(denied.required_permission())));
+                continue;
+            }
+            if let Some(status) = new_status {
+                match self.ensure_not_blocked(task_id, status).await {
+                    Ok(()) => {}
+                    Err(crate::error::AppError::Conflict(message)) => {
+                        failures.insert(task_id, BulkTaskResult::failed(task_id, message));
+                        continue;
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+
+            let task = self.get_task(user_id, task_id).await?;
+            previous_statuses.insert(task_id, task.status);
+            // Trashed tasks have no audience, so look it up before anything changes
+            audiences.insert(task_id, self.repo.get_audience(task_id).await?);
+        }
+
+        let eligible: Vec<Uuid> = task_ids.iter().copied().filter(|id| !failures.contains_key(id)).collect();
+        let mut changed: HashMap<Uuid, Task> = HashMap::new();
+        if !eligible.is_empty() {
+            let action = match operations.as_slice() {
+                [BulkTaskOperation::Delete] => "task_deleted",
+                _ => "bulk_updated",
+            };
+            for task in self.repo.bulk_apply(&eligible, &operations, user_id, action).await? {
+                changed.insert(task.id, task);
+            }
+        }
+
+        // Cascades, unblocked dependents and next occurrences, as for a single status change
+        if let Some(status) = new_status {
+            for (&task_id, &previous) in &previous_statuses {
+                if let Some(task) = changed.remove(&task_id) {
+                    let task = self.after_status_change(user_id, task, previous, status).await?;
+                    changed.insert(task_id, task);
+                }
+            }
+        }
+
+        let applied: Vec<&str> = operations.iter().map(|op| op.name()).collect();
+        let kind = match (operations.as_slice(), &new_status) {
+            ([BulkTaskOperation::Delete], _) => TaskEventKind::Deleted,
+            (_, Some(_)) => TaskEventKind::StatusChanged,
+            _ => TaskEventKind::Updated,
+        };
+        let mut results = Vec::with_capacity(task_ids.len());
+        for task_id in task_ids {
+            if let Some(failure) = failures.remove(&task_id) {
+                results.push(failure);
+                continue;
+            }
+            let task = changed.remove(&task_id);
+            if let Some(task) = &task {
+                let mut data = serde_json::json!({"bulk": applied});
+                if let (TaskEventKind::StatusChanged, Some(previous)) = (kind, previous_statuses.get(&task_id)) {
+                    data["from"] = serde_json::json!(previous);
+                    data["to"] = serde_json::json!(task.status);
+                }
+                self.events.publish_to(
+                    audiences.remove(&task_id).unwrap_or_default(),
+                    kind,
+                    task_id,
+                    (kind != TaskEventKind::Deleted).then(|| task.clone()),
+                    Some(user_id),
+                    Some(data),
+                );
+            }
+            results.push(BulkTaskResult {
+                task_id,
+                success: task.is_some(),
+                error: task.is_none().then(|| "Task not found".to_string()),
+                task,
+            });
+        }
+
+        let succeeded = results.iter().filter(|r| r.success).count();
+        Ok(BulkTaskResponse {
+            succeeded,
+            failed: results.len() - succeeded,
+            results,
+        })
+    }
+
+    // Version methods
+
+    /// Every version of a task, newest first, each with the changes it made.
+    pub async fn list_versions(&self, user_id: Uuid, task_id: Uuid) -> Result<Vec<TaskVersionDiff>> {
+        self.require_permission(task_id, user_id, TaskPermission::View).await?;
+
+        let versions = self.repo.find_versions(task_id).await?;
+        let previous: Vec<Option<TaskSnapshot>> = versions
+            .iter()
+            .skip(1)
+            .map(|v| Some(v.snapshot.0.clone()))
+            .chain(std::iter::once(None))
+            .collect();
+
+        Ok(versions
+            .into_iter()
+            .zip(previous)
+            .map(|(version, previous)| TaskVersionDiff {
+                changes: diff_snapshots(previous.as_ref(), &version.snapshot),
+                version,
+            })
+            .collect())
+    }
+
+    pub async fn get_version(&self, user_id: Uuid, task_id: Uuid, version: i32) -> Result<TaskVersionDiff> {
+        self.require_permission(task_id, user_id, TaskPermission::View).await?;
+
+        let mut versions = self.repo.find_version(task_id, version).await?.into_iter();
+        let version = versions
+            .next()
+            .ok_or_else(|| crate::error::AppError::NotFound("Version not found".to_string()))?;
+        let previous = versions.next();
+
+        Ok(TaskVersionDiff {
+            changes: diff_snapshots(previous.as_ref().map(|v| &v.snapshot.0), &version.snapshot),
+            version,
+        })
+    }
+
+    /// Puts a task's editable fields back to how they were at `version`. The
+    /// revert goes through the same checks as an update and becomes a new
+    /// version itself, so it can be undone in turn.
+    pub async fn revert_to_version(&self, user_id: Uuid, task_id: Uuid, version: i32) -> Result<Task> {
+        self.require_permission(task_id, user_id, TaskPermission::Edit).await?;
+
+        let existing = self.get_task(user_id, task_id).await?;
+        let target = self.repo
+            .find_version(task_id, version)
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| crate::error::AppError::NotFound("Version not found".to_string()))?;
+        let snapshot = target.snapshot.0;
+
+        let changes = diff_snapshots(Some(&TaskSnapshot::from(&existing)), &snapshot);
+        if changes.is_empty() {
+            return Ok(existing);
+        }
+
+        if snapshot.status != existing.status {
+            self.ensure_not_blocked(task_id, snapshot.status).await?;
+        }
+        if let Some(rule) = &snapshot.recurrence_rule {
+            Self::check_recurrence(rule, snapshot.due_date)?;
+        }
+
+        let task = self.repo.apply_snapshot(task_id, &snapshot).await?;
+        self.repo.record_versions(&[task_id], Some(user_id), "reverted").await?;
+
+        let _ = self.repo.log_task_activity(
+            task_id,
+            user_id,
+            "reverted",
+            Some(serde_json::json!({"to_version": version, "changes": changes})),
+        ).await;
+
+        let task = if snapshot.status != existing.status {
+            self.after_status_change(user_id, task, existing.status, snapshot.status).await?
+        } else {
+            task
+        };
+
+        let fields: Vec<&str> = changes.iter().map(|c| c.field.as_str()).collect();
+        let _ = self.notification_helper
+            .notify_task_updated(user_id, &task.title, task.id, &format!("reverted {} to version {}", fields.join(", "), version))
+            .await;
+        self.events
+            .publish(
+                TaskEventKind::Updated,
+                &task,
+                Some(user_id),
+                Some(serde_json::json!({"changes": fields, "reverted_to_version": version})),
+            )
+            .await;
+
         Ok(task)
     }
 
     // Collaboration methods
+
+    /// Adds members with the given role. Admins can share as editor or viewer;
+    /// only the owner can add admins. Existing members are skipped and keep
+    /// their role; `change_member_role` changes it. Returns the users added.
     pub async fn share_task(
         &self,
         task_id: Uuid,
         user_ids: Vec<Uuid>,
+        role: TaskRole,
         shared_by: Uuid,
-    ) -> Result<()> {
-        // Only owner can share
-        if !self.repo.is_task_owner(task_id, shared_by).await? {
-            return Err(crate::error::AppError::Forbidden("Only task owner can share".to_string()));
-        }
+    ) -> Result<Vec<Uuid>> {
+        let sharer_role = self.require_permission(task_id, shared_by, TaskPermission::ManageMembers).await?;
+        Self::ensure_can_manage(sharer_role, role)?;
 
+        let mut added = Vec::with_capacity(user_ids.len());
         for user_id in user_ids {
-            self.repo.add_task_member(task_id, user_id, "collaborator", shared_by).await?;
-            
+            if !self.repo.add_task_member(task_id, user_id, role, shared_by).await? {
+                continue;
+            }
+            added.push(user_id);
+
             // Log activity
             let _ = self.repo.log_task_activity(
                 task_id,
                 shared_by,
                 "member_added",
-                Some(serde_json::json!({"added_user_id": user_id})),
+                Some(serde_json::json!({"added_user_id": user_id, "role": role})),
+            ).await;
+        }
+
+        if !added.is_empty() {
+            self.publish_change(
+                TaskEventKind::Shared,
+                task_id,
+                shared_by,
+                serde_json::json!({"user_ids": added, "role": role}),
             ).await;
         }
 
+        Ok(added)
+    }
+
+    /// Changes a member's role, within what the caller's own role can manage.
+    pub async fn change_member_role(
+        &self,
+        task_id: Uuid,
+        user_id: Uuid,
+        role: TaskRole,
+        changed_by: Uuid,
+    ) -> Result<()> {
+        let changer_role = self.require_permission(task_id, changed_by, TaskPermission::ManageMembers).await?;
+        let current = self.get_member_role(task_id, user_id).await?;
+        if current == TaskRole::Owner {
+            return Err(crate::error::AppError::BadRequest("Cannot change the task owner's role".to_string()));
+        }
+        Self::ensure_can_manage(changer_role, current)?;
+        Self::ensure_can_manage(changer_role, role)?;
+
+        if current == role {
+            return Ok(());
+        }
+
+        self.repo.update_member_role(task_id, user_id, role).await?;
+        self.repo.remove_uneditable_assignments(task_id, user_id).await?;
+
+        let _ = self.repo.log_task_activity(
+            task_id,
+            changed_by,
+            "member_role_changed",
+            Some(serde_json::json!({"user_id": user_id, "from": current, "to": role})),
+        ).await;
+
+        self.publish_change(
+            TaskEventKind::MemberRoleChanged,
+            task_id,
+            changed_by,
+            serde_json::json!({"user_id": user_id, "from": current, "to": role}),
+        ).await;
+
         Ok(())
     }
 
@@ -209,17 +730,22 @@ impl TaskService {
         user_id: Uuid,
         removed_by: Uuid,
     ) -> Result<()> {
-        // Only owner can remove collaborators
-        if !self.repo.is_task_owner(task_id, removed_by).await? {
-            return Err(crate::error::AppError::Forbidden("Only task owner can remove collaborators".to_string()));
-        }
+        let remover_role = self.require_permission(task_id, removed_by, TaskPermission::ManageMembers).await?;
 
         // Cannot remove owner
         if self.repo.is_task_owner(task_id, user_id).await? {
             return Err(crate::error::AppError::BadRequest("Cannot remove task owner".to_string()));
         }
 
+        let current = self.get_member_role(task_id, user_id).await?;
+        Self::ensure_can_manage(remover_role, current)?;
+
+        // The removed member should hear about it too
+        let task = self.get_task(removed_by, task_id).await?;
+        let audience = self.events.audience(task_id).await;
+
         self.repo.remove_task_member(task_id, user_id).await?;
+        self.repo.remove_uneditable_assignments(task_id, user_id).await?;
 
         // Log activity
         let _ = self.repo.log_task_activity(
@@ -229,19 +755,164 @@ impl TaskService {
             Some(serde_json::json!({"removed_user_id": user_id})),
         ).await;
 
+        self.events.publish_to(
+            audience,
+            TaskEventKind::MemberRemoved,
+            task_id,
+            Some(task),
+            Some(removed_by),
+            Some(serde_json::json!({"user_id": user_id})),
+        );
+
         Ok(())
     }
 
+    // Assignee methods
+
+    /// Assigns task members to a task. Anyone who can edit the task can assign,
+    /// and only members who can edit it can be assigned; returns the users that
+    /// weren't assigned already.
+    pub async fn assign_users(
+        &self,
+        task_id: Uuid,
+        user_ids: Vec<Uuid>,
+        assigned_by: Uuid,
+    ) -> Result<Vec<Uuid>> {
+        self.require_permission(task_id, assigned_by, TaskPermission::Edit).await?;
+
+        for &user_id in &user_ids {
+            match self.repo.get_task_role(task_id, user_id).await? {
+                Some(role) if role.can(TaskPermission::Edit) => {}
+                Some(_) => {
+                    return Err(crate::error::AppError::BadRequest(format!(
+                        "User {} can only view this task and cannot be assigned to it",
+                        user_id
+                    )));
+                }
+                None => {
+                    return Err(crate::error::AppError::BadRequest(format!(
+                        "User {} is not a member of this task",
+                        user_id
+                    )));
+                }
+            }
+        }
+
+        let added = self.repo.add_assignees(task_id, &user_ids, assigned_by).await?;
+
+        for &user_id in &added {
+            let _ = self.repo.log_task_activity(
+                task_id,
+                assigned_by,
+                "assignee_added",
+                Some(serde_json::json!({"assignee_id": user_id})),
+            ).await;
+        }
+
+        if !added.is_empty() {
+            self.publish_change(
+                TaskEventKind::Assigned,
+                task_id,
+                assigned_by,
+                serde_json::json!({"user_ids": added}),
+            ).await;
+            self.notify_assignment_change(task_id, &added, assigned_by, true).await;
+        }
+
+        Ok(added)
+    }
+
+    pub async fn unassign_user(
+        &self,
+        task_id: Uuid,
+        user_id: Uuid,
+        removed_by: Uuid,
+    ) -> Result<()> {
+        self.require_permission(task_id, removed_by, TaskPermission::Edit).await?;
+
+        if self.repo.remove_assignee(task_id, user_id).await? == 0 {
+            return Err(crate::error::AppError::NotFound("User is not assigned to this task".to_string()));
+        }
+
+        let _ = self.repo.log_task_activity(
+            task_id,
+            removed_by,
+            "assignee_removed",
+            Some(serde_json::json!({"assignee_id": user_id})),
+        ).await;
+
+        self.publish_change(
+            TaskEventKind::Unassigned,
+            task_id,
+            removed_by,
+            serde_json::json!({"user_id": user_id}),
+        ).await;
+        self.notify_assignment_change(task_id, &[user_id], removed_by, false).await;
+
+        Ok(())
+    }
+
+    /// Tells users they were assigned to or unassigned from a task, unless
+    /// they made the change themselves.
+    async fn notify_assignment_change(&self, task_id: Uuid, user_ids: &[Uuid], changed_by: Uuid, assigned: bool) {
+        let recipients: Vec<Uuid> = user_ids.iter().copied().filter(|&id| id != changed_by).collect();
+        if recipients.is_empty() {
+            return;
+        }
+
+        let (task, changer) = match (self.get_task(changed_by, task_id).await, self.user_repo.find_by_id(changed_by).await) {
+            (Ok(task), Ok(Some(changer))) => (task, changer),
+            _ => return,
+        };
+
+        for user_id in recipients {
+            let payload = TaskAssignmentPayload {
+                task_id,
+                task_title: task.title.clone(),
+                changed_by,
+                changed_by_username: changer.username.clone(),
+            };
+            let ws_message = if assigned {
+                WsMessage::TaskAssigned(payload)
+            } else {
+                WsMessage::TaskUnassigned(payload)
+            };
+            self.ws_manager.send_to_user(&user_id, ws_message);
+
+            let _ = if assigned {
+                self.notification_helper
+                    .notify_task_assigned(user_id, &task.title, &changer.username, task_id)
+                    .await
+            } else {
+                self.notification_helper
+                    .notify_task_unassigned(user_id, &task.title, &changer.username, task_id)
+                    .await
+            };
+        }
+    }
+
+    /// Publishes a change around a task, such as to its members, checklist or
+    /// labels, that leaves the task's own fields as they were.
+    pub async fn publish_change(&self, kind: TaskEventKind, task_id: Uuid, actor: Uuid, data: serde_json::Value) {
+        match self.get_task(actor, task_id).await {
+            Ok(task) => self.events.publish(kind, &task, Some(actor), Some(data)).await,
+            Err(e) => tracing::warn!("Failed to publish {:?} event for task {}: {:?}", kind, task_id, e),
+        }
+    }
+
+    pub async fn get_assignees(&self, task_id: Uuid, requesting_user: Uuid) -> Result<Vec<TaskAssignee>> {
+        self.require_permission(task_id, requesting_user, TaskPermission::View).await?;
+
+        self.repo.get_assignees(task_id).await
+    }
+
     #[allow(dead_code)]
     pub async fn get_task_with_members(
         &self,
         task_id: Uuid,
         requesting_user: Uuid,
     ) -> Result<crate::task::task_models::TaskWithMembers> {
-        // Check access
-        if !self.repo.has_task_access(task_id, requesting_user).await? {
-            return Err(crate::error::AppError::Forbidden("Access denied".to_string()));
-        }
+        self.require_permission(task_id, requesting_user, TaskPermission::View).await?;
 
         let task = self.get_task(requesting_user, task_id).await?;
         let members = self.repo.get_task_members(task_id).await?;
@@ -255,21 +926,394 @@ impl TaskService {
     }
 
     pub async fn get_task_members(&self, task_id: Uuid, requesting_user: Uuid) -> Result<Vec<crate::task::task_models::TaskMemberInfo>> {
-        // Check access
-        if !self.repo.has_task_access(task_id, requesting_user).await? {
-            return Err(crate::error::AppError::Forbidden("Access denied".to_string()));
-        }
+        self.require_permission(task_id, requesting_user, TaskPermission::View).await?;
 
         self.repo.get_task_members(task_id).await
     }
 
     pub async fn get_task_activity(&self, task_id: Uuid, requesting_user: Uuid) -> Result<Vec<crate::task::task_dto::TaskActivityResponse>> {
-        // Check access
-        if !self.repo.has_task_access(task_id, requesting_user).await? {
-            return Err(crate::error::AppError::Forbidden("Access denied".to_string()));
-        }
+        self.require_permission(task_id, requesting_user, TaskPermission::View).await?;
 
         self.repo.get_task_activity(task_id).await
     }
 
+    // Subtask methods
+    pub async fn create_subtask(
+        &self,
+        user_id: Uuid,
+        parent_task_id: Uuid,
+        payload: CreateTaskRequest,
+    ) -> Result<Task> {
+        // Anyone who can edit the parent can add children to it
+        self.require_permission(parent_task_id, user_id, TaskPermission::Edit).await?;
+        let parent = self.get_task(user_id, parent_task_id).await?;
+
+        let subtask = self.insert_task(user_id, payload, Some(parent.id)).await?;
+
+        let _ = self.repo.log_task_activity(
+            parent.id,
+            user_id,
+            "subtask_added",
+            Some(serde_json::json!({"subtask_id": subtask.id, "title": subtask.title})),
+        ).await;
+
+        // A new open child means the parent is no longer done
+        let _ = self.apply_status_cascade(user_id, &subtask, subtask.status).await?;
+
+        Ok(subtask)
+    }
+
+    pub async fn list_subtasks(&self, user_id: Uuid, parent_task_id: Uuid) -> Result<Vec<TaskListItem>> {
+        let parent = self.get_task(user_id, parent_task_id).await?;
+        let subtasks = self.repo.find_subtasks(parent.id).await?;
+        self.to_list_items(subtasks).await
+    }
+
+    pub async fn reorder_subtasks(
+        &self,
+        user_id: Uuid,
+        parent_task_id: Uuid,
+        subtask_ids: Vec<Uuid>,
+    ) -> Result<Vec<TaskListItem>> {
+        self.require_permission(parent_task_id, user_id, TaskPermission::Edit).await?;
+        let parent = self.get_task(user_id, parent_task_id).await?;
+
+        // The new order must be a permutation of the current children
+        let current: std::collections::HashSet<Uuid> = self.repo
+            .find_subtasks(parent.id)
+            .await?
+            .into_iter()
+            .map(|t| t.id)
+            .collect();
+        let requested: std::collections::HashSet<Uuid> = subtask_ids.iter().copied().collect();
+        if requested.len() != subtask_ids.len() || requested != current {
+            return Err(crate::error::AppError::BadRequest(
+                "subtask_ids must list every subtask of the task exactly once".to_string(),
+            ));
+        }
+
+        self.repo.reorder_subtasks(parent.id, &subtask_ids).await?;
+
+        let _ = self.repo.log_task_activity(
+            parent.id,
+            user_id,
+            "subtasks_reordered",
+            Some(serde_json::json!({"subtask_ids": subtask_ids})),
+        ).await;
+
+        self.publish_change(
+            TaskEventKind::SubtasksReordered,
+            parent.id,
+            user_id,
+            serde_json::json!({"subtask_ids": subtask_ids}),
+        ).await;
+
+        self.list_subtasks(user_id, parent.id).await
+    }
+
+    /// Follow-up work once `task` has been saved with a new `status`: cascades
+    /// through the hierarchy and, if the status actually changed, notifies
+    /// unblocked dependents, spawns the next recurrence and moves the task to
+    /// the matching board column. Returns the task as stored afterwards.
+    async fn after_status_change(
+        &self,
+        user_id: Uuid,
+        task: Task,
+        previous_status: TaskStatus,
+        status: TaskStatus,
+    ) -> Result<Task> {
+        let cascaded = self.apply_status_cascade(user_id, &task, status).await?;
+        if previous_status == task.status {
+            return Ok(task);
+        }
+
+        self.notify_unblocked_dependents(&task, &cascaded).await?;
+        self.create_next_occurrence_on_completion(&task).await?;
+
+        Ok(self.project_repo.sync_task_column(&task).await?.unwrap_or(task))
+    }
+
+    /// Keeps parents and children consistent after `task` moved to `status`:
+    /// - completing or archiving a task does the same to all of its open descendants;
+    /// - reopening a subtask reopens any completed ancestor as InProgress;
+    /// - starting a subtask also starts any pending ancestor.
+    ///
+    /// Returns the ids of the other tasks whose status changed.
+    async fn apply_status_cascade(&self, user_id: Uuid, task: &Task, status: TaskStatus) -> Result<Vec<Uuid>> {
+        let is_subtask = task.parent_task_id.is_some();
+
+        let (action, affected) = match status {
+            TaskStatus::Completed | TaskStatus::Archived => {
+                let ids = self.repo.cascade_status_to_descendants(task.id, status).await?;
+                ("status_cascaded_to_subtasks", ids)
+            }
+            TaskStatus::InProgress if is_subtask => {
+                let ids = self.repo
+                    .reopen_ancestors(task.id, &[TaskStatus::Completed, TaskStatus::Pending])
+                    .await?;
+                ("status_cascaded_to_parents", ids)
+            }
+            TaskStatus::Pending if is_subtask => {
+                let ids = self.repo.reopen_ancestors(task.id, &[TaskStatus::Completed]).await?;
+                ("status_cascaded_to_parents", ids)
+            }
+            _ => return Ok(Vec::new()),
+        };
+
+        if !affected.is_empty() {
+            self.repo.record_versions(&affected, Some(user_id), action).await?;
+            let _ = self.repo.log_task_activity(
+                task.id,
+                user_id,
+                action,
+                Some(serde_json::json!({"status": status, "task_ids": affected})),
+            ).await;
+        }
+
+        Ok(affected)
+    }
+
+    // Dependency methods
+    pub async fn add_dependency(
+        &self,
+        user_id: Uuid,
+        task_id: Uuid,
+        blocked_by_task_id: Uuid,
+    ) -> Result<TaskDetail> {
+        if task_id == blocked_by_task_id {
+            return Err(crate::error::AppError::BadRequest("A task cannot block itself".to_string()));
+        }
+
+        // The user must be able to edit the blocked task and see its blocker
+        self.require_permission(task_id, user_id, TaskPermission::Edit).await?;
+        let task = self.get_task(user_id, task_id).await?;
+        let blocker = self.get_task(user_id, blocked_by_task_id).await?;
+
+        if !self.repo.add_dependency(task.id, blocker.id, user_id).await? {
+            return Err(crate::error::AppError::Conflict(format!(
+                "'{}' already depends on '{}'; adding this dependency would create a cycle",
+                blocker.title, task.title
+            )));
+        }
+
+        let _ = self.repo.log_task_activity(
+            task.id,
+            user_id,
+            "dependency_added",
+            Some(serde_json::json!({"blocked_by_task_id": blocker.id, "title": blocker.title})),
+        ).await;
+
+        self.publish_change(
+            TaskEventKind::DependenciesChanged,
+            task.id,
+            user_id,
+            serde_json::json!({"action": "added", "blocked_by_task_id": blocker.id}),
+        ).await;
+
+        self.get_task_detail(user_id, task.id).await
+    }
+
+    pub async fn remove_dependency(
+        &self,
+        user_id: Uuid,
+        task_id: Uuid,
+        blocked_by_task_id: Uuid,
+    ) -> Result<()> {
+        self.require_permission(task_id, user_id, TaskPermission::Edit).await?;
+        let task = self.get_task(user_id, task_id).await?;
+
+        if self.repo.remove_dependency(task.id, blocked_by_task_id).await? == 0 {
+            return Err(crate::error::AppError::NotFound("Dependency not found".to_string()));
+        }
+
+        let _ = self.repo.log_task_activity(
+            task.id,
+            user_id,
+            "dependency_removed",
+            Some(serde_json::json!({"blocked_by_task_id": blocked_by_task_id})),
+        ).await;
+
+        self.publish_change(
+            TaskEventKind::DependenciesChanged,
+            task.id,
+            user_id,
+            serde_json::json!({"action": "removed", "blocked_by_task_id": blocked_by_task_id}),
+        ).await;
+
+        Ok(())
+    }
+
+    /// The user's role on the task, provided it grants `permission`.
+    pub async fn require_permission(&self, task_id: Uuid, user_id: Uuid, permission: TaskPermission) -> Result<TaskRole> {
+        let role = self.repo
+            .get_task_role(task_id, user_id)
+            .await?
+            .ok_or_else(|| crate::error::AppError::Forbidden("Access denied".to_string()))?;
+
+        if !role.can(permission) {
+            return Err(crate::error::AppError::Forbidden(role.denied(permission)));
+        }
+
+        Ok(role)
+    }
+
+    /// The role `user_id` was given on this task directly.
+    async fn get_member_role(&self, task_id: Uuid, user_id: Uuid) -> Result<TaskRole> {
+        self.repo
+            .get_member_role(task_id, user_id)
+            .await?
+            .ok_or_else(|| crate::error::AppError::NotFound("User is not a member of this task".to_string()))
+    }
+
+    fn ensure_can_manage(manager: TaskRole, role: TaskRole) -> Result<()> {
+        if role == TaskRole::Owner {
+            return Err(crate::error::AppError::BadRequest("A task has exactly one owner".to_string()));
+        }
+        if !manager.can_manage(role) {
+            return Err(crate::error::AppError::Forbidden(format!(
+                "A task {} cannot manage {}s",
+                manager, role
+            )));
+        }
+        Ok(())
+    }
+
+    /// The error for a conditional write that found the task at another version,
+    /// carrying the task as it is now.
+    async fn stale_task_error(&self, user_id: Uuid, task_id: Uuid) -> crate::error::AppError {
+        match self.get_task(user_id, task_id).await {
+            Ok(current) => crate::error::AppError::stale("task", &current),
+            Err(e) => e,
+        }
+    }
+
+    /// Refuses to start or complete a task while any of its blockers is still open.
+    async fn ensure_not_blocked(&self, task_id: Uuid, status: TaskStatus) -> Result<()> {
+        if !matches!(status, TaskStatus::InProgress | TaskStatus::Completed) {
+            return Ok(());
+        }
+
+        let blockers = self.repo.get_unfinished_blocker_titles(task_id).await?;
+        if !blockers.is_empty() {
+            return Err(crate::error::AppError::Conflict(format!(
+                "Task is blocked by unfinished tasks: {}",
+                blockers.join(", ")
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Notifies owners of tasks that were waiting on `task` (or on any task that
+    /// was completed along with it) and no longer have open blockers.
+    async fn notify_unblocked_dependents(&self, task: &Task, cascaded: &[Uuid]) -> Result<()> {
+        if !matches!(task.status, TaskStatus::Completed | TaskStatus::Archived) {
+            return Ok(());
+        }
+
+        let mut finished = vec![task.id];
+        finished.extend_from_slice(cascaded);
+
+        for dependent in self.repo.find_unblocked_dependents(&finished).await? {
+            let _ = self.notification_helper
+                .notify_task_unblocked(dependent.user_id, &dependent.title, dependent.id)
+                .await;
+        }
+
+        Ok(())
+    }
+
+    // Recurrence methods
+
+    fn check_recurrence(rule: &RecurrenceRule, due_date: Option<DateTime<Utc>>) -> Result<()> {
+        rule.check()
+            .map_err(|e| crate::error::AppError::Validation(e.to_string()))?;
+
+        if due_date.is_none() {
+            return Err(crate::error::AppError::Validation(
+                "Recurring tasks need a due_date".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Completing the latest occurrence of a recurring task creates the next one right away.
+    async fn create_next_occurrence_on_completion(&self, task: &Task) -> Result<()> {
+        if task.status == TaskStatus::Completed {
+            self.create_next_occurrence(task, None).await?;
+        }
+        Ok(())
+    }
+
+    /// Creates the next occurrence of every recurring series whose next reminder
+    /// (or due date, without a reminder) falls within `lookahead`, so the reminder
+    /// job can pick it up on time. Returns the new occurrences.
+    pub async fn materialize_upcoming_occurrences(&self, lookahead: Duration) -> Result<Vec<Task>> {
+        let horizon = Utc::now() + lookahead;
+        let mut created = Vec::new();
+
+        for head in self.repo.find_recurring_series_heads().await? {
+            if let Some(task) = self.create_next_occurrence(&head, Some(horizon)).await? {
+                created.push(task);
+            }
+        }
+
+        Ok(created)
+    }
+
+    /// Creates the occurrence following `task`, shifting its due date and reminder.
+    /// Occurrences that would already be overdue are skipped. With a `horizon`,
+    /// nothing is created if the next occurrence triggers after it.
+    async fn create_next_occurrence(
+        &self,
+        task: &Task,
+        horizon: Option<DateTime<Utc>>,
+    ) -> Result<Option<Task>> {
+        let (Some(rule), Some(due), Some(index)) =
+            (&task.recurrence_rule, task.due_date, task.recurrence_index)
+        else {
+            return Ok(None);
+        };
+
+        let Some((next_due, next_index)) = rule.next_occurrence(due, index, Utc::now()) else {
+            return Ok(None);
+        };
+        let next_reminder = task.reminder_time.map(|reminder| reminder + (next_due - due));
+
+        if horizon.is_some_and(|horizon| next_reminder.unwrap_or(next_due) > horizon) {
+            return Ok(None);
+        }
+
+        let next = self.repo
+            .create_occurrence(task.id, next_index, next_due, next_reminder)
+            .await?;
+        let next = match next {
+            Some(next) => match next.project_id {
+                Some(project_id) => Some(self.project_repo.add_task_to_project(next.id, project_id, next.status).await?),
+                None => Some(next),
+            },
+            None => None,
+        };
+
+        if let Some(next) = &next {
+            self.repo.record_versions(&[next.id], None, "created").await?;
+            let _ = self.repo.log_task_activity(
+                next.id,
+                next.user_id,
+                "recurrence_generated",
+                Some(serde_json::json!({"previous_task_id": task.id, "recurrence_index": next_index})),
+            ).await;
+            self.events
+                .publish(
+                    TaskEventKind::Created,
+                    next,
+                    None,
+                    Some(serde_json::json!({"previous_task_id": task.id, "recurrence_index": next_index})),
+                )
+                .await;
+        }
+
+        Ok(next)
+    }
 }
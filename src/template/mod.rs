@@ -0,0 +1,6 @@
+// Declare submodules
+pub mod template_models;
+pub mod template_dto;
+pub mod template_repository;
+pub mod template_service;
+pub mod template_handlers;
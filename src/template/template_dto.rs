@@ -0,0 +1,85 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::task::task_models::TaskPriority;
+
+use super::template_models::{TemplateMember, TemplateVisibility};
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateTemplateRequest {
+    /// Name of the template itself
+    #[validate(length(min = 1, max = 255))]
+    pub name: String,
+    /// Title given to the tasks
+    #[validate(length(min = 1, max = 500))]
+    pub title: String,
+    pub description: Option<String>,
+    pub priority: Option<TaskPriority>,
+    /// Minutes from the base date to the due date
+    pub due_offset_minutes: Option<i32>,
+    /// Minutes from the base date to the reminder
+    pub reminder_offset_minutes: Option<i32>,
+    /// Expected effort in minutes
+    #[validate(range(min = 1))]
+    pub estimate_minutes: Option<i32>,
+    /// Checklist items, in order
+    #[serde(default)]
+    #[validate(length(max = 200))]
+    pub checklist: Vec<String>,
+    /// Users added to every task created from the template
+    #[serde(default)]
+    #[validate(length(max = 50))]
+    pub default_members: Vec<TemplateMember>,
+    /// Defaults to private
+    #[serde(default)]
+    pub visibility: TemplateVisibility,
+    /// Required for group templates
+    pub group_id: Option<Uuid>,
+    /// Users who can use a shared template
+    #[serde(default)]
+    pub shared_with: Vec<Uuid>,
+}
+
+/// Fields left out keep their value; lists given replace the old ones.
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateTemplateRequest {
+    #[validate(length(min = 1, max = 255))]
+    pub name: Option<String>,
+    #[validate(length(min = 1, max = 500))]
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub priority: Option<TaskPriority>,
+    pub due_offset_minutes: Option<i32>,
+    pub reminder_offset_minutes: Option<i32>,
+    #[validate(range(min = 1))]
+    pub estimate_minutes: Option<i32>,
+    #[validate(length(max = 200))]
+    pub checklist: Option<Vec<String>>,
+    #[validate(length(max = 50))]
+    pub default_members: Option<Vec<TemplateMember>>,
+    pub visibility: Option<TemplateVisibility>,
+    pub group_id: Option<Uuid>,
+    pub shared_with: Option<Vec<Uuid>>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct InstantiateTemplateRequest {
+    /// Due and reminder offsets count from here; defaults to now
+    pub base_date: Option<DateTime<Utc>>,
+    /// Project to add the new task to
+    pub project_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct InstantiateTemplatesRequest {
+    /// Templates to create tasks from, one task each, in this order
+    #[validate(length(min = 1, max = 50))]
+    pub template_ids: Vec<Uuid>,
+    /// Due and reminder offsets count from here; defaults to now
+    pub base_date: Option<DateTime<Utc>>,
+    /// Project to add the new tasks to
+    pub project_id: Option<Uuid>,
+}
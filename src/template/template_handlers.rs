@@ -0,0 +1,223 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use chrono::Utc;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    error::Result,
+    middleware::{AppJson, AuthUser},
+    state::AppState,
+    template::template_dto::{
+        CreateTemplateRequest, InstantiateTemplateRequest, InstantiateTemplatesRequest, UpdateTemplateRequest,
+    },
+};
+
+/// Create a task template
+#[utoipa::path(
+    post,
+    path = "/api/templates",
+    tag = "templates",
+    request_body = CreateTemplateRequest,
+    responses(
+        (status = 201, description = "Template created", body = TaskTemplate),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Not a member of the group"),
+        (status = 422, description = "Unknown priority or visibility; the error lists the allowed values")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create_template(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    AppJson(payload): AppJson<CreateTemplateRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+
+    let template = state.template_service.create_template(user_id, payload).await?;
+
+    Ok((StatusCode::CREATED, Json(template)))
+}
+
+/// List the templates you own, were shared or can use through a group
+#[utoipa::path(
+    get,
+    path = "/api/templates",
+    tag = "templates",
+    responses(
+        (status = 200, description = "Templates retrieved", body = Vec<TaskTemplate>),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_templates(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+) -> Result<impl IntoResponse> {
+    let templates = state.template_service.list_templates(user_id).await?;
+
+    Ok((StatusCode::OK, Json(templates)))
+}
+
+/// Get a task template
+#[utoipa::path(
+    get,
+    path = "/api/templates/{template_id}",
+    tag = "templates",
+    params(
+        ("template_id" = Uuid, Path, description = "Template ID")
+    ),
+    responses(
+        (status = 200, description = "Template retrieved", body = TaskTemplate),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Template not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_template(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(template_id): Path<Uuid>,
+) -> Result<impl IntoResponse> {
+    let template = state.template_service.get_template(template_id, user_id).await?;
+
+    Ok((StatusCode::OK, Json(template)))
+}
+
+/// Update a task template (owner only)
+#[utoipa::path(
+    put,
+    path = "/api/templates/{template_id}",
+    tag = "templates",
+    params(
+        ("template_id" = Uuid, Path, description = "Template ID")
+    ),
+    request_body = UpdateTemplateRequest,
+    responses(
+        (status = 200, description = "Template updated", body = TaskTemplate),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Not the template owner"),
+        (status = 404, description = "Template not found"),
+        (status = 422, description = "Unknown priority or visibility; the error lists the allowed values")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn update_template(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(template_id): Path<Uuid>,
+    AppJson(payload): AppJson<UpdateTemplateRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+
+    let template = state.template_service.update_template(template_id, user_id, payload).await?;
+
+    Ok((StatusCode::OK, Json(template)))
+}
+
+/// Delete a task template (owner only)
+#[utoipa::path(
+    delete,
+    path = "/api/templates/{template_id}",
+    tag = "templates",
+    params(
+        ("template_id" = Uuid, Path, description = "Template ID")
+    ),
+    responses(
+        (status = 204, description = "Template deleted"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Not the template owner"),
+        (status = 404, description = "Template not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_template(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(template_id): Path<Uuid>,
+) -> Result<impl IntoResponse> {
+    state.template_service.delete_template(template_id, user_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Create a task from a template
+#[utoipa::path(
+    post,
+    path = "/api/templates/{template_id}/instantiate",
+    tag = "templates",
+    params(
+        ("template_id" = Uuid, Path, description = "Template ID")
+    ),
+    request_body = InstantiateTemplateRequest,
+    responses(
+        (status = 201, description = "Task created", body = Task),
+        (status = 400, description = "A default member no longer exists"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Template or project not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn instantiate_template(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(template_id): Path<Uuid>,
+    AppJson(payload): AppJson<InstantiateTemplateRequest>,
+) -> Result<impl IntoResponse> {
+    let base_date = payload.base_date.unwrap_or_else(Utc::now);
+
+    let task = state.template_service
+        .instantiate(template_id, user_id, base_date, payload.project_id)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(task)))
+}
+
+/// Create tasks from several templates at once, against one base date
+#[utoipa::path(
+    post,
+    path = "/api/templates/instantiate",
+    tag = "templates",
+    request_body = InstantiateTemplatesRequest,
+    responses(
+        (status = 200, description = "Outcome for each template, in request order", body = InstantiateTemplatesResponse),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "A template or the project was not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn instantiate_templates(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    AppJson(payload): AppJson<InstantiateTemplatesRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+    let base_date = payload.base_date.unwrap_or_else(Utc::now);
+
+    let response = state.template_service
+        .instantiate_many(&payload.template_ids, user_id, base_date, payload.project_id)
+        .await?;
+
+    Ok(Json(response))
+}
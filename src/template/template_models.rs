@@ -0,0 +1,171 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::task::task_models::{Task, TaskPriority, TaskRole};
+
+/// Who can see and use a template besides its owner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "text", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum TemplateVisibility {
+    /// Only the owner
+    #[default]
+    Private,
+    /// The owner and the users in `shared_with`
+    Shared,
+    /// The owner and every member of `group_id`
+    Group,
+}
+
+/// Someone added to every task created from a template.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct TemplateMember {
+    pub user_id: Uuid,
+    /// Defaults to editor
+    #[serde(default = "default_member_role")]
+    pub role: TaskRole,
+}
+
+fn default_member_role() -> TaskRole {
+    TaskRole::Editor
+}
+
+/// The editable part of a template.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct TemplateFields {
+    pub name: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub priority: Option<TaskPriority>,
+    /// Minutes from the base date to the due date
+    pub due_offset_minutes: Option<i32>,
+    /// Minutes from the base date to the reminder
+    pub reminder_offset_minutes: Option<i32>,
+    /// Expected effort in minutes
+    pub estimate_minutes: Option<i32>,
+    /// Checklist items, in order
+    pub checklist: Vec<String>,
+    #[schema(value_type = Vec<TemplateMember>)]
+    pub default_members: sqlx::types::Json<Vec<TemplateMember>>,
+    pub visibility: TemplateVisibility,
+    /// Group whose members can use the template; only for group templates
+    pub group_id: Option<Uuid>,
+    /// Users who can use the template; only for shared templates
+    pub shared_with: Vec<Uuid>,
+}
+
+impl TemplateFields {
+    /// Checks the parts of a template serde can't express.
+    pub fn check(&self) -> std::result::Result<(), &'static str> {
+        match self.visibility {
+            TemplateVisibility::Group if self.group_id.is_none() => {
+                return Err("group templates need a group_id");
+            }
+            TemplateVisibility::Private | TemplateVisibility::Shared if self.group_id.is_some() => {
+                return Err("group_id is only allowed on group templates");
+            }
+            TemplateVisibility::Private | TemplateVisibility::Group if !self.shared_with.is_empty() => {
+                return Err("shared_with is only allowed on shared templates");
+            }
+            _ => {}
+        }
+        if self.default_members.iter().any(|m| m.role == TaskRole::Owner) {
+            return Err("default members cannot be owners; whoever uses the template owns the task");
+        }
+        Ok(())
+    }
+
+    /// Due date and reminder time of a task created against `base_date`.
+    pub fn schedule(&self, base_date: DateTime<Utc>) -> (Option<DateTime<Utc>>, Option<DateTime<Utc>>) {
+        let at = |offset: Option<i32>| offset.map(|minutes| base_date + Duration::minutes(minutes as i64));
+        (at(self.due_offset_minutes), at(self.reminder_offset_minutes))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct TaskTemplate {
+    pub id: Uuid,
+    pub owner_id: Uuid,
+    #[serde(flatten)]
+    #[sqlx(flatten)]
+    pub fields: TemplateFields,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// How one template of a batch instantiation fared.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TemplateInstantiation {
+    pub template_id: Uuid,
+    pub success: bool,
+    /// Why no task was created from the template
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// The task created from the template
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task: Option<Task>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct InstantiateTemplatesResponse {
+    pub succeeded: usize,
+    pub failed: usize,
+    /// One entry per template, in request order
+    pub results: Vec<TemplateInstantiation>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn fields(visibility: TemplateVisibility) -> TemplateFields {
+        TemplateFields {
+            name: "Onboarding".to_string(),
+            title: "Set up laptop".to_string(),
+            description: None,
+            priority: None,
+            due_offset_minutes: Some(2 * 24 * 60),
+            reminder_offset_minutes: Some(-60),
+            estimate_minutes: None,
+            checklist: Vec::new(),
+            default_members: sqlx::types::Json(Vec::new()),
+            visibility,
+            group_id: None,
+            shared_with: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_schedule_is_relative_to_base_date() {
+        let base = Utc.with_ymd_and_hms(2026, 3, 2, 9, 0, 0).unwrap();
+        let (due, reminder) = fields(TemplateVisibility::Private).schedule(base);
+
+        assert_eq!(due, Some(Utc.with_ymd_and_hms(2026, 3, 4, 9, 0, 0).unwrap()));
+        assert_eq!(reminder, Some(Utc.with_ymd_and_hms(2026, 3, 2, 8, 0, 0).unwrap()));
+    }
+
+    #[test]
+    fn test_check_sharing() {
+        assert!(fields(TemplateVisibility::Private).check().is_ok());
+        assert!(fields(TemplateVisibility::Group).check().is_err());
+
+        let mut group = fields(TemplateVisibility::Group);
+        group.group_id = Some(Uuid::new_v4());
+        assert!(group.check().is_ok());
+        group.shared_with = vec![Uuid::new_v4()];
+        assert!(group.check().is_err());
+
+        let mut shared = fields(TemplateVisibility::Shared);
+        shared.shared_with = vec![Uuid::new_v4()];
+        assert!(shared.check().is_ok());
+        shared.default_members.0.push(TemplateMember {
+            user_id: Uuid::new_v4(),
+            role: TaskRole::Owner,
+        });
+        assert!(shared.check().is_err());
+    }
+}
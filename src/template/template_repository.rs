@@ -0,0 +1,136 @@
+use crate::error::Result;
+use sqlx::PgPool;
+use uuid::Uuid;
+use super::template_models::{TaskTemplate, TemplateFields};
+
+#[derive(Clone)]
+pub struct TemplateRepository {
+    pool: PgPool,
+}
+
+impl TemplateRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn create(&self, owner_id: Uuid, fields: &TemplateFields) -> Result<TaskTemplate> {
+        let template = sqlx::query_as::<_, TaskTemplate>(
+            "INSERT INTO task_templates (owner_id, name, title, description, priority, due_offset_minutes,
+                                         reminder_offset_minutes, estimate_minutes, checklist, default_members,
+                                         visibility, group_id, shared_with)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+             RETURNING *"
+        )
+        .bind(owner_id)
+        .bind(&fields.name)
+        .bind(&fields.title)
+        .bind(&fields.description)
+        .bind(fields.priority)
+        .bind(fields.due_offset_minutes)
+        .bind(fields.reminder_offset_minutes)
+        .bind(fields.estimate_minutes)
+        .bind(&fields.checklist)
+        .bind(&fields.default_members)
+        .bind(fields.visibility)
+        .bind(fields.group_id)
+        .bind(&fields.shared_with)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(template)
+    }
+
+    pub async fn find_by_id(&self, id: Uuid) -> Result<Option<TaskTemplate>> {
+        let template = sqlx::query_as::<_, TaskTemplate>("SELECT * FROM task_templates WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(template)
+    }
+
+    /// Templates the user owns, has been shared or can reach through a group.
+    pub async fn find_usable(&self, user_id: Uuid) -> Result<Vec<TaskTemplate>> {
+        let templates = sqlx::query_as::<_, TaskTemplate>(
+            "SELECT * FROM task_templates t
+             WHERE t.owner_id = $1
+             OR (t.visibility = 'shared' AND $1 = ANY(t.shared_with))
+             OR (t.visibility = 'group' AND EXISTS (
+                 SELECT 1 FROM group_members gm WHERE gm.group_id = t.group_id AND gm.user_id = $1
+             ))
+             ORDER BY t.name ASC, t.created_at ASC"
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(templates)
+    }
+
+    pub async fn can_use(&self, id: Uuid, user_id: Uuid) -> Result<bool> {
+        let can_use: bool = sqlx::query_scalar(
+            "SELECT EXISTS (
+                 SELECT 1 FROM task_templates t
+                 WHERE t.id = $1
+                 AND (t.owner_id = $2
+                      OR (t.visibility = 'shared' AND $2 = ANY(t.shared_with))
+                      OR (t.visibility = 'group' AND EXISTS (
+                          SELECT 1 FROM group_members gm WHERE gm.group_id = t.group_id AND gm.user_id = $2
+                      )))
+             )"
+        )
+        .bind(id)
+        .bind(user_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(can_use)
+    }
+
+    pub async fn update(&self, id: Uuid, fields: &TemplateFields) -> Result<TaskTemplate> {
+        let template = sqlx::query_as::<_, TaskTemplate>(
+            "UPDATE task_templates SET
+                name = $2,
+                title = $3,
+                description = $4,
+                priority = $5,
+                due_offset_minutes = $6,
+                reminder_offset_minutes = $7,
+                estimate_minutes = $8,
+                checklist = $9,
+                default_members = $10,
+                visibility = $11,
+                group_id = $12,
+                shared_with = $13,
+                updated_at = NOW()
+             WHERE id = $1
+             RETURNING *"
+        )
+        .bind(id)
+        .bind(&fields.name)
+        .bind(&fields.title)
+        .bind(&fields.description)
+        .bind(fields.priority)
+        .bind(fields.due_offset_minutes)
+        .bind(fields.reminder_offset_minutes)
+        .bind(fields.estimate_minutes)
+        .bind(&fields.checklist)
+        .bind(&fields.default_members)
+        .bind(fields.visibility)
+        .bind(fields.group_id)
+        .bind(&fields.shared_with)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(template)
+    }
+
+    pub async fn delete(&self, id: Uuid) -> Result<()> {
+        sqlx::query("DELETE FROM task_templates WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
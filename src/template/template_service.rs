@@ -0,0 +1,309 @@
+use std::collections::{BTreeMap, HashSet};
+
+use crate::checklist::checklist_models::MAX_ITEM_CHARS;
+use crate::checklist::checklist_repository::ChecklistRepository;
+use crate::error::{AppError, Result};
+use crate::group::group_repository::GroupRepository;
+use crate::task::task_dto::CreateTaskRequest;
+use crate::task::task_models::{Task, TaskRole};
+use crate::task::task_repository::TaskRepository;
+use crate::task::task_service::TaskService;
+use crate::user::user_repository::UserRepository;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use super::template_dto::{CreateTemplateRequest, UpdateTemplateRequest};
+use super::template_models::{InstantiateTemplatesResponse, TaskTemplate, TemplateFields, TemplateInstantiation};
+use super::template_repository::TemplateRepository;
+
+/// Service layer for task templates.
+///
+/// Anyone who can see a template can create tasks from it; only its owner can
+/// change or delete it.
+#[derive(Clone)]
+pub struct TemplateService {
+    repo: TemplateRepository,
+    task_repo: TaskRepository,
+    task_service: TaskService,
+    checklist_repo: ChecklistRepository,
+    group_repo: GroupRepository,
+    user_repo: UserRepository,
+}
+
+impl TemplateService {
+    pub fn new(
+        repo: TemplateRepository,
+        task_repo: TaskRepository,
+        task_service: TaskService,
+        checklist_repo: ChecklistRepository,
+        group_repo: GroupRepository,
+        user_repo: UserRepository,
+    ) -> Self {
+        Self {
+            repo,
+            task_repo,
+            task_service,
+            checklist_repo,
+            group_repo,
+            user_repo,
+        }
+    }
+
+    pub async fn create_template(&self, owner_id: Uuid, payload: CreateTemplateRequest) -> Result<TaskTemplate> {
+        let fields = TemplateFields {
+            name: payload.name,
+            title: payload.title,
+            description: payload.description,
+            priority: payload.priority,
+            due_offset_minutes: payload.due_offset_minutes,
+            reminder_offset_minutes: payload.reminder_offset_minutes,
+            estimate_minutes: payload.estimate_minutes,
+            checklist: payload.checklist,
+            default_members: sqlx::types::Json(payload.default_members),
+            visibility: payload.visibility,
+            group_id: payload.group_id,
+            shared_with: payload.shared_with,
+        };
+        let fields = self.check_fields(owner_id, fields).await?;
+
+        self.repo.create(owner_id, &fields).await
+    }
+
+    pub async fn list_templates(&self, user_id: Uuid) -> Result<Vec<TaskTemplate>> {
+        self.repo.find_usable(user_id).await
+    }
+
+    /// A template the user can use. Templates they can't see are reported as missing.
+    pub async fn get_template(&self, template_id: Uuid, user_id: Uuid) -> Result<TaskTemplate> {
+        let template = self.repo.find_by_id(template_id).await?;
+
+        match template {
+            Some(template) if self.repo.can_use(template.id, user_id).await? => Ok(template),
+            _ => Err(AppError::NotFound("Template not found".to_string())),
+        }
+    }
+
+    pub async fn update_template(
+        &self,
+        template_id: Uuid,
+        user_id: Uuid,
+        payload: UpdateTemplateRequest,
+    ) -> Result<TaskTemplate> {
+        let template = self.get_owned_template(template_id, user_id, "Only the template owner can change it").await?;
+
+        let mut fields = template.fields;
+        if let Some(name) = payload.name {
+            fields.name = name;
+        }
+        if let Some(title) = payload.title {
+            fields.title = title;
+        }
+        if payload.description.is_some() {
+            fields.description = payload.description;
+        }
+        if payload.priority.is_some() {
+            fields.priority = payload.priority;
+        }
+        if payload.due_offset_minutes.is_some() {
+            fields.due_offset_minutes = payload.due_offset_minutes;
+        }
+        if payload.reminder_offset_minutes.is_some() {
+            fields.reminder_offset_minutes = payload.reminder_offset_minutes;
+        }
+        if payload.estimate_minutes.is_some() {
+            fields.estimate_minutes = payload.estimate_minutes;
+        }
+        if let Some(checklist) = payload.checklist {
+            fields.checklist = checklist;
+        }
+        if let Some(default_members) = payload.default_members {
+            fields.default_members = sqlx::types::Json(default_members);
+        }
+        if let Some(visibility) = payload.visibility {
+            // Switching visibility drops the audience of the old one
+            fields.visibility = visibility;
+            fields.group_id = None;
+            fields.shared_with.clear();
+        }
+        if payload.group_id.is_some() {
+            fields.group_id = payload.group_id;
+        }
+        if let Some(shared_with) = payload.shared_with {
+            fields.shared_with = shared_with;
+        }
+        let fields = self.check_fields(user_id, fields).await?;
+
+        self.repo.update(template_id, &fields).await
+    }
+
+    pub async fn delete_template(&self, template_id: Uuid, user_id: Uuid) -> Result<()> {
+        self.get_owned_template(template_id, user_id, "Only the template owner can delete it").await?;
+        self.repo.delete(template_id).await
+    }
+
+    /// Creates a task from the template, with due and reminder times counted
+    /// from `base_date`.
+    pub async fn instantiate(
+        &self,
+        template_id: Uuid,
+        user_id: Uuid,
+        base_date: DateTime<Utc>,
+        project_id: Option<Uuid>,
+    ) -> Result<Task> {
+        let template = self.get_template(template_id, user_id).await?;
+        self.create_task_from(&template, user_id, base_date, project_id).await
+    }
+
+    /// Creates one task per template, in order, like `instantiate`. All templates
+    /// are looked up before any task is created; after that each template
+    /// succeeds or fails on its own, and a failed one leaves no task behind.
+    pub async fn instantiate_many(
+        &self,
+        template_ids: &[Uuid],
+        user_id: Uuid,
+        base_date: DateTime<Utc>,
+        project_id: Option<Uuid>,
+    ) -> Result<InstantiateTemplatesResponse> {
+        let mut templates = Vec::with_capacity(template_ids.len());
+        for &template_id in template_ids {
+            templates.push(self.get_template(template_id, user_id).await?);
+        }
+
+        let mut results = Vec::with_capacity(templates.len());
+        for template in templates {
+            let result = match self.create_task_from(&template, user_id, base_date, project_id).await {
+                Ok(task) => TemplateInstantiation { template_id: template.id, success: true, error: None, task: Some(task) },
+                Err(e) => TemplateInstantiation {
+                    template_id: template.id,
+                    success: false,
+                    error: Some(e.message()),
+                    task: None,
+                },
+            };
+            results.push(result);
+        }
+
+        let succeeded = results.iter().filter(|r| r.success).count();
+        Ok(InstantiateTemplatesResponse {
+            succeeded,
+            failed: results.len() - succeeded,
+            results,
+        })
+    }
+
+    /// Creates the task with its checklist and members. If any of it fails the
+    /// task is deleted again, so there is never a half-made task.
+    async fn create_task_from(
+        &self,
+        template: &TaskTemplate,
+        user_id: Uuid,
+        base_date: DateTime<Utc>,
+        project_id: Option<Uuid>,
+    ) -> Result<Task> {
+        let fields = &template.fields;
+        let (due_date, reminder_time) = fields.schedule(base_date);
+
+        // Default members may have been deleted since the template was saved
+        for member in fields.default_members.iter() {
+            if self.user_repo.find_by_id(member.user_id).await?.is_none() {
+                return Err(AppError::BadRequest(format!(
+                    "Default member {} no longer exists; update the template",
+                    member.user_id
+                )));
+            }
+        }
+
+        let task = self.task_service
+            .create_task(
+                user_id,
+                CreateTaskRequest {
+                    title: fields.title.clone(),
+                    description: fields.description.clone(),
+                    priority: fields.priority,
+                    due_date,
+                    reminder_time,
+                    estimate_minutes: fields.estimate_minutes,
+                    recurrence_rule: None,
+                    project_id,
+                },
+            )
+            .await?;
+
+        if let Err(e) = self.fill_in(&task, fields, user_id).await {
+            let _ = self.task_service.delete_task(user_id, task.id).await;
+            let _ = self.task_repo.purge(&[task.id]).await;
+            return Err(e);
+        }
+
+        let _ = self.task_repo.log_task_activity(
+            task.id,
+            user_id,
+            "created_from_template",
+            Some(serde_json::json!({"template_id": template.id, "template_name": fields.name})),
+        ).await;
+
+        Ok(task)
+    }
+
+    /// Adds the template's checklist and default members to a task created from it.
+    async fn fill_in(&self, task: &Task, fields: &TemplateFields, user_id: Uuid) -> Result<()> {
+        if !fields.checklist.is_empty() {
+            let items: Vec<(String, bool)> = fields.checklist.iter().map(|item| (item.clone(), false)).collect();
+            self.checklist_repo.append(task.id, &items, user_id).await?;
+        }
+
+        // Whoever creates the task owns it, so they can add everyone else
+        let mut members_by_role: BTreeMap<TaskRole, Vec<Uuid>> = BTreeMap::new();
+        for member in fields.default_members.iter().filter(|m| m.user_id != user_id) {
+            members_by_role.entry(member.role).or_default().push(member.user_id);
+        }
+        for (role, user_ids) in members_by_role {
+            self.task_service.share_task(task.id, user_ids, role, user_id).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn get_owned_template(&self, template_id: Uuid, user_id: Uuid, denied: &str) -> Result<TaskTemplate> {
+        let template = self.get_template(template_id, user_id).await?;
+        if template.owner_id != user_id {
+            return Err(AppError::Forbidden(denied.to_string()));
+        }
+        Ok(template)
+    }
+
+    /// Validates a template before it is saved, trimming its checklist.
+    async fn check_fields(&self, owner_id: Uuid, mut fields: TemplateFields) -> Result<TemplateFields> {
+        fields.check().map_err(|e| AppError::BadRequest(e.to_string()))?;
+
+        fields.checklist = fields.checklist.iter().map(|item| item.trim().to_string()).collect();
+        if fields.checklist.iter().any(|item| item.is_empty() || item.chars().count() > MAX_ITEM_CHARS) {
+            return Err(AppError::BadRequest(format!(
+                "Checklist items must be between 1 and {} characters",
+                MAX_ITEM_CHARS
+            )));
+        }
+
+        if let Some(group_id) = fields.group_id {
+            if !self.group_repo.is_member(group_id, owner_id).await? {
+                return Err(AppError::Forbidden("You are not a member of this group".to_string()));
+            }
+        }
+
+        fields.shared_with.sort_unstable();
+        fields.shared_with.dedup();
+        fields.shared_with.retain(|&id| id != owner_id);
+
+        let mut seen = HashSet::new();
+        if !fields.default_members.iter().all(|m| seen.insert(m.user_id)) {
+            return Err(AppError::BadRequest("Each default member can be listed only once".to_string()));
+        }
+
+        for &id in fields.shared_with.iter().chain(seen.iter()) {
+            if self.user_repo.find_by_id(id).await?.is_none() {
+                return Err(AppError::BadRequest(format!("User {} not found", id)));
+            }
+        }
+
+        Ok(fields)
+    }
+}
@@ -0,0 +1,6 @@
+// Declare submodules
+pub mod time_entry_models;
+pub mod time_entry_dto;
+pub mod time_entry_repository;
+pub mod time_entry_service;
+pub mod time_entry_handlers;
@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateTimeEntryRequest {
+    /// When the work started (defaults to `duration_minutes` ago)
+    pub started_at: Option<DateTime<Utc>>,
+    #[validate(range(min = 1, max = 1440))]
+    pub duration_minutes: i32,
+    #[validate(length(max = 1000))]
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateTimeEntryRequest {
+    pub started_at: Option<DateTime<Utc>>,
+    /// Only for finished entries
+    #[validate(range(min = 1, max = 1440))]
+    pub duration_minutes: Option<i32>,
+    #[validate(length(max = 1000))]
+    pub note: Option<String>,
+}
@@ -0,0 +1,250 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    error::Result,
+    middleware::{AppJson, AuthUser},
+    state::AppState,
+    time_entry::time_entry_dto::{CreateTimeEntryRequest, UpdateTimeEntryRequest},
+};
+
+#[derive(Debug, Deserialize)]
+pub struct TimeReportQuery {
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+}
+
+/// Start a timer on a task
+#[utoipa::path(
+    post,
+    path = "/api/tasks/{task_id}/timer/start",
+    tag = "time-tracking",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID")
+    ),
+    responses(
+        (status = 201, description = "Timer started", body = TimeEntry),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Task not found or read-only for you"),
+        (status = 409, description = "Another timer is already running")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn start_timer(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(task_id): Path<Uuid>,
+) -> Result<impl IntoResponse> {
+    let entry = state.time_entry_service.start_timer(task_id, user_id).await?;
+
+    Ok((StatusCode::CREATED, Json(entry)))
+}
+
+/// Stop your running timer on a task
+#[utoipa::path(
+    post,
+    path = "/api/tasks/{task_id}/timer/stop",
+    tag = "time-tracking",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID")
+    ),
+    responses(
+        (status = 200, description = "Timer stopped", body = TimeEntry),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "No running timer on this task")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn stop_timer(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(task_id): Path<Uuid>,
+) -> Result<impl IntoResponse> {
+    let entry = state.time_entry_service.stop_timer(task_id, user_id).await?;
+
+    Ok((StatusCode::OK, Json(entry)))
+}
+
+/// Log work on a task without a timer
+#[utoipa::path(
+    post,
+    path = "/api/tasks/{task_id}/time-entries",
+    tag = "time-tracking",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID")
+    ),
+    request_body = CreateTimeEntryRequest,
+    responses(
+        (status = 201, description = "Time logged", body = TimeEntry),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Task not found or read-only for you")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create_time_entry(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(task_id): Path<Uuid>,
+    AppJson(payload): AppJson<CreateTimeEntryRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+
+    let entry = state.time_entry_service.log_time(task_id, user_id, payload).await?;
+
+    Ok((StatusCode::CREATED, Json(entry)))
+}
+
+/// Get the time logged on a task, per user and in total
+#[utoipa::path(
+    get,
+    path = "/api/tasks/{task_id}/time-entries",
+    tag = "time-tracking",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID")
+    ),
+    responses(
+        (status = 200, description = "Task time report", body = TaskTimeReport),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Task not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_task_time(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(task_id): Path<Uuid>,
+) -> Result<impl IntoResponse> {
+    let report = state.time_entry_service.get_task_report(task_id, user_id).await?;
+
+    Ok((StatusCode::OK, Json(report)))
+}
+
+/// Get your time entries and per-task totals
+#[utoipa::path(
+    get,
+    path = "/api/time-entries",
+    tag = "time-tracking",
+    params(
+        ("from" = Option<DateTime<Utc>>, Query, description = "Only entries started at or after this time"),
+        ("to" = Option<DateTime<Utc>>, Query, description = "Only entries started before this time")
+    ),
+    responses(
+        (status = 200, description = "User time report", body = UserTimeReport),
+        (status = 400, description = "Invalid date range"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_my_time(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Query(query): Query<TimeReportQuery>,
+) -> Result<impl IntoResponse> {
+    let report = state.time_entry_service
+        .get_user_report(user_id, query.from, query.to)
+        .await?;
+
+    Ok((StatusCode::OK, Json(report)))
+}
+
+/// Get your running timer, if any
+#[utoipa::path(
+    get,
+    path = "/api/time-entries/running",
+    tag = "time-tracking",
+    responses(
+        (status = 200, description = "The running timer, or null", body = Option<TimeEntry>),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_running_timer(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+) -> Result<impl IntoResponse> {
+    let entry = state.time_entry_service.get_running_timer(user_id).await?;
+
+    Ok((StatusCode::OK, Json(entry)))
+}
+
+/// Update one of your time entries
+#[utoipa::path(
+    put,
+    path = "/api/time-entries/{entry_id}",
+    tag = "time-tracking",
+    params(
+        ("entry_id" = Uuid, Path, description = "Time entry ID")
+    ),
+    request_body = UpdateTimeEntryRequest,
+    responses(
+        (status = 200, description = "Time entry updated", body = TimeEntry),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Not your entry"),
+        (status = 404, description = "Time entry not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn update_time_entry(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(entry_id): Path<Uuid>,
+    AppJson(payload): AppJson<UpdateTimeEntryRequest>,
+) -> Result<impl IntoResponse> {
+    payload.validate()?;
+
+    let entry = state.time_entry_service.update_entry(entry_id, user_id, payload).await?;
+
+    Ok((StatusCode::OK, Json(entry)))
+}
+
+/// Delete one of your time entries
+#[utoipa::path(
+    delete,
+    path = "/api/time-entries/{entry_id}",
+    tag = "time-tracking",
+    params(
+        ("entry_id" = Uuid, Path, description = "Time entry ID")
+    ),
+    responses(
+        (status = 204, description = "Time entry deleted"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Not your entry"),
+        (status = 404, description = "Time entry not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_time_entry(
+    State(state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(entry_id): Path<Uuid>,
+) -> Result<impl IntoResponse> {
+    state.time_entry_service.delete_entry(entry_id, user_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
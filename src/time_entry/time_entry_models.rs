@@ -0,0 +1,87 @@
+use chrono::{DateTime, Datelike, Duration, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct TimeEntry {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub user_id: Uuid,
+    pub started_at: DateTime<Utc>,
+    /// `None` while the timer is running
+    pub ended_at: Option<DateTime<Utc>>,
+    pub note: Option<String>,
+    /// Logged by hand rather than with a timer
+    pub is_manual: bool,
+    /// Elapsed time; for a running timer, up to now
+    pub duration_seconds: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct UserTimeTotal {
+    pub user_id: Uuid,
+    pub username: String,
+    pub total_seconds: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct TaskTimeTotal {
+    pub task_id: Uuid,
+    pub title: String,
+    pub total_seconds: i64,
+}
+
+/// Time logged on one task, by everyone.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TaskTimeReport {
+    pub task_id: Uuid,
+    pub estimate_minutes: Option<i32>,
+    pub total_seconds: i64,
+    pub per_user: Vec<UserTimeTotal>,
+    pub entries: Vec<TimeEntry>,
+}
+
+/// Time logged by one user across tasks, within a date range.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UserTimeReport {
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub total_seconds: i64,
+    pub per_task: Vec<TaskTimeTotal>,
+    pub entries: Vec<TimeEntry>,
+}
+
+/// Start of the current day, ISO week (Monday) and month, in UTC.
+pub fn period_starts(now: DateTime<Utc>) -> (DateTime<Utc>, DateTime<Utc>, DateTime<Utc>) {
+    let today = now.date_naive();
+    let day_start = Utc.from_utc_datetime(&today.and_hms_opt(0, 0, 0).unwrap_or_default());
+    let week_start = day_start - Duration::days(today.weekday().num_days_from_monday() as i64);
+    let month_start = day_start - Duration::days(today.day0() as i64);
+
+    (day_start, week_start, month_start)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn period_starts_align_to_day_week_and_month() {
+        // Thursday
+        let now = Utc.with_ymd_and_hms(2026, 10, 15, 17, 30, 0).unwrap();
+        let (day, week, month) = period_starts(now);
+
+        assert_eq!(day, Utc.with_ymd_and_hms(2026, 10, 15, 0, 0, 0).unwrap());
+        assert_eq!(week, Utc.with_ymd_and_hms(2026, 10, 12, 0, 0, 0).unwrap());
+        assert_eq!(month, Utc.with_ymd_and_hms(2026, 10, 1, 0, 0, 0).unwrap());
+
+        // A Monday that starts a month
+        let (day, week, month) = period_starts(Utc.with_ymd_and_hms(2026, 6, 1, 8, 0, 0).unwrap());
+        assert_eq!(day, week);
+        assert_eq!(day, month);
+    }
+}
@@ -0,0 +1,257 @@
+use crate::error::{AppError, Result};
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+use super::time_entry_models::{TaskTimeTotal, TimeEntry, UserTimeTotal};
+
+const DURATION_SECONDS: &str =
+    "EXTRACT(EPOCH FROM (COALESCE(ended_at, NOW()) - started_at))::BIGINT AS duration_seconds";
+
+/// Seconds of an entry that fall after `since` (a bind placeholder)
+fn seconds_since(since: &str) -> String {
+    format!(
+        "COALESCE(SUM(GREATEST(EXTRACT(EPOCH FROM (COALESCE(ended_at, NOW()) - GREATEST(started_at, {}))), 0)), 0)::BIGINT",
+        since
+    )
+}
+
+#[derive(Clone)]
+pub struct TimeEntryRepository {
+    pool: PgPool,
+}
+
+impl TimeEntryRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Starts a timer. Fails with a conflict if the user already has one running.
+    pub async fn start_timer(&self, task_id: Uuid, user_id: Uuid) -> Result<TimeEntry> {
+        let entry = sqlx::query_as::<_, TimeEntry>(&format!(
+            "INSERT INTO time_entries (task_id, user_id, started_at)
+             VALUES ($1, $2, NOW())
+             RETURNING *, {}",
+            DURATION_SECONDS
+        ))
+        .bind(task_id)
+        .bind(user_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| match &e {
+            sqlx::Error::Database(db) if db.constraint() == Some("idx_time_entries_one_running_timer") => {
+                AppError::Conflict("You already have a running timer; stop it first".to_string())
+            }
+            _ => AppError::Database(e),
+        })?;
+
+        Ok(entry)
+    }
+
+    pub async fn find_running(&self, user_id: Uuid) -> Result<Option<TimeEntry>> {
+        let entry = sqlx::query_as::<_, TimeEntry>(&format!(
+            "SELECT *, {} FROM time_entries WHERE user_id = $1 AND ended_at IS NULL",
+            DURATION_SECONDS
+        ))
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(entry)
+    }
+
+    /// Stops the user's running timer on the task, if there is one.
+    pub async fn stop_timer(&self, task_id: Uuid, user_id: Uuid) -> Result<Option<TimeEntry>> {
+        let entry = sqlx::query_as::<_, TimeEntry>(&format!(
+            "UPDATE time_entries SET ended_at = NOW(), updated_at = NOW()
+             WHERE task_id = $1 AND user_id = $2 AND ended_at IS NULL
+             RETURNING *, {}",
+            DURATION_SECONDS
+        ))
+        .bind(task_id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(entry)
+    }
+
+    pub async fn create_manual(
+        &self,
+        task_id: Uuid,
+        user_id: Uuid,
+        started_at: DateTime<Utc>,
+        ended_at: DateTime<Utc>,
+        note: Option<&str>,
+    ) -> Result<TimeEntry> {
+        let entry = sqlx::query_as::<_, TimeEntry>(&format!(
+            "INSERT INTO time_entries (task_id, user_id, started_at, ended_at, note, is_manual)
+             VALUES ($1, $2, $3, $4, $5, TRUE)
+             RETURNING *, {}",
+            DURATION_SECONDS
+        ))
+        .bind(task_id)
+        .bind(user_id)
+        .bind(started_at)
+        .bind(ended_at)
+        .bind(note)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(entry)
+    }
+
+    pub async fn find_by_id(&self, id: Uuid) -> Result<Option<TimeEntry>> {
+        let entry = sqlx::query_as::<_, TimeEntry>(&format!(
+            "SELECT *, {} FROM time_entries WHERE id = $1",
+            DURATION_SECONDS
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(entry)
+    }
+
+    pub async fn update(
+        &self,
+        id: Uuid,
+        started_at: DateTime<Utc>,
+        ended_at: Option<DateTime<Utc>>,
+        note: Option<&str>,
+    ) -> Result<TimeEntry> {
+        let entry = sqlx::query_as::<_, TimeEntry>(&format!(
+            "UPDATE time_entries SET
+                started_at = $2,
+                ended_at = $3,
+                note = COALESCE($4, note),
+                updated_at = NOW()
+             WHERE id = $1
+             RETURNING *, {}",
+            DURATION_SECONDS
+        ))
+        .bind(id)
+        .bind(started_at)
+        .bind(ended_at)
+        .bind(note)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(entry)
+    }
+
+    pub async fn delete(&self, id: Uuid) -> Result<()> {
+        sqlx::query("DELETE FROM time_entries WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn find_for_task(&self, task_id: Uuid) -> Result<Vec<TimeEntry>> {
+        let entries = sqlx::query_as::<_, TimeEntry>(&format!(
+            "SELECT *, {} FROM time_entries WHERE task_id = $1 ORDER BY started_at DESC",
+            DURATION_SECONDS
+        ))
+        .bind(task_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(entries)
+    }
+
+    pub async fn task_totals_by_user(&self, task_id: Uuid) -> Result<Vec<UserTimeTotal>> {
+        let totals = sqlx::query_as::<_, UserTimeTotal>(
+            "SELECT u.id AS user_id, u.username,
+                    SUM(EXTRACT(EPOCH FROM (COALESCE(e.ended_at, NOW()) - e.started_at)))::BIGINT AS total_seconds
+             FROM time_entries e
+             INNER JOIN users u ON u.id = e.user_id
+             WHERE e.task_id = $1
+             GROUP BY u.id, u.username
+             ORDER BY total_seconds DESC"
+        )
+        .bind(task_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(totals)
+    }
+
+    /// The user's entries that started within `[from, to)`; either bound may be open.
+    pub async fn find_for_user(
+        &self,
+        user_id: Uuid,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> Result<Vec<TimeEntry>> {
+        let entries = sqlx::query_as::<_, TimeEntry>(&format!(
+            "SELECT *, {} FROM time_entries
+             WHERE user_id = $1
+             AND ($2::timestamptz IS NULL OR started_at >= $2)
+             AND ($3::timestamptz IS NULL OR started_at < $3)
+             ORDER BY started_at DESC",
+            DURATION_SECONDS
+        ))
+        .bind(user_id)
+        .bind(from)
+        .bind(to)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(entries)
+    }
+
+    pub async fn user_totals_by_task(
+        &self,
+        user_id: Uuid,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> Result<Vec<TaskTimeTotal>> {
+        let totals = sqlx::query_as::<_, TaskTimeTotal>(
+            "SELECT t.id AS task_id, t.title,
+                    SUM(EXTRACT(EPOCH FROM (COALESCE(e.ended_at, NOW()) - e.started_at)))::BIGINT AS total_seconds
+             FROM time_entries e
+             INNER JOIN tasks t ON t.id = e.task_id
+             WHERE e.user_id = $1
+             AND ($2::timestamptz IS NULL OR e.started_at >= $2)
+             AND ($3::timestamptz IS NULL OR e.started_at < $3)
+             GROUP BY t.id, t.title
+             ORDER BY total_seconds DESC"
+        )
+        .bind(user_id)
+        .bind(from)
+        .bind(to)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(totals)
+    }
+
+    /// Seconds tracked since each of the given instants, plus the all-time total.
+    /// Entries straddling an instant only count the part after it.
+    pub async fn tracked_seconds(
+        &self,
+        user_id: Uuid,
+        day_start: DateTime<Utc>,
+        week_start: DateTime<Utc>,
+        month_start: DateTime<Utc>,
+    ) -> Result<(i64, i64, i64, i64)> {
+        let totals: (i64, i64, i64, i64) = sqlx::query_as(&format!(
+            "SELECT {}, {}, {},
+                    COALESCE(SUM(EXTRACT(EPOCH FROM (COALESCE(ended_at, NOW()) - started_at))), 0)::BIGINT
+             FROM time_entries
+             WHERE user_id = $1",
+            seconds_since("$2"),
+            seconds_since("$3"),
+            seconds_since("$4"),
+        ))
+        .bind(user_id)
+        .bind(day_start)
+        .bind(week_start)
+        .bind(month_start)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(totals)
+    }
+}
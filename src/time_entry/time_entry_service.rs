@@ -0,0 +1,176 @@
+use crate::error::{AppError, Result};
+use crate::task::task_models::{Task, TaskPermission};
+use crate::task::task_repository::TaskRepository;
+use crate::task::task_service::TaskService;
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+use super::time_entry_dto::{CreateTimeEntryRequest, UpdateTimeEntryRequest};
+use super::time_entry_models::{TaskTimeReport, TimeEntry, UserTimeReport};
+use super::time_entry_repository::TimeEntryRepository;
+
+#[derive(Clone)]
+pub struct TimeEntryService {
+    repo: TimeEntryRepository,
+    task_repo: TaskRepository,
+    task_service: TaskService,
+}
+
+impl TimeEntryService {
+    pub fn new(repo: TimeEntryRepository, task_repo: TaskRepository, task_service: TaskService) -> Self {
+        Self { repo, task_repo, task_service }
+    }
+
+    /// Starts the user's timer on a task they can edit. Only one timer can run per user.
+    pub async fn start_timer(&self, task_id: Uuid, user_id: Uuid) -> Result<TimeEntry> {
+        self.task_service.require_permission(task_id, user_id, TaskPermission::Edit).await?;
+
+        if let Some(running) = self.repo.find_running(user_id).await? {
+            return Err(AppError::Conflict(format!(
+                "You already have a running timer on task {}; stop it first",
+                running.task_id
+            )));
+        }
+
+        self.repo.start_timer(task_id, user_id).await
+    }
+
+    /// Stops the user's running timer on a task and logs the time to the task's activity.
+    /// Only their own timer can be stopped, so there is no access check: someone
+    /// removed from the task, or whose task was trashed, can still stop the clock.
+    pub async fn stop_timer(&self, task_id: Uuid, user_id: Uuid) -> Result<TimeEntry> {
+        let entry = self.repo
+            .stop_timer(task_id, user_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("No running timer on this task".to_string()))?;
+
+        let _ = self.task_repo.log_task_activity(
+            task_id,
+            user_id,
+            "timer_stopped",
+            Some(serde_json::json!({
+                "time_entry_id": entry.id,
+                "duration_seconds": entry.duration_seconds,
+            })),
+        ).await;
+
+        Ok(entry)
+    }
+
+    pub async fn get_running_timer(&self, user_id: Uuid) -> Result<Option<TimeEntry>> {
+        self.repo.find_running(user_id).await
+    }
+
+    /// Logs work done without a timer on a task the user can edit.
+    pub async fn log_time(&self, task_id: Uuid, user_id: Uuid, payload: CreateTimeEntryRequest) -> Result<TimeEntry> {
+        self.task_service.require_permission(task_id, user_id, TaskPermission::Edit).await?;
+
+        let duration = Duration::minutes(payload.duration_minutes as i64);
+        let started_at = payload.started_at.unwrap_or_else(|| Utc::now() - duration);
+        let ended_at = started_at + duration;
+        if ended_at > Utc::now() + Duration::minutes(1) {
+            return Err(AppError::BadRequest("Logged work cannot end in the future".to_string()));
+        }
+
+        let entry = self.repo
+            .create_manual(task_id, user_id, started_at, ended_at, payload.note.as_deref())
+            .await?;
+
+        let _ = self.task_repo.log_task_activity(
+            task_id,
+            user_id,
+            "time_logged",
+            Some(serde_json::json!({
+                "time_entry_id": entry.id,
+                "duration_seconds": entry.duration_seconds,
+            })),
+        ).await;
+
+        Ok(entry)
+    }
+
+    /// Only the person who logged an entry can change it.
+    pub async fn update_entry(&self, entry_id: Uuid, user_id: Uuid, payload: UpdateTimeEntryRequest) -> Result<TimeEntry> {
+        let entry = self.get_own_entry(entry_id, user_id).await?;
+
+        let started_at = payload.started_at.unwrap_or(entry.started_at);
+        let ended_at = match (entry.ended_at, payload.duration_minutes) {
+            (None, Some(_)) => {
+                return Err(AppError::BadRequest("Stop the timer before changing its duration".to_string()));
+            }
+            (None, None) => None,
+            (Some(ended_at), None) => Some(started_at + (ended_at - entry.started_at)),
+            (Some(_), Some(minutes)) => Some(started_at + Duration::minutes(minutes as i64)),
+        };
+        if started_at > Utc::now() || ended_at.is_some_and(|end| end > Utc::now() + Duration::minutes(1)) {
+            return Err(AppError::BadRequest("Logged work cannot end in the future".to_string()));
+        }
+
+        self.repo.update(entry_id, started_at, ended_at, payload.note.as_deref()).await
+    }
+
+    pub async fn delete_entry(&self, entry_id: Uuid, user_id: Uuid) -> Result<()> {
+        self.get_own_entry(entry_id, user_id).await?;
+        self.repo.delete(entry_id).await
+    }
+
+    /// Everyone's time on a task, with the estimate for comparison.
+    pub async fn get_task_report(&self, task_id: Uuid, user_id: Uuid) -> Result<TaskTimeReport> {
+        let task = self.get_accessible_task(task_id, user_id).await?;
+
+        let per_user = self.repo.task_totals_by_user(task_id).await?;
+        let entries = self.repo.find_for_task(task_id).await?;
+
+        Ok(TaskTimeReport {
+            task_id,
+            estimate_minutes: task.estimate_minutes,
+            total_seconds: per_user.iter().map(|u| u.total_seconds).sum(),
+            per_user,
+            entries,
+        })
+    }
+
+    /// The user's own time across tasks within an optional date range.
+    pub async fn get_user_report(
+        &self,
+        user_id: Uuid,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> Result<UserTimeReport> {
+        if let (Some(from), Some(to)) = (from, to) {
+            if from >= to {
+                return Err(AppError::BadRequest("'from' must be before 'to'".to_string()));
+            }
+        }
+
+        let per_task = self.repo.user_totals_by_task(user_id, from, to).await?;
+        let entries = self.repo.find_for_user(user_id, from, to).await?;
+
+        Ok(UserTimeReport {
+            from,
+            to,
+            total_seconds: per_task.iter().map(|t| t.total_seconds).sum(),
+            per_task,
+            entries,
+        })
+    }
+
+    async fn get_accessible_task(&self, task_id: Uuid, user_id: Uuid) -> Result<Task> {
+        self.task_repo
+            .find_by_id_with_access(task_id, user_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Task not found".to_string()))
+    }
+
+    async fn get_own_entry(&self, entry_id: Uuid, user_id: Uuid) -> Result<TimeEntry> {
+        let entry = self.repo
+            .find_by_id(entry_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Time entry not found".to_string()))?;
+
+        if entry.user_id != user_id {
+            return Err(AppError::Forbidden("You can only change your own time entries".to_string()));
+        }
+
+        Ok(entry)
+    }
+}
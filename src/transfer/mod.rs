@@ -0,0 +1,8 @@
+// Declare submodules
+pub mod transfer_models;
+pub mod transfer_dto;
+pub mod transfer_repository;
+pub mod transfer_service;
+pub mod transfer_handlers;
+pub mod trello;
+pub mod todoist;
@@ -0,0 +1,250 @@
+//! Reads a Todoist export in the Sync API's JSON shape (`sections`, `items`,
+//! `notes`, `collaborators`) into an [`ImportPlan`].
+//!
+//! Sections become statuses by name and items become tasks. Todoist
+//! subtasks become checklist items on their parent, so a task and its steps
+//! stay together. Priorities run the other way round from the app: 4 is the
+//! most urgent.
+
+use std::collections::{HashMap, HashSet};
+
+use serde::Deserialize;
+
+use crate::task::task_models::{TaskPriority, TaskStatus};
+use super::transfer_models::{
+    checklist_text, imported_comment, parse_import_time, ImportPlan, ImportRow, PlannedLabel, PlannedMember,
+    PlannedTask,
+};
+
+/// Older exports use numeric IDs, newer ones strings.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(untagged)]
+pub enum TodoistId {
+    Text(String),
+    Number(i64),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TodoistExport {
+    #[serde(default)]
+    pub sections: Vec<TodoistSection>,
+    #[serde(default)]
+    pub items: Vec<TodoistItem>,
+    #[serde(default)]
+    pub notes: Vec<TodoistNote>,
+    #[serde(default)]
+    pub collaborators: Vec<TodoistCollaborator>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TodoistSection {
+    pub id: TodoistId,
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TodoistItem {
+    pub id: TodoistId,
+    pub content: String,
+    #[serde(default)]
+    pub description: String,
+    pub section_id: Option<TodoistId>,
+    pub parent_id: Option<TodoistId>,
+    #[serde(default)]
+    pub checked: bool,
+    /// 1 (normal) to 4 (urgent)
+    #[serde(default = "default_priority")]
+    pub priority: u8,
+    pub due: Option<TodoistDue>,
+    /// Label names
+    #[serde(default)]
+    pub labels: Vec<String>,
+    pub responsible_uid: Option<TodoistId>,
+    #[serde(default)]
+    pub child_order: i64,
+}
+
+fn default_priority() -> u8 {
+    1
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TodoistDue {
+    /// `YYYY-MM-DD`, or a date and time (with `Z` when it isn't floating)
+    pub date: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TodoistNote {
+    pub item_id: TodoistId,
+    pub content: String,
+    pub posted_uid: Option<TodoistId>,
+    pub posted_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TodoistCollaborator {
+    pub id: TodoistId,
+    #[serde(default)]
+    pub full_name: String,
+    #[serde(default)]
+    pub email: String,
+}
+
+fn priority(priority: u8) -> TaskPriority {
+    match priority {
+        4 => TaskPriority::Urgent,
+        3 => TaskPriority::High,
+        2 => TaskPriority::Medium,
+        _ => TaskPriority::Low,
+    }
+}
+
+pub fn plan(export: TodoistExport) -> ImportPlan {
+    let mut plan = ImportPlan::default();
+
+    let sections: HashMap<&TodoistId, &str> = export.sections.iter().map(|s| (&s.id, s.name.as_str())).collect();
+    let collaborators: HashMap<&TodoistId, &TodoistCollaborator> =
+        export.collaborators.iter().map(|c| (&c.id, c)).collect();
+    let item_ids: HashSet<&TodoistId> = export.items.iter().map(|i| &i.id).collect();
+
+    let mut items: Vec<&TodoistItem> = export.items.iter().collect();
+    items.sort_by_key(|item| item.child_order);
+
+    // Subtasks whose parent is in the export; the rest are tasks of their own
+    let mut subtasks: HashMap<&TodoistId, Vec<&TodoistItem>> = HashMap::new();
+    let mut top_level = Vec::new();
+    for item in items {
+        match &item.parent_id {
+            Some(parent_id) if item_ids.contains(parent_id) => subtasks.entry(parent_id).or_default().push(item),
+            _ => top_level.push(item),
+        }
+    }
+
+    let mut notes: HashMap<&TodoistId, Vec<&TodoistNote>> = HashMap::new();
+    for note in &export.notes {
+        notes.entry(&note.item_id).or_default().push(note);
+    }
+
+    for item in top_level {
+        let mut status = match item.section_id.as_ref().and_then(|id| sections.get(id)) {
+            Some(section) => plan.map_status(section),
+            None => TaskStatus::Pending,
+        };
+        if item.checked {
+            status = TaskStatus::Completed;
+        }
+
+        // Steps nested deeper than one level are flattened into the same checklist
+        let mut checklist = Vec::new();
+        let mut pending: Vec<&TodoistItem> = subtasks.remove(&item.id).unwrap_or_default();
+        pending.reverse();
+        while let Some(step) = pending.pop() {
+            if let Some(text) = checklist_text(&step.content) {
+                checklist.push((text, step.checked));
+            }
+            let mut children = subtasks.remove(&step.id).unwrap_or_default();
+            children.reverse();
+            pending.extend(children);
+        }
+
+        let comments = notes
+            .remove(&item.id)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|note| !note.content.trim().is_empty())
+            .map(|note| {
+                let author = note
+                    .posted_uid
+                    .as_ref()
+                    .and_then(|uid| collaborators.get(uid))
+                    .map(|c| c.full_name.as_str());
+                let posted_at = note.posted_at.as_deref().and_then(parse_import_time);
+                imported_comment(author, posted_at, &note.content)
+            })
+            .collect();
+
+        let members = item
+            .responsible_uid
+            .as_ref()
+            .and_then(|uid| collaborators.get(uid))
+            .map(|c| PlannedMember {
+                name: if c.full_name.is_empty() { c.email.clone() } else { c.full_name.clone() },
+                username: None,
+                email: Some(c.email.clone()).filter(|e| !e.is_empty()),
+            })
+            .into_iter()
+            .collect();
+
+        plan.tasks.push(PlannedTask {
+            row: ImportRow {
+                title: Some(item.content.clone()),
+                description: Some(item.description.clone()).filter(|d| !d.trim().is_empty()),
+                status: Some(status.to_string()),
+                priority: Some(priority(item.priority).to_string()),
+                due_date: item.due.as_ref().and_then(|due| parse_import_time(&due.date)),
+                reminder_time: None,
+                estimate_minutes: None,
+            },
+            labels: item
+                .labels
+                .iter()
+                .filter(|name| !name.trim().is_empty())
+                .map(|name| PlannedLabel {
+                    name: name.trim().to_string(),
+                    color: None,
+                })
+                .collect(),
+            checklist,
+            comments,
+            members,
+        });
+    }
+
+    plan
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXPORT: &str = r#"{
+        "sections": [{"id": "s1", "name": "In progress"}, {"id": 7, "name": "Someday"}],
+        "collaborators": [{"id": "u1", "full_name": "Grace Hopper", "email": "grace@example.com"}],
+        "items": [
+            {"id": "i1", "content": "Write spec", "description": "v2", "section_id": "s1", "priority": 4,
+             "due": {"date": "2026-06-01T09:00:00Z"}, "labels": ["docs"], "responsible_uid": "u1", "child_order": 1},
+            {"id": "i2", "content": "Outline", "parent_id": "i1", "checked": true, "child_order": 1},
+            {"id": "i3", "content": "Details", "parent_id": "i2", "child_order": 1},
+            {"id": "i4", "content": "Review", "parent_id": "i1", "child_order": 2},
+            {"id": 5, "content": "Old idea", "section_id": 7, "due": {"date": "2026-07-01"}, "child_order": 2},
+            {"id": "i6", "content": "Orphan", "parent_id": "gone", "checked": true, "child_order": 3}
+        ],
+        "notes": [{"item_id": "i1", "content": "Looks good", "posted_uid": "u1", "posted_at": "2026-05-01T08:00:00.000000Z"}]
+    }"#;
+
+    #[test]
+    fn test_plan_maps_export() {
+        let plan = plan(serde_json::from_str(EXPORT).unwrap());
+
+        let titles: Vec<_> = plan.tasks.iter().map(|t| t.row.title.clone().unwrap()).collect();
+        assert_eq!(titles, ["Write spec", "Old idea", "Orphan"]);
+        assert_eq!(plan.status_mapping.get("In progress").map(String::as_str), Some("InProgress"));
+        assert_eq!(plan.status_mapping.get("Someday").map(String::as_str), Some("Pending"));
+
+        let spec = &plan.tasks[0];
+        assert_eq!(spec.row.status.as_deref(), Some("InProgress"));
+        assert_eq!(spec.row.priority.as_deref(), Some("Urgent"));
+        assert_eq!(
+            spec.checklist,
+            vec![("Outline".to_string(), true), ("Details".to_string(), false), ("Review".to_string(), false)]
+        );
+        assert_eq!(spec.members[0].email.as_deref(), Some("grace@example.com"));
+        assert!(spec.comments[0].starts_with("Grace Hopper wrote on 2026-05-01 08:00 UTC"));
+        assert_eq!(spec.labels[0].name, "docs");
+
+        assert_eq!(plan.tasks[1].row.due_date, Some("2026-07-01T00:00:00Z".parse().unwrap()));
+        assert_eq!(plan.tasks[1].row.priority.as_deref(), Some("Low"));
+        assert_eq!(plan.tasks[2].row.status.as_deref(), Some("Completed"));
+    }
+}
@@ -0,0 +1,27 @@
+use serde::Deserialize;
+use uuid::Uuid;
+
+use super::transfer_models::TransferFormat;
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ExportQuery {
+    /// `json` (default) or `csv`
+    pub format: Option<TransferFormat>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ImportQuery {
+    /// `json` (default) or `csv`
+    pub format: Option<TransferFormat>,
+    /// Validate every row without creating anything
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Project to add the imported tasks to
+    pub project_id: Option<Uuid>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ImportJobQuery {
+    /// Project to add the imported tasks to
+    pub project_id: Option<Uuid>,
+}
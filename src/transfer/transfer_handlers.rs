@@ -0,0 +1,213 @@
+use axum::{
+    body::Body,
+    extract::{Path, Query, State},
+    http::{header, HeaderValue, StatusCode},
+    response::IntoResponse,
+    Extension, Json,
+};
+use uuid::Uuid;
+
+use crate::{
+    error::Result,
+    state::AppState,
+    transfer::transfer_dto::{ExportQuery, ImportJobQuery, ImportQuery},
+    transfer::transfer_models::ImportSource,
+};
+
+/// Export all tasks you have access to
+///
+/// Streams every task with all its fields, label names, members and activity
+/// history. CSV exports write the nested values as JSON.
+#[utoipa::path(
+    get,
+    path = "/api/tasks/export",
+    params(
+        ("format" = Option<TransferFormat>, Query, description = "`json` (default) or `csv`")
+    ),
+    responses(
+        (status = 200, description = "JSON array of tasks, or a CSV file with a header row", body = [ExportedTask]),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "tasks",
+    security(("bearer_auth" = []))
+)]
+pub async fn export_tasks(
+    State(state): State<AppState>,
+    Extension(user_id): Extension<Uuid>,
+    Query(query): Query<ExportQuery>,
+) -> Result<impl IntoResponse> {
+    let format = query.format.unwrap_or_default();
+    let body = Body::from_stream(state.transfer_service.export(user_id, format));
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, HeaderValue::from_static(format.content_type())),
+            (header::CONTENT_DISPOSITION, HeaderValue::from_static(format.content_disposition())),
+        ],
+        body,
+    ))
+}
+
+/// Import tasks from a CSV or JSON file
+///
+/// The body is the file itself: a JSON array of task objects (a JSON export
+/// works as is) or CSV with a header row. Rows are checked like
+/// `POST /api/tasks`; the recognized fields are title, description, status,
+/// priority, due_date, reminder_time and estimate_minutes. Common status and
+/// priority names from other tools ("done", "to do", "critical") are mapped
+/// onto ours. Valid rows are imported even when others fail; every row gets
+/// a result in the report.
+#[utoipa::path(
+    post,
+    path = "/api/tasks/import",
+    params(
+        ("format" = Option<TransferFormat>, Query, description = "`json` (default) or `csv`"),
+        ("dry_run" = Option<bool>, Query, description = "Check every row without creating anything"),
+        ("project_id" = Option<Uuid>, Query, description = "Project to add the tasks to")
+    ),
+    request_body(content = String, description = "The file to import", content_type = "text/csv"),
+    responses(
+        (status = 200, description = "Per-row report", body = ImportReport),
+        (status = 400, description = "The file can't be read, or has too many rows"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Project not found")
+    ),
+    tag = "tasks",
+    security(("bearer_auth" = []))
+)]
+pub async fn import_tasks(
+    State(state): State<AppState>,
+    Extension(user_id): Extension<Uuid>,
+    Query(query): Query<ImportQuery>,
+    body: String,
+) -> Result<impl IntoResponse> {
+    let report = state.transfer_service
+        .import(user_id, query.format.unwrap_or_default(), &body, query.dry_run, query.project_id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(report)))
+}
+
+async fn start_import(
+    state: AppState,
+    user_id: Uuid,
+    source: ImportSource,
+    query: ImportJobQuery,
+    body: String,
+) -> Result<impl IntoResponse> {
+    let job = state.transfer_service
+        .start_import(user_id, source, &body, query.project_id)
+        .await?;
+
+    Ok((StatusCode::ACCEPTED, Json(job)))
+}
+
+/// Import a Trello board
+///
+/// The body is the board's JSON export. Lists become statuses by name ("Doing"
+/// → InProgress, "Done" → Completed, anything unrecognized → Pending), cards
+/// become tasks with their checklists, labels, comments and due dates, and card
+/// members with a TaskPadi account of the same username become editors and
+/// assignees. The import runs in the background; poll the returned job.
+#[utoipa::path(
+    post,
+    path = "/api/tasks/import/trello",
+    params(
+        ("project_id" = Option<Uuid>, Query, description = "Project to add the tasks to")
+    ),
+    request_body(content = String, description = "Trello board JSON export", content_type = "application/json"),
+    responses(
+        (status = 202, description = "Import job started", body = ImportJob),
+        (status = 400, description = "Not a Trello board export, or it has no cards"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Project not found")
+    ),
+    tag = "tasks",
+    security(("bearer_auth" = []))
+)]
+pub async fn import_trello(
+    State(state): State<AppState>,
+    Extension(user_id): Extension<Uuid>,
+    Query(query): Query<ImportJobQuery>,
+    body: String,
+) -> Result<impl IntoResponse> {
+    start_import(state, user_id, ImportSource::Trello, query, body).await
+}
+
+/// Import a Todoist export
+///
+/// The body is a Todoist export in the Sync API's JSON shape. Sections become
+/// statuses by name, items become tasks (priority 4 is Urgent), subtasks become
+/// checklist items, and comments, labels and due dates come along. A
+/// responsible collaborator with a TaskPadi account of the same email becomes
+/// an editor and assignee. The import runs in the background; poll the
+/// returned job.
+#[utoipa::path(
+    post,
+    path = "/api/tasks/import/todoist",
+    params(
+        ("project_id" = Option<Uuid>, Query, description = "Project to add the tasks to")
+    ),
+    request_body(content = String, description = "Todoist JSON export", content_type = "application/json"),
+    responses(
+        (status = 202, description = "Import job started", body = ImportJob),
+        (status = 400, description = "Not a Todoist export, or it has no items"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Project not found")
+    ),
+    tag = "tasks",
+    security(("bearer_auth" = []))
+)]
+pub async fn import_todoist(
+    State(state): State<AppState>,
+    Extension(user_id): Extension<Uuid>,
+    Query(query): Query<ImportJobQuery>,
+    body: String,
+) -> Result<impl IntoResponse> {
+    start_import(state, user_id, ImportSource::Todoist, query, body).await
+}
+
+/// List your recent import jobs
+#[utoipa::path(
+    get,
+    path = "/api/tasks/import/jobs",
+    responses(
+        (status = 200, description = "Your 50 most recent import jobs, newest first", body = [ImportJob]),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "tasks",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_import_jobs(
+    State(state): State<AppState>,
+    Extension(user_id): Extension<Uuid>,
+) -> Result<impl IntoResponse> {
+    let jobs = state.transfer_service.list_jobs(user_id).await?;
+
+    Ok((StatusCode::OK, Json(jobs)))
+}
+
+/// Get an import job's progress and summary
+#[utoipa::path(
+    get,
+    path = "/api/tasks/import/jobs/{job_id}",
+    params(
+        ("job_id" = Uuid, Path, description = "Import job ID")
+    ),
+    responses(
+        (status = 200, description = "The job; `summary` is set once it has finished", body = ImportJob),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Import job not found")
+    ),
+    tag = "tasks",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_import_job(
+    State(state): State<AppState>,
+    Extension(user_id): Extension<Uuid>,
+    Path(job_id): Path<Uuid>,
+) -> Result<impl IntoResponse> {
+    let job = state.transfer_service.get_job(user_id, job_id).await?;
+
+    Ok((StatusCode::OK, Json(job)))
+}
@@ -0,0 +1,557 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::task::task_models::{Task, TaskPriority, TaskStatus};
+
+/// Columns of a CSV export, in order. Imports read the ones they know by name.
+pub const CSV_COLUMNS: [&str; 25] = [
+    "id",
+    "title",
+    "description",
+    "status",
+    "priority",
+    "due_date",
+    "reminder_time",
+    "estimate_minutes",
+    "user_id",
+    "project_id",
+    "column_id",
+    "board_rank",
+    "parent_task_id",
+    "position",
+    "recurrence_rule",
+    "recurrence_series_id",
+    "recurrence_index",
+    "notified",
+    "deleted_at",
+    "row_version",
+    "created_at",
+    "updated_at",
+    "labels",
+    "members",
+    "activity",
+];
+
+/// Columns an import takes values from; everything else is ignored.
+const IMPORT_COLUMNS: [&str; 7] = [
+    "title",
+    "description",
+    "status",
+    "priority",
+    "due_date",
+    "reminder_time",
+    "estimate_minutes",
+];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TransferFormat {
+    #[default]
+    Json,
+    Csv,
+}
+
+impl TransferFormat {
+    pub fn content_type(self) -> &'static str {
+        match self {
+            TransferFormat::Json => "application/json",
+            TransferFormat::Csv => "text/csv; charset=utf-8",
+        }
+    }
+
+    pub fn content_disposition(self) -> &'static str {
+        match self {
+            TransferFormat::Json => "attachment; filename=\"tasks.json\"",
+            TransferFormat::Csv => "attachment; filename=\"tasks.csv\"",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, FromRow, ToSchema)]
+pub struct ExportedMember {
+    #[serde(skip)]
+    pub task_id: Uuid,
+    pub user_id: Uuid,
+    pub username: String,
+    pub role: String,
+    pub added_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, FromRow, ToSchema)]
+pub struct ExportedActivity {
+    #[serde(skip)]
+    pub task_id: Uuid,
+    pub user_id: Option<Uuid>,
+    pub username: Option<String>,
+    pub action: String,
+    pub details: Option<serde_json::Value>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// One task as it appears in an export: every task field plus its label
+/// names, members and activity history.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ExportedTask {
+    #[serde(flatten)]
+    pub task: Task,
+    pub labels: Vec<String>,
+    pub members: Vec<ExportedMember>,
+    pub activity: Vec<ExportedActivity>,
+}
+
+impl ExportedTask {
+    /// The task as a CSV record, without the line ending. Nested values
+    /// (recurrence rule, members, activity) are written as JSON.
+    pub fn to_csv_record(&self) -> String {
+        let task = &self.task;
+        let time = |t: Option<DateTime<Utc>>| t.map(|t| t.to_rfc3339()).unwrap_or_default();
+        let opt = |v: Option<String>| v.unwrap_or_default();
+        let json = |v: serde_json::Result<String>| v.unwrap_or_default();
+
+        let fields = [
+            task.id.to_string(),
+            task.title.clone(),
+            opt(task.description.clone()),
+            task.status.to_string(),
+            task.priority.to_string(),
+            time(task.due_date),
+            time(task.reminder_time),
+            opt(task.estimate_minutes.map(|m| m.to_string())),
+            task.user_id.to_string(),
+            opt(task.project_id.map(|id| id.to_string())),
+            opt(task.column_id.map(|id| id.to_string())),
+            task.board_rank.to_string(),
+            opt(task.parent_task_id.map(|id| id.to_string())),
+            task.position.to_string(),
+            opt(task.recurrence_rule.as_ref().map(|rule| json(serde_json::to_string(&rule.0)))),
+            opt(task.recurrence_series_id.map(|id| id.to_string())),
+            opt(task.recurrence_index.map(|i| i.to_string())),
+            task.notified.to_string(),
+            time(task.deleted_at),
+            task.row_version.to_string(),
+            task.created_at.to_rfc3339(),
+            task.updated_at.to_rfc3339(),
+            json(serde_json::to_string(&self.labels)),
+            json(serde_json::to_string(&self.members)),
+            json(serde_json::to_string(&self.activity)),
+        ];
+
+        csv_record(&fields)
+    }
+}
+
+pub fn csv_header() -> String {
+    csv_record(&CSV_COLUMNS)
+}
+
+fn csv_record<S: AsRef<str>>(fields: &[S]) -> String {
+    fields.iter().map(|f| csv_field(f.as_ref())).collect::<Vec<_>>().join(",")
+}
+
+/// Quotes a field when it holds a delimiter, quote or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Splits a CSV document into records, following RFC 4180: quoted fields may
+/// hold commas, line breaks and doubled quotes. Blank lines are skipped.
+pub fn parse_csv(input: &str) -> std::result::Result<Vec<Vec<String>>, String> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = input.strip_prefix('\u{feff}').unwrap_or(input).chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                c => field.push(c),
+            }
+            continue;
+        }
+
+        match c {
+            '"' if field.is_empty() => in_quotes = true,
+            ',' => record.push(std::mem::take(&mut field)),
+            '\r' | '\n' => {
+                if c == '\r' && chars.peek() == Some(&'\n') {
+                    chars.next();
+                }
+                record.push(std::mem::take(&mut field));
+                if record.iter().any(|f| !f.is_empty()) {
+                    records.push(std::mem::take(&mut record));
+                }
+                record.clear();
+            }
+            c => field.push(c),
+        }
+    }
+
+    if in_quotes {
+        return Err("CSV ends inside a quoted field".to_string());
+    }
+    record.push(field);
+    if record.iter().any(|f| !f.is_empty()) {
+        records.push(record);
+    }
+
+    Ok(records)
+}
+
+/// One row of an import, before validation. JSON imports accept the objects
+/// of a JSON export as is; other fields are ignored.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ImportRow {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub status: Option<String>,
+    pub priority: Option<String>,
+    pub due_date: Option<DateTime<Utc>>,
+    pub reminder_time: Option<DateTime<Utc>>,
+    pub estimate_minutes: Option<i32>,
+}
+
+impl ImportRow {
+    /// Reads a CSV record against the header row. Empty cells count as missing.
+    pub fn from_csv(header: &[String], record: &[String]) -> std::result::Result<Self, Vec<String>> {
+        let mut row = ImportRow::default();
+        let mut errors = Vec::new();
+
+        for (column, value) in header.iter().zip(record) {
+            let value = value.trim();
+            if value.is_empty() {
+                continue;
+            }
+            match column.as_str() {
+                "title" => row.title = Some(value.to_string()),
+                "description" => row.description = Some(value.to_string()),
+                "status" => row.status = Some(value.to_string()),
+                "priority" => row.priority = Some(value.to_string()),
+                "due_date" => match parse_import_time(value) {
+                    Some(time) => row.due_date = Some(time),
+                    None => errors.push(format!("due_date: \"{}\" is not a date", value)),
+                },
+                "reminder_time" => match parse_import_time(value) {
+                    Some(time) => row.reminder_time = Some(time),
+                    None => errors.push(format!("reminder_time: \"{}\" is not a date", value)),
+                },
+                "estimate_minutes" => match value.parse() {
+                    Ok(minutes) => row.estimate_minutes = Some(minutes),
+                    Err(_) => errors.push(format!("estimate_minutes: \"{}\" is not a whole number", value)),
+                },
+                _ => {}
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(row)
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// Normalizes a CSV header row; fails when it has no title column.
+pub fn import_header(header: &[String]) -> std::result::Result<Vec<String>, String> {
+    let header: Vec<String> = header.iter().map(|c| c.trim().to_lowercase()).collect();
+    if !header.iter().any(|c| c == "title") {
+        return Err(format!(
+            "The CSV header has no title column; recognized columns are {}",
+            IMPORT_COLUMNS.join(", ")
+        ));
+    }
+    Ok(header)
+}
+
+/// RFC 3339 timestamps, or times and plain `YYYY-MM-DD` dates without an
+/// offset, taken as UTC (dates at midnight).
+pub fn parse_import_time(value: &str) -> Option<DateTime<Utc>> {
+    if let Ok(time) = DateTime::parse_from_rfc3339(value) {
+        return Some(time.with_timezone(&Utc));
+    }
+    if let Ok(time) = NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S") {
+        return Some(time.and_utc());
+    }
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|time| time.and_utc())
+}
+
+/// Lowercase with spaces, dashes and underscores removed, so "In Progress",
+/// "in_progress" and "InProgress" all read the same.
+fn normalize(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '_'))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Maps a status from another tool onto ours.
+pub fn parse_import_status(value: &str) -> std::result::Result<TaskStatus, String> {
+    match normalize(value).as_str() {
+        "pending" | "todo" | "open" | "new" | "notstarted" | "backlog" => Ok(TaskStatus::Pending),
+        "inprogress" | "doing" | "started" | "active" | "inreview" => Ok(TaskStatus::InProgress),
+        "completed" | "complete" | "done" | "closed" | "finished" | "resolved" => Ok(TaskStatus::Completed),
+        "archived" => Ok(TaskStatus::Archived),
+        _ => Err(format!(
+            "status: unknown value \"{}\"; expected one of {}, {}, {}, {}",
+            value,
+            TaskStatus::Pending,
+            TaskStatus::InProgress,
+            TaskStatus::Completed,
+            TaskStatus::Archived
+        )),
+    }
+}
+
+/// Maps a priority from another tool onto ours.
+pub fn parse_import_priority(value: &str) -> std::result::Result<TaskPriority, String> {
+    match normalize(value).as_str() {
+        "low" | "lowest" | "minor" => Ok(TaskPriority::Low),
+        "medium" | "normal" | "default" => Ok(TaskPriority::Medium),
+        "high" | "major" | "important" => Ok(TaskPriority::High),
+        "urgent" | "highest" | "critical" | "blocker" => Ok(TaskPriority::Urgent),
+        _ => Err(format!(
+            "priority: unknown value \"{}\"; expected one of {}, {}, {}, {}",
+            value,
+            TaskPriority::Low,
+            TaskPriority::Medium,
+            TaskPriority::High,
+            TaskPriority::Urgent
+        )),
+    }
+}
+
+/// What happened to one row of an import. Rows are numbered from 1, not
+/// counting a CSV header.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ImportRowResult {
+    pub row: usize,
+    pub title: Option<String>,
+    /// The created task; absent on a dry run or when the row failed
+    pub task_id: Option<Uuid>,
+    /// Empty when the row is (or would be) imported
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ImportReport {
+    pub dry_run: bool,
+    pub total_rows: usize,
+    /// Rows imported, or on a dry run the rows that would be
+    pub imported: usize,
+    pub failed: usize,
+    pub rows: Vec<ImportRowResult>,
+}
+
+impl ImportReport {
+    pub fn new(dry_run: bool, rows: Vec<ImportRowResult>) -> Self {
+        let failed = rows.iter().filter(|r| !r.errors.is_empty()).count();
+        Self {
+            dry_run,
+            total_rows: rows.len(),
+            imported: rows.len() - failed,
+            failed,
+            rows,
+        }
+    }
+}
+
+/// Tool whose export file an import job reads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "text", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum ImportSource {
+    Trello,
+    Todoist,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "text", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum ImportJobStatus {
+    Queued,
+    Running,
+    Completed,
+    Failed,
+}
+
+/// A Trello or Todoist import running in the background. Poll it until
+/// `status` is `completed` or `failed`; `summary` is filled in at the end.
+#[derive(Debug, Clone, Serialize, FromRow, ToSchema)]
+pub struct ImportJob {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub source: ImportSource,
+    pub status: ImportJobStatus,
+    /// Tasks in the export file
+    pub total: i32,
+    /// Tasks handled so far, imported or skipped
+    pub processed: i32,
+    #[schema(value_type = Option<ImportSummary>)]
+    pub summary: Option<sqlx::types::Json<ImportSummary>>,
+    /// Why the job stopped early, when it failed
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+/// What an import job did. Also saved for failed jobs, covering the tasks
+/// imported before the failure.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(default)]
+pub struct ImportSummary {
+    pub tasks_created: usize,
+    /// Cards or items that couldn't be imported, with the reasons
+    pub skipped: Vec<SkippedImport>,
+    pub checklist_items: usize,
+    /// Labels that didn't exist yet among yours
+    pub labels_created: usize,
+    pub comments: usize,
+    /// Member and assignee links made to TaskPadi users
+    pub members_added: usize,
+    /// People in the export who weren't added to any task. Only users who
+    /// share a group or project with you are added.
+    pub members_not_added: Vec<String>,
+    /// Imported tasks whose people couldn't be added, with the reasons
+    pub member_failures: Vec<SkippedImport>,
+    /// Status each Trello list or Todoist section was mapped to
+    pub status_mapping: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct SkippedImport {
+    pub name: String,
+    pub errors: Vec<String>,
+}
+
+/// Someone named in an export. Matched to a TaskPadi user by username or
+/// email, whichever the export has.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedMember {
+    pub name: String,
+    pub username: Option<String>,
+    pub email: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedLabel {
+    pub name: String,
+    /// Hex color, when the tool's color is one we know
+    pub color: Option<String>,
+}
+
+/// One task to create, read from a Trello card or Todoist item.
+#[derive(Debug, Clone)]
+pub struct PlannedTask {
+    pub row: ImportRow,
+    pub labels: Vec<PlannedLabel>,
+    /// Item text and whether it is checked
+    pub checklist: Vec<(String, bool)>,
+    /// Comment bodies, already credited to their original authors
+    pub comments: Vec<String>,
+    pub members: Vec<PlannedMember>,
+}
+
+/// An export file turned into the tasks to create.
+#[derive(Debug, Clone, Default)]
+pub struct ImportPlan {
+    pub tasks: Vec<PlannedTask>,
+    pub status_mapping: BTreeMap<String, String>,
+}
+
+impl ImportPlan {
+    /// Status for tasks in a list or section, by its name; names we don't
+    /// recognize go to `Pending`. Every mapping used ends up in the summary.
+    pub fn map_status(&mut self, group_name: &str) -> TaskStatus {
+        let status = parse_import_status(group_name).unwrap_or(TaskStatus::Pending);
+        self.status_mapping.insert(group_name.to_string(), status.to_string());
+        status
+    }
+}
+
+/// Comment text crediting the author and time of a comment in another tool.
+pub fn imported_comment(author: Option<&str>, posted_at: Option<DateTime<Utc>>, text: &str) -> String {
+    let author = author.filter(|a| !a.is_empty()).unwrap_or("Someone");
+    match posted_at {
+        Some(time) => format!("{} wrote on {}:\n\n{}", author, time.format("%Y-%m-%d %H:%M UTC"), text),
+        None => format!("{} wrote:\n\n{}", author, text),
+    }
+}
+
+/// Checklist item text trimmed to what a checklist item can hold; `None`
+/// for blank items.
+pub fn checklist_text(text: &str) -> Option<String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return None;
+    }
+    Some(text.chars().take(crate::checklist::checklist_models::MAX_ITEM_CHARS).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let fields = ["plain", "with, comma", "say \"hi\"", "two\nlines", ""];
+        let line = csv_record(&fields);
+        assert_eq!(line, "plain,\"with, comma\",\"say \"\"hi\"\"\",\"two\nlines\",");
+
+        let records = parse_csv(&format!("{}\r\n\r\n{}", csv_record(&["a", "b", "c", "d", "e"]), line)).unwrap();
+        assert_eq!(records, vec![strings(&["a", "b", "c", "d", "e"]), strings(&fields)]);
+
+        assert!(parse_csv("title\n\"unterminated").is_err());
+    }
+
+    #[test]
+    fn test_import_row_from_csv() {
+        let header = import_header(&strings(&["ID", " Title ", "due_date", "estimate_minutes", "members"])).unwrap();
+
+        let row = ImportRow::from_csv(&header, &strings(&["x", "Write docs", "2026-05-01", "30", "[]"])).unwrap();
+        assert_eq!(row.title.as_deref(), Some("Write docs"));
+        assert_eq!(row.due_date, Some("2026-05-01T00:00:00Z".parse().unwrap()));
+        assert_eq!(parse_import_time("2026-05-01T09:30:00"), Some("2026-05-01T09:30:00Z".parse().unwrap()));
+        assert_eq!(row.estimate_minutes, Some(30));
+
+        let errors = ImportRow::from_csv(&header, &strings(&["", "Bad", "soon", "1.5"])).unwrap_err();
+        assert_eq!(errors.len(), 2);
+
+        assert!(import_header(&strings(&["name", "status"])).is_err());
+    }
+
+    #[test]
+    fn test_status_and_priority_mapping() {
+        assert!(matches!(parse_import_status("In Progress"), Ok(TaskStatus::InProgress)));
+        assert!(matches!(parse_import_status("done"), Ok(TaskStatus::Completed)));
+        assert!(matches!(parse_import_status("to-do"), Ok(TaskStatus::Pending)));
+        assert!(matches!(parse_import_priority("CRITICAL"), Ok(TaskPriority::Urgent)));
+        assert!(matches!(parse_import_priority("normal"), Ok(TaskPriority::Medium)));
+
+        let err = parse_import_status("someday").unwrap_err();
+        assert!(err.contains("\"someday\"") && err.contains("InProgress"));
+        assert!(parse_import_priority("p1").unwrap_err().contains("Urgent"));
+    }
+}
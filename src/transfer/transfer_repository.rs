@@ -0,0 +1,202 @@
+use std::collections::HashMap;
+
+use crate::error::Result;
+use crate::pagination::{keyset_sql, Cursor, CursorPosition};
+use crate::task::task_models::Task;
+use crate::task::task_repository::ACCESSIBLE_TASKS;
+use sqlx::PgPool;
+use uuid::Uuid;
+use super::transfer_models::{ExportedActivity, ExportedMember, ImportJob, ImportJobStatus, ImportSource, ImportSummary};
+
+#[derive(Clone)]
+pub struct TransferRepository {
+    pool: PgPool,
+}
+
+impl TransferRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// The next `limit` tasks the user has access to after `after`, oldest
+    /// first. Trashed tasks are left out.
+    pub async fn find_export_page(&self, user_id: Uuid, after: Option<Cursor>, limit: i64) -> Result<Vec<Task>> {
+        let position = after.map(CursorPosition::After);
+        let (keyset, order) = keyset_sql("t", position.as_ref(), false, 3);
+
+        let query = format!("SELECT DISTINCT t.* {}{}{} LIMIT $2", ACCESSIBLE_TASKS, keyset, order);
+
+        let mut db_query = sqlx::query_as::<_, Task>(&query).bind(user_id).bind(limit);
+        if let Some(cursor) = after {
+            db_query = db_query.bind(cursor.created_at).bind(cursor.id);
+        }
+
+        Ok(db_query.fetch_all(&self.pool).await?)
+    }
+
+    pub async fn find_members(&self, task_ids: &[Uuid]) -> Result<HashMap<Uuid, Vec<ExportedMember>>> {
+        let members = sqlx::query_as::<_, ExportedMember>(
+            "SELECT tm.task_id, tm.user_id, u.username, tm.role, tm.added_at
+             FROM task_members tm
+             JOIN users u ON u.id = tm.user_id
+             WHERE tm.task_id = ANY($1)
+             ORDER BY tm.added_at ASC"
+        )
+        .bind(task_ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut by_task: HashMap<Uuid, Vec<ExportedMember>> = HashMap::new();
+        for member in members {
+            by_task.entry(member.task_id).or_default().push(member);
+        }
+        Ok(by_task)
+    }
+
+    pub async fn find_activity(&self, task_ids: &[Uuid]) -> Result<HashMap<Uuid, Vec<ExportedActivity>>> {
+        let activity = sqlx::query_as::<_, ExportedActivity>(
+            "SELECT ta.task_id, ta.user_id, u.username, ta.action, ta.details, ta.created_at
+             FROM task_activity ta
+             LEFT JOIN users u ON u.id = ta.user_id
+             WHERE ta.task_id = ANY($1)
+             ORDER BY ta.created_at ASC"
+        )
+        .bind(task_ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut by_task: HashMap<Uuid, Vec<ExportedActivity>> = HashMap::new();
+        for entry in activity {
+            by_task.entry(entry.task_id).or_default().push(entry);
+        }
+        Ok(by_task)
+    }
+
+    // Import jobs
+
+    pub async fn create_job(&self, user_id: Uuid, source: ImportSource, total: i32) -> Result<ImportJob> {
+        let job = sqlx::query_as::<_, ImportJob>(
+            "INSERT INTO import_jobs (user_id, source, total) VALUES ($1, $2, $3) RETURNING *"
+        )
+        .bind(user_id)
+        .bind(source)
+        .bind(total)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(job)
+    }
+
+    pub async fn find_job(&self, id: Uuid, user_id: Uuid) -> Result<Option<ImportJob>> {
+        let job = sqlx::query_as::<_, ImportJob>("SELECT * FROM import_jobs WHERE id = $1 AND user_id = $2")
+            .bind(id)
+            .bind(user_id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(job)
+    }
+
+    pub async fn find_jobs(&self, user_id: Uuid, limit: i64) -> Result<Vec<ImportJob>> {
+        let jobs = sqlx::query_as::<_, ImportJob>(
+            "SELECT * FROM import_jobs WHERE user_id = $1 ORDER BY created_at DESC LIMIT $2"
+        )
+        .bind(user_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(jobs)
+    }
+
+    pub async fn start_job(&self, id: Uuid) -> Result<()> {
+        sqlx::query("UPDATE import_jobs SET status = 'running', started_at = NOW() WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn record_progress(&self, id: Uuid, processed: i32) -> Result<()> {
+        sqlx::query("UPDATE import_jobs SET processed = $2 WHERE id = $1")
+            .bind(id)
+            .bind(processed)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn finish_job(
+        &self,
+        id: Uuid,
+        status: ImportJobStatus,
+        processed: i32,
+        summary: &ImportSummary,
+        error: Option<&str>,
+    ) -> Result<()> {
+        sqlx::query(
+            "UPDATE import_jobs
+             SET status = $2, processed = $3, summary = $4, error = $5, finished_at = NOW()
+             WHERE id = $1"
+        )
+        .bind(id)
+        .bind(status)
+        .bind(processed)
+        .bind(sqlx::types::Json(summary))
+        .bind(error)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Fails jobs left queued or running by a previous server process; they
+    /// don't resume after a restart.
+    pub async fn fail_interrupted_jobs(&self) -> Result<u64> {
+        let result = sqlx::query(
+            "UPDATE import_jobs
+             SET status = 'failed', error = 'Interrupted by a server restart', finished_at = NOW()
+             WHERE status IN ('queued', 'running')"
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Active users with any of these usernames or emails, compared
+    /// case-insensitively, as `(id, username, email)`. Only the user and
+    /// people who share a group or project with them are returned.
+    pub async fn match_users(
+        &self,
+        user_id: Uuid,
+        usernames: &[String],
+        emails: &[String],
+    ) -> Result<Vec<(Uuid, String, String)>> {
+        let users = sqlx::query_as::<_, (Uuid, String, String)>(
+            "SELECT u.id, u.username, u.email FROM users u
+             WHERE u.is_active = true
+             AND (LOWER(u.username) = ANY($2) OR LOWER(u.email) = ANY($3))
+             AND (u.id = $1
+                  OR EXISTS (
+                      SELECT 1 FROM group_members mine
+                      JOIN group_members theirs ON theirs.group_id = mine.group_id
+                      WHERE mine.user_id = $1 AND theirs.user_id = u.id
+                  )
+                  OR EXISTS (
+                      SELECT 1 FROM project_members mine
+                      JOIN project_members theirs ON theirs.project_id = mine.project_id
+                      WHERE mine.user_id = $1 AND theirs.user_id = u.id
+                  ))"
+        )
+        .bind(user_id)
+        .bind(usernames)
+        .bind(emails)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(users)
+    }
+}
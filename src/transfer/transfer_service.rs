@@ -0,0 +1,586 @@
+use std::collections::HashMap;
+
+use crate::checklist::checklist_repository::ChecklistRepository;
+use crate::comment::comment_repository::CommentRepository;
+use crate::error::{AppError, Result};
+use crate::label::label_repository::LabelRepository;
+use crate::pagination::Cursor;
+use crate::project::project_repository::ProjectRepository;
+use crate::task::task_dto::{CreateTaskRequest, UpdateTaskStatusRequest};
+use crate::task::task_models::{Task, TaskRole, TaskStatus};
+use crate::task::task_repository::TaskRepository;
+use crate::task::task_service::TaskService;
+use axum::body::Bytes;
+use futures::stream::{self, Stream};
+use uuid::Uuid;
+use validator::Validate;
+use super::transfer_models::{
+    csv_header, import_header, parse_csv, parse_import_priority, parse_import_status, ExportedTask, ImportJob,
+    ImportJobStatus, ImportPlan, ImportReport, ImportRow, ImportRowResult, ImportSource, ImportSummary,
+    PlannedMember, PlannedTask, SkippedImport, TransferFormat,
+};
+use super::transfer_repository::TransferRepository;
+use super::{todoist, trello};
+
+/// Tasks read from the database per chunk of an export
+const EXPORT_PAGE_SIZE: i64 = 200;
+
+/// Most rows a single import can hold
+pub const MAX_IMPORT_ROWS: usize = 1000;
+
+/// Largest import file accepted; Trello exports carry the board's whole history
+pub const MAX_IMPORT_FILE_BYTES: usize = 20 * 1024 * 1024;
+
+/// Import jobs listed per user, newest first
+const MAX_LISTED_JOBS: i64 = 50;
+
+/// Tasks handled between progress updates of an import job
+const PROGRESS_INTERVAL: usize = 20;
+
+/// Longest label name, as `CreateLabelRequest` allows
+const MAX_LABEL_NAME_CHARS: usize = 100;
+
+/// Where a running export has got to.
+struct ExportCursor {
+    after: Option<Cursor>,
+    written: usize,
+    finished: bool,
+}
+
+/// What every task of an import job shares.
+struct ImportTarget {
+    job_id: Uuid,
+    user_id: Uuid,
+    source: ImportSource,
+    project_id: Option<Uuid>,
+}
+
+/// Running totals of an import job.
+#[derive(Default)]
+struct JobProgress {
+    processed: usize,
+    summary: ImportSummary,
+    /// Label IDs by lowercased name, including labels the job created
+    labels: HashMap<String, Uuid>,
+}
+
+/// TaskPadi users found for the people in an export.
+#[derive(Default)]
+struct MatchedUsers {
+    by_username: HashMap<String, Uuid>,
+    by_email: HashMap<String, Uuid>,
+}
+
+impl MatchedUsers {
+    fn find(&self, member: &PlannedMember) -> Option<Uuid> {
+        let by_username = member.username.as_ref().and_then(|u| self.by_username.get(&u.to_lowercase()));
+        let by_email = member.email.as_ref().and_then(|e| self.by_email.get(&e.to_lowercase()));
+        by_username.or(by_email).copied()
+    }
+}
+
+/// Service layer for moving tasks in and out of TaskPadi.
+#[derive(Clone)]
+pub struct TransferService {
+    repo: TransferRepository,
+    task_repo: TaskRepository,
+    task_service: TaskService,
+    label_repo: LabelRepository,
+    project_repo: ProjectRepository,
+    checklist_repo: ChecklistRepository,
+    comment_repo: CommentRepository,
+}
+
+impl TransferService {
+    pub fn new(
+        repo: TransferRepository,
+        task_repo: TaskRepository,
+        task_service: TaskService,
+        label_repo: LabelRepository,
+        project_repo: ProjectRepository,
+        checklist_repo: ChecklistRepository,
+        comment_repo: CommentRepository,
+    ) -> Self {
+        Self {
+            repo,
+            task_repo,
+            task_service,
+            label_repo,
+            project_repo,
+            checklist_repo,
+            comment_repo,
+        }
+    }
+
+    /// Every task the user has access to, oldest first, one page at a time so
+    /// large exports never sit in memory whole.
+    pub fn export(&self, user_id: Uuid, format: TransferFormat) -> impl Stream<Item = Result<Bytes>> {
+        let service = self.clone();
+        let start = ExportCursor {
+            after: None,
+            written: 0,
+            finished: false,
+        };
+
+        stream::try_unfold(start, move |mut state| {
+            let service = service.clone();
+            async move {
+                if state.finished {
+                    return Ok(None);
+                }
+
+                let first_chunk = state.after.is_none();
+                let tasks = service.repo.find_export_page(user_id, state.after, EXPORT_PAGE_SIZE).await?;
+                state.finished = (tasks.len() as i64) < EXPORT_PAGE_SIZE;
+                if let Some(last) = tasks.last() {
+                    state.after = Some(Cursor::new(last.created_at, last.id));
+                }
+
+                let mut chunk = String::new();
+                if first_chunk {
+                    match format {
+                        TransferFormat::Json => chunk.push('['),
+                        TransferFormat::Csv => {
+                            chunk.push_str(&csv_header());
+                            chunk.push_str("\r\n");
+                        }
+                    }
+                }
+
+                for task in service.to_exported(tasks).await? {
+                    match format {
+                        TransferFormat::Json => {
+                            if state.written > 0 {
+                                chunk.push(',');
+                            }
+                            chunk.push_str(&serde_json::to_string(&task).map_err(|_| AppError::InternalError)?);
+                        }
+                        TransferFormat::Csv => {
+                            chunk.push_str(&task.to_csv_record());
+                            chunk.push_str("\r\n");
+                        }
+                    }
+                    state.written += 1;
+                }
+
+                if state.finished && format == TransferFormat::Json {
+                    chunk.push(']');
+                }
+
+                Ok(Some((Bytes::from(chunk), state)))
+            }
+        })
+    }
+
+    async fn to_exported(&self, tasks: Vec<Task>) -> Result<Vec<ExportedTask>> {
+        let ids: Vec<Uuid> = tasks.iter().map(|t| t.id).collect();
+        let mut labels = self.label_repo.find_for_tasks(&ids).await?;
+        let mut members = self.repo.find_members(&ids).await?;
+        let mut activity = self.repo.find_activity(&ids).await?;
+
+        Ok(tasks
+            .into_iter()
+            .map(|task| ExportedTask {
+                labels: labels
+                    .remove(&task.id)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|label| label.name)
+                    .collect(),
+                members: members.remove(&task.id).unwrap_or_default(),
+                activity: activity.remove(&task.id).unwrap_or_default(),
+                task,
+            })
+            .collect())
+    }
+
+    /// Creates a task per valid row and reports on every row. A dry run
+    /// checks the rows the same way but creates nothing.
+    pub async fn import(
+        &self,
+        user_id: Uuid,
+        format: TransferFormat,
+        body: &str,
+        dry_run: bool,
+        project_id: Option<Uuid>,
+    ) -> Result<ImportReport> {
+        self.check_project(user_id, project_id).await?;
+
+        let rows = Self::read_rows(format, body)?;
+        if rows.len() > MAX_IMPORT_ROWS {
+            return Err(AppError::BadRequest(format!(
+                "The file has {} rows; at most {} can be imported at once",
+                rows.len(),
+                MAX_IMPORT_ROWS
+            )));
+        }
+
+        let mut results = Vec::with_capacity(rows.len());
+        for (index, row) in rows.into_iter().enumerate() {
+            let mut result = ImportRowResult {
+                row: index + 1,
+                title: None,
+                task_id: None,
+                errors: Vec::new(),
+            };
+
+            let checked = row.and_then(|row| {
+                result.title = row.title.clone();
+                Self::check_row(row, project_id)
+            });
+            match checked {
+                Err(errors) => result.errors = errors,
+                Ok(_) if dry_run => {}
+                Ok((request, status)) => match self.create(user_id, request, status, serde_json::json!({"format": format})).await {
+                    Ok(task_id) => result.task_id = Some(task_id),
+                    Err(e) => result.errors.push(e.to_string()),
+                },
+            }
+            results.push(result);
+        }
+
+        Ok(ImportReport::new(dry_run, results))
+    }
+
+    /// Splits the body into rows. Problems with a single row are kept for
+    /// the report; only an unreadable file fails the whole import.
+    fn read_rows(format: TransferFormat, body: &str) -> Result<Vec<std::result::Result<ImportRow, Vec<String>>>> {
+        match format {
+            TransferFormat::Json => {
+                let values: Vec<serde_json::Value> = serde_json::from_str(body)
+                    .map_err(|e| AppError::BadRequest(format!("Expected a JSON array of tasks: {}", e)))?;
+
+                Ok(values
+                    .into_iter()
+                    .map(|value| serde_json::from_value(value).map_err(|e| vec![e.to_string()]))
+                    .collect())
+            }
+            TransferFormat::Csv => {
+                let mut records = parse_csv(body).map_err(AppError::BadRequest)?.into_iter();
+                let header = match records.next() {
+                    Some(header) => import_header(&header).map_err(AppError::BadRequest)?,
+                    None => return Ok(Vec::new()),
+                };
+
+                Ok(records.map(|record| ImportRow::from_csv(&header, &record)).collect())
+            }
+        }
+    }
+
+    /// Builds the create request for a row and checks it like `POST /api/tasks` does.
+    fn check_row(
+        row: ImportRow,
+        project_id: Option<Uuid>,
+    ) -> std::result::Result<(CreateTaskRequest, TaskStatus), Vec<String>> {
+        let mut errors = Vec::new();
+
+        let status = match row.status.as_deref().map(parse_import_status).transpose() {
+            Ok(status) => status.unwrap_or(TaskStatus::Pending),
+            Err(e) => {
+                errors.push(e);
+                TaskStatus::Pending
+            }
+        };
+        let priority = match row.priority.as_deref().map(parse_import_priority).transpose() {
+            Ok(priority) => priority,
+            Err(e) => {
+                errors.push(e);
+                None
+            }
+        };
+
+        let title = row.title.map(|t| t.trim().to_string()).unwrap_or_default();
+        if title.is_empty() {
+            errors.push("title: is required".to_string());
+        }
+
+        let request = CreateTaskRequest {
+            title,
+            description: row.description,
+            priority,
+            due_date: row.due_date,
+            reminder_time: row.reminder_time,
+            estimate_minutes: row.estimate_minutes,
+            recurrence_rule: None,
+            project_id,
+        };
+        if let Err(e) = request.validate() {
+            let mut failed: Vec<String> = e
+                .field_errors()
+                .into_iter()
+                // A missing title is already reported above
+                .filter(|(field, _)| !(*field == "title" && request.title.is_empty()))
+                .flat_map(|(field, field_errors)| {
+                    field_errors.iter().map(move |error| format!("{}: fails the {} check", field, error.code))
+                })
+                .collect();
+            failed.sort();
+            errors.extend(failed);
+        }
+
+        if errors.is_empty() {
+            Ok((request, status))
+        } else {
+            Err(errors)
+        }
+    }
+
+    async fn create(
+        &self,
+        user_id: Uuid,
+        request: CreateTaskRequest,
+        status: TaskStatus,
+        details: serde_json::Value,
+    ) -> Result<Uuid> {
+        let task = self.task_service.create_task(user_id, request).await?;
+
+        if status != TaskStatus::Pending {
+            self.task_service
+                .update_status(user_id, task.id, UpdateTaskStatusRequest { status }, None)
+                .await?;
+        }
+
+        let _ = self.task_repo.log_task_activity(
+            task.id,
+            user_id,
+            "imported",
+            Some(details),
+        ).await;
+
+        Ok(task.id)
+    }
+
+    async fn check_project(&self, user_id: Uuid, project_id: Option<Uuid>) -> Result<()> {
+        if let Some(project_id) = project_id {
+            if !self.project_repo.is_member(project_id, user_id).await? {
+                return Err(AppError::NotFound("Project not found".to_string()));
+            }
+        }
+        Ok(())
+    }
+
+    // Import jobs
+
+    /// Reads a Trello or Todoist export and imports it in the background.
+    /// The file is parsed up front, so a file that isn't an export fails the
+    /// request instead of the job.
+    pub async fn start_import(
+        &self,
+        user_id: Uuid,
+        source: ImportSource,
+        body: &str,
+        project_id: Option<Uuid>,
+    ) -> Result<ImportJob> {
+        self.check_project(user_id, project_id).await?;
+
+        let plan = match source {
+            ImportSource::Trello => trello::plan(
+                serde_json::from_str(body)
+                    .map_err(|e| AppError::BadRequest(format!("Not a Trello board export: {}", e)))?,
+            ),
+            ImportSource::Todoist => todoist::plan(
+                serde_json::from_str(body)
+                    .map_err(|e| AppError::BadRequest(format!("Not a Todoist export: {}", e)))?,
+            ),
+        };
+        if plan.tasks.is_empty() {
+            return Err(AppError::BadRequest("The export has no cards or items to import".to_string()));
+        }
+
+        let job = self.repo.create_job(user_id, source, plan.tasks.len() as i32).await?;
+
+        let service = self.clone();
+        let job_id = job.id;
+        tokio::spawn(async move {
+            service.run_job(job_id, user_id, source, plan, project_id).await;
+        });
+
+        Ok(job)
+    }
+
+    pub async fn get_job(&self, user_id: Uuid, job_id: Uuid) -> Result<ImportJob> {
+        self.repo
+            .find_job(job_id, user_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Import job not found".to_string()))
+    }
+
+    pub async fn list_jobs(&self, user_id: Uuid) -> Result<Vec<ImportJob>> {
+        self.repo.find_jobs(user_id, MAX_LISTED_JOBS).await
+    }
+
+    /// Marks jobs cut off by a restart as failed. Called once at startup.
+    pub async fn fail_interrupted_jobs(&self) -> Result<u64> {
+        self.repo.fail_interrupted_jobs().await
+    }
+
+    async fn run_job(
+        &self,
+        job_id: Uuid,
+        user_id: Uuid,
+        source: ImportSource,
+        plan: ImportPlan,
+        project_id: Option<Uuid>,
+    ) {
+        let target = ImportTarget {
+            job_id,
+            user_id,
+            source,
+            project_id,
+        };
+        let mut progress = JobProgress {
+            summary: ImportSummary {
+                status_mapping: plan.status_mapping.clone(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let (status, error) = match self.run_plan(&target, plan, &mut progress).await {
+            Ok(()) => (ImportJobStatus::Completed, None),
+            Err(e) => {
+                tracing::error!("Import job {} failed: {:?}", job_id, e);
+                (ImportJobStatus::Failed, Some(e.to_string()))
+            }
+        };
+        if let Err(e) = self.repo
+            .finish_job(job_id, status, progress.processed as i32, &progress.summary, error.as_deref())
+            .await
+        {
+            tracing::error!("Could not record the end of import job {}: {:?}", job_id, e);
+        }
+    }
+
+    async fn run_plan(&self, target: &ImportTarget, plan: ImportPlan, progress: &mut JobProgress) -> Result<()> {
+        self.repo.start_job(target.job_id).await?;
+
+        let users = self.match_users(target.user_id, &plan, &mut progress.summary).await?;
+        progress.labels = self.label_repo
+            .find_user_labels(target.user_id)
+            .await?
+            .into_iter()
+            .map(|label| (label.name.to_lowercase(), label.id))
+            .collect();
+
+        for planned in plan.tasks {
+            self.import_planned(target, planned, &users, progress).await?;
+            progress.processed += 1;
+            if progress.processed.is_multiple_of(PROGRESS_INTERVAL) {
+                self.repo.record_progress(target.job_id, progress.processed as i32).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Looks up everyone named in the plan among the people the importer
+    /// already works with. The rest are listed in the summary, whether or not
+    /// they have an account, so an export can't be used to probe for them.
+    async fn match_users(&self, user_id: Uuid, plan: &ImportPlan, summary: &mut ImportSummary) -> Result<MatchedUsers> {
+        let members: Vec<&PlannedMember> = plan.tasks.iter().flat_map(|t| &t.members).collect();
+        if members.is_empty() {
+            return Ok(MatchedUsers::default());
+        }
+
+        let usernames: Vec<String> = members.iter().filter_map(|m| m.username.as_ref()).map(|u| u.to_lowercase()).collect();
+        let emails: Vec<String> = members.iter().filter_map(|m| m.email.as_ref()).map(|e| e.to_lowercase()).collect();
+
+        let mut matched = MatchedUsers::default();
+        for (id, username, email) in self.repo.match_users(user_id, &usernames, &emails).await? {
+            matched.by_username.insert(username.to_lowercase(), id);
+            matched.by_email.insert(email.to_lowercase(), id);
+        }
+
+        let mut not_added: Vec<String> = members
+            .iter()
+            .filter(|m| matched.find(m).is_none())
+            .map(|m| m.name.clone())
+            .collect();
+        not_added.sort();
+        not_added.dedup();
+        summary.members_not_added = not_added;
+
+        Ok(matched)
+    }
+
+    /// Creates one task with its labels, checklist, comments and members.
+    /// Tasks that fail validation are skipped; only database errors stop the job.
+    async fn import_planned(
+        &self,
+        target: &ImportTarget,
+        planned: PlannedTask,
+        users: &MatchedUsers,
+        progress: &mut JobProgress,
+    ) -> Result<()> {
+        let user_id = target.user_id;
+        let name = planned.row.title.clone().unwrap_or_default();
+
+        let (request, status) = match Self::check_row(planned.row, target.project_id) {
+            Ok(checked) => checked,
+            Err(errors) => {
+                progress.summary.skipped.push(SkippedImport { name, errors });
+                return Ok(());
+            }
+        };
+        let details = serde_json::json!({"source": target.source});
+        let task_id = match self.create(user_id, request, status, details).await {
+            Ok(task_id) => task_id,
+            Err(AppError::Database(e)) => return Err(AppError::Database(e)),
+            Err(e) => {
+                progress.summary.skipped.push(SkippedImport { name, errors: vec![e.to_string()] });
+                return Ok(());
+            }
+        };
+        let summary = &mut progress.summary;
+        summary.tasks_created += 1;
+
+        for label in planned.labels {
+            let label_name: String = label.name.chars().take(MAX_LABEL_NAME_CHARS).collect();
+            let key = label_name.to_lowercase();
+            let label_id = match progress.labels.get(&key) {
+                Some(&label_id) => label_id,
+                None => {
+                    let created = self.label_repo.create(user_id, &label_name, label.color.as_deref()).await?;
+                    progress.labels.insert(key, created.id);
+                    summary.labels_created += 1;
+                    created.id
+                }
+            };
+            self.label_repo.add_to_task(task_id, label_id, user_id).await?;
+        }
+
+        if !planned.checklist.is_empty() {
+            self.checklist_repo.append(task_id, &planned.checklist, user_id).await?;
+            summary.checklist_items += planned.checklist.len();
+        }
+
+        for comment in &planned.comments {
+            self.comment_repo.create(task_id, user_id, None, comment).await?;
+        }
+        summary.comments += planned.comments.len();
+
+        let mut member_ids: Vec<Uuid> = planned.members.iter().filter_map(|m| users.find(m)).collect();
+        member_ids.sort_unstable();
+        member_ids.dedup();
+        if !member_ids.is_empty() {
+            // The task is imported either way; a failure only leaves it without its people
+            match self.add_members(task_id, user_id, &member_ids).await {
+                Ok(()) => summary.members_added += member_ids.len(),
+                Err(e) => summary.member_failures.push(SkippedImport { name, errors: vec![e.to_string()] }),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Shares an imported task with `member_ids` and assigns them to it.
+    async fn add_members(&self, task_id: Uuid, user_id: Uuid, member_ids: &[Uuid]) -> Result<()> {
+        // The importer already owns the task; everyone else joins as an editor
+        let others: Vec<Uuid> = member_ids.iter().copied().filter(|&id| id != user_id).collect();
+        if !others.is_empty() {
+            self.task_service.share_task(task_id, others, TaskRole::Editor, user_id).await?;
+        }
+        self.task_service.assign_users(task_id, member_ids.to_vec(), user_id).await?;
+        Ok(())
+    }
+}
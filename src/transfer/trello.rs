@@ -0,0 +1,312 @@
+//! Reads a Trello board export (Board menu → Print, export and share →
+//! Export as JSON) into an [`ImportPlan`].
+//!
+//! Lists become statuses by name, cards become tasks, and card checklists,
+//! labels, comments, due dates and members come along. Archived cards and
+//! cards in archived lists are imported as `Archived`.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::task::task_models::TaskStatus;
+use super::transfer_models::{
+    checklist_text, imported_comment, ImportPlan, ImportRow, PlannedLabel, PlannedMember, PlannedTask,
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrelloBoard {
+    #[serde(default)]
+    pub lists: Vec<TrelloList>,
+    #[serde(default)]
+    pub cards: Vec<TrelloCard>,
+    #[serde(default)]
+    pub labels: Vec<TrelloLabel>,
+    #[serde(default)]
+    pub checklists: Vec<TrelloChecklist>,
+    #[serde(default)]
+    pub actions: Vec<TrelloAction>,
+    #[serde(default)]
+    pub members: Vec<TrelloMember>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TrelloList {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub closed: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrelloCard {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub desc: String,
+    pub id_list: String,
+    pub due: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub due_complete: bool,
+    #[serde(default)]
+    pub closed: bool,
+    #[serde(default)]
+    pub pos: f64,
+    #[serde(default)]
+    pub id_labels: Vec<String>,
+    #[serde(default)]
+    pub id_members: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TrelloLabel {
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+    pub color: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrelloChecklist {
+    pub id_card: String,
+    #[serde(default)]
+    pub pos: f64,
+    #[serde(default)]
+    pub check_items: Vec<TrelloCheckItem>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TrelloCheckItem {
+    pub name: String,
+    /// `complete` or `incomplete`
+    pub state: String,
+    #[serde(default)]
+    pub pos: f64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrelloAction {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub date: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub data: TrelloActionData,
+    pub member_creator: Option<TrelloMember>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct TrelloActionData {
+    pub card: Option<TrelloRef>,
+    pub text: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TrelloRef {
+    pub id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrelloMember {
+    pub id: String,
+    #[serde(default)]
+    pub username: String,
+    #[serde(default)]
+    pub full_name: String,
+}
+
+/// Trello's label palette; `_dark` and `_light` shades map to the base color.
+fn label_color(color: &str) -> Option<String> {
+    let base = color.trim_end_matches("_dark").trim_end_matches("_light");
+    let hex = match base {
+        "green" => "#61bd4f",
+        "yellow" => "#f2d600",
+        "orange" => "#ff9f1a",
+        "red" => "#eb5a46",
+        "purple" => "#c377e0",
+        "blue" => "#0079bf",
+        "sky" => "#00c2e0",
+        "lime" => "#51e898",
+        "pink" => "#ff78cb",
+        "black" => "#344563",
+        _ => return None,
+    };
+    Some(hex.to_string())
+}
+
+pub fn plan(board: TrelloBoard) -> ImportPlan {
+    let mut plan = ImportPlan::default();
+
+    let lists: HashMap<&str, &TrelloList> = board.lists.iter().map(|l| (l.id.as_str(), l)).collect();
+    let labels: HashMap<&str, &TrelloLabel> = board.labels.iter().map(|l| (l.id.as_str(), l)).collect();
+    let members: HashMap<&str, &TrelloMember> = board.members.iter().map(|m| (m.id.as_str(), m)).collect();
+
+    let mut checklists: HashMap<&str, Vec<&TrelloChecklist>> = HashMap::new();
+    for checklist in &board.checklists {
+        checklists.entry(checklist.id_card.as_str()).or_default().push(checklist);
+    }
+
+    // Exports list actions newest first
+    let mut comments: HashMap<&str, Vec<&TrelloAction>> = HashMap::new();
+    for action in board.actions.iter().filter(|a| a.kind == "commentCard") {
+        if let Some(card) = &action.data.card {
+            comments.entry(card.id.as_str()).or_default().push(action);
+        }
+    }
+
+    // Cards in board order: by list position in the export, then card position
+    let list_order: HashMap<&str, usize> = board.lists.iter().enumerate().map(|(i, l)| (l.id.as_str(), i)).collect();
+    let mut cards: Vec<&TrelloCard> = board.cards.iter().collect();
+    cards.sort_by(|a, b| {
+        let list = |c: &TrelloCard| list_order.get(c.id_list.as_str()).copied().unwrap_or(usize::MAX);
+        list(a).cmp(&list(b)).then(a.pos.total_cmp(&b.pos))
+    });
+
+    for card in cards {
+        let list = lists.get(card.id_list.as_str());
+        let mut status = match list {
+            Some(list) => plan.map_status(&list.name),
+            None => TaskStatus::Pending,
+        };
+        if card.due_complete {
+            status = TaskStatus::Completed;
+        }
+        if card.closed || list.is_some_and(|l| l.closed) {
+            status = TaskStatus::Archived;
+        }
+
+        let mut card_checklists = checklists.remove(card.id.as_str()).unwrap_or_default();
+        card_checklists.sort_by(|a, b| a.pos.total_cmp(&b.pos));
+        let checklist = card_checklists
+            .into_iter()
+            .flat_map(|checklist| {
+                let mut items: Vec<&TrelloCheckItem> = checklist.check_items.iter().collect();
+                items.sort_by(|a, b| a.pos.total_cmp(&b.pos));
+                items
+            })
+            .filter_map(|item| Some((checklist_text(&item.name)?, item.state == "complete")))
+            .collect();
+
+        let card_comments = comments
+            .remove(card.id.as_str())
+            .unwrap_or_default()
+            .into_iter()
+            .rev()
+            .filter_map(|action| {
+                let text = action.data.text.as_deref().filter(|t| !t.trim().is_empty())?;
+                let author = action.member_creator.as_ref().map(|m| {
+                    if m.full_name.is_empty() { m.username.as_str() } else { m.full_name.as_str() }
+                });
+                Some(imported_comment(author, action.date, text))
+            })
+            .collect();
+
+        plan.tasks.push(PlannedTask {
+            row: ImportRow {
+                title: Some(card.name.clone()),
+                description: Some(card.desc.clone()).filter(|d| !d.trim().is_empty()),
+                status: Some(status.to_string()),
+                priority: None,
+                due_date: card.due,
+                reminder_time: None,
+                estimate_minutes: None,
+            },
+            labels: card
+                .id_labels
+                .iter()
+                .filter_map(|id| labels.get(id.as_str()))
+                .filter_map(|label| {
+                    let color = label.color.as_deref().unwrap_or_default();
+                    let name = if label.name.trim().is_empty() { color } else { label.name.trim() };
+                    (!name.is_empty()).then(|| PlannedLabel {
+                        name: name.to_string(),
+                        color: label_color(color),
+                    })
+                })
+                .collect(),
+            checklist,
+            comments: card_comments,
+            members: card
+                .id_members
+                .iter()
+                .filter_map(|id| members.get(id.as_str()))
+                .map(|member| PlannedMember {
+                    name: if member.full_name.is_empty() { member.username.clone() } else { member.full_name.clone() },
+                    username: Some(member.username.clone()).filter(|u| !u.is_empty()),
+                    email: None,
+                })
+                .collect(),
+        });
+    }
+
+    plan
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOARD: &str = r#"{
+        "name": "Launch",
+        "lists": [
+            {"id": "l1", "name": "To Do", "closed": false},
+            {"id": "l2", "name": "Done", "closed": false},
+            {"id": "l3", "name": "Ideas", "closed": true}
+        ],
+        "labels": [
+            {"id": "b1", "name": "Bug", "color": "red_dark"},
+            {"id": "b2", "name": "", "color": "green"}
+        ],
+        "members": [{"id": "m1", "username": "ada", "fullName": "Ada Lovelace"}],
+        "cards": [
+            {"id": "c2", "name": "Ship it", "desc": "", "idList": "l2", "pos": 1, "idLabels": [], "idMembers": []},
+            {"id": "c1", "name": "Fix login", "desc": "Crashes on submit", "idList": "l1", "pos": 2,
+             "due": "2026-04-01T12:00:00.000Z", "dueComplete": false, "closed": false,
+             "idLabels": ["b1", "b2"], "idMembers": ["m1"]},
+            {"id": "c3", "name": "Dark mode", "idList": "l3", "pos": 1}
+        ],
+        "checklists": [
+            {"id": "k1", "idCard": "c1", "pos": 1, "checkItems": [
+                {"name": "Repro", "state": "complete", "pos": 1},
+                {"name": "  ", "state": "incomplete", "pos": 2},
+                {"name": "Patch", "state": "incomplete", "pos": 3}
+            ]}
+        ],
+        "actions": [
+            {"type": "commentCard", "date": "2026-03-02T10:00:00.000Z",
+             "data": {"card": {"id": "c1"}, "text": "Second"}, "memberCreator": {"id": "m1", "username": "ada", "fullName": ""}},
+            {"type": "commentCard", "date": "2026-03-01T10:00:00.000Z",
+             "data": {"card": {"id": "c1"}, "text": "First"}, "memberCreator": {"id": "m1", "username": "ada", "fullName": "Ada Lovelace"}},
+            {"type": "updateCard", "data": {"card": {"id": "c1"}}}
+        ]
+    }"#;
+
+    #[test]
+    fn test_plan_maps_board() {
+        let plan = plan(serde_json::from_str(BOARD).unwrap());
+
+        let titles: Vec<_> = plan.tasks.iter().map(|t| t.row.title.clone().unwrap()).collect();
+        assert_eq!(titles, ["Fix login", "Ship it", "Dark mode"]);
+        assert_eq!(plan.status_mapping.get("To Do").map(String::as_str), Some("Pending"));
+        assert_eq!(plan.status_mapping.get("Done").map(String::as_str), Some("Completed"));
+
+        let card = &plan.tasks[0];
+        assert_eq!(card.row.status.as_deref(), Some("Pending"));
+        assert_eq!(card.row.due_date, Some("2026-04-01T12:00:00Z".parse().unwrap()));
+        assert_eq!(card.checklist, vec![("Repro".to_string(), true), ("Patch".to_string(), false)]);
+        assert_eq!(card.labels[0], PlannedLabel { name: "Bug".to_string(), color: Some("#eb5a46".to_string()) });
+        assert_eq!(card.labels[1].name, "green");
+        assert_eq!(card.members[0].username.as_deref(), Some("ada"));
+        assert_eq!(card.comments.len(), 2);
+        assert!(card.comments[0].starts_with("Ada Lovelace wrote on 2026-03-01") && card.comments[0].ends_with("First"));
+        assert!(card.comments[1].starts_with("ada wrote"));
+
+        assert_eq!(plan.tasks[1].row.status.as_deref(), Some("Completed"));
+        assert_eq!(plan.tasks[2].row.status.as_deref(), Some("Archived"));
+    }
+}
@@ -24,6 +24,11 @@ pub struct UserStatsResponse {
     pub medium_priority_tasks: i64,
     pub high_priority_tasks: i64,
     pub urgent_priority_tasks: i64,
+    /// Time tracked since the start of the day, ISO week and month (UTC), and overall
+    pub tracked_seconds_today: i64,
+    pub tracked_seconds_this_week: i64,
+    pub tracked_seconds_this_month: i64,
+    pub tracked_seconds_total: i64,
 }
 
 
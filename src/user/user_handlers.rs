@@ -9,7 +9,7 @@ use validator::Validate;
 
 use crate::{
     error::Result,
-    middleware::AuthUser,
+    middleware::{AppJson, AuthUser},
     state::AppState,
     task::task_dto::PaginatedResponse,
     user::user_dto::UpdateProfileRequest,
@@ -71,7 +71,7 @@ pub async fn get_current_user(
 pub async fn update_current_user(
     State(state): State<AppState>,
     AuthUser(user_id): AuthUser,
-    Json(payload): Json<UpdateProfileRequest>,
+    AppJson(payload): AppJson<UpdateProfileRequest>,
 ) -> Result<impl IntoResponse> {
     payload.validate()?;
 
@@ -129,7 +129,7 @@ pub async fn list_users(
     Query(params): Query<UserSearchParams>,
 ) -> Result<impl IntoResponse> {
     let page = params.page.unwrap_or(1).max(1);
-    let limit = params.limit.unwrap_or(20).min(100).max(1);
+    let limit = params.limit.unwrap_or(20).clamp(1, 100);
     let offset = ((page - 1) * limit) as i64;
 
     let (users, total) = state
@@ -145,6 +145,7 @@ pub async fn list_users(
         page,
         limit,
         total_pages,
+        next_cursor: None,
     };
 
     Ok((StatusCode::OK, Json(response)))
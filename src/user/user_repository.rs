@@ -220,7 +220,7 @@ impl UserRepository {
             bindings.push("avatar_url".to_string());
         }
 
-        query.push_str(&format!(" WHERE id = $1 RETURNING *"));
+        query.push_str(" WHERE id = $1 RETURNING *");
 
         let mut q = sqlx::query_as::<_, User>(&query).bind(user_id);
 
@@ -1,6 +1,7 @@
 use crate::{
     error::Result,
     task::task_repository::TaskRepository,
+    time_entry::{time_entry_models::period_starts, time_entry_repository::TimeEntryRepository},
     user::{
         user_dto::{UpdateProfileRequest, UserStatsResponse},
         user_models::UserResponse,
@@ -13,13 +14,19 @@ use uuid::Uuid;
 pub struct UserService {
     user_repository: UserRepository,
     task_repository: TaskRepository,
+    time_entry_repository: TimeEntryRepository,
 }
 
 impl UserService {
-    pub fn new(user_repository: UserRepository, task_repository: TaskRepository) -> Self {
+    pub fn new(
+        user_repository: UserRepository,
+        task_repository: TaskRepository,
+        time_entry_repository: TimeEntryRepository,
+    ) -> Self {
         Self {
             user_repository,
             task_repository,
+            time_entry_repository,
         }
     }
 
@@ -59,6 +66,16 @@ impl UserService {
             urgent_priority_tasks,
         ) = self.task_repository.get_user_stats(user_id).await?;
 
+        let (day_start, week_start, month_start) = period_starts(chrono::Utc::now());
+        let (
+            tracked_seconds_today,
+            tracked_seconds_this_week,
+            tracked_seconds_this_month,
+            tracked_seconds_total,
+        ) = self.time_entry_repository
+            .tracked_seconds(user_id, day_start, week_start, month_start)
+            .await?;
+
         let completion_rate = if total_tasks > 0 {
             (completed_tasks as f64 / total_tasks as f64) * 100.0
         } else {
@@ -76,6 +93,10 @@ impl UserService {
             medium_priority_tasks,
             high_priority_tasks,
             urgent_priority_tasks,
+            tracked_seconds_today,
+            tracked_seconds_this_week,
+            tracked_seconds_this_month,
+            tracked_seconds_total,
         })
     }
 
@@ -9,7 +9,7 @@ use validator::Validate;
 
 use crate::{
     error::{AppError, Result},
-    middleware::AuthUser,
+    middleware::{AppJson, AuthUser},
     state::AppState,
     video_call::{
         video_call_dto::{CallHistoryParams, InitiateCallRequest, AddParticipantRequest},
@@ -39,7 +39,7 @@ use crate::{
 pub async fn initiate_call(
     State(state): State<AppState>,
     AuthUser(user_id): AuthUser,
-    Json(payload): Json<InitiateCallRequest>,
+    AppJson(payload): AppJson<InitiateCallRequest>,
 ) -> Result<impl IntoResponse> {
     payload
         .validate()
@@ -215,7 +215,7 @@ pub async fn get_call_history(
     AuthUser(user_id): AuthUser,
     Query(params): Query<CallHistoryParams>,
 ) -> Result<impl IntoResponse> {
-    let limit = params.limit.min(100).max(1);
+    let limit = params.limit.clamp(1, 100);
     let offset = params.offset.max(0);
 
     let (calls, total) = state
@@ -280,7 +280,7 @@ pub async fn add_participant(
     State(state): State<AppState>,
     AuthUser(user_id): AuthUser,
     Path(call_id): Path<Uuid>,
-    Json(payload): Json<AddParticipantRequest>,
+    AppJson(payload): AppJson<AddParticipantRequest>,
 ) -> Result<impl IntoResponse> {
     payload
         .validate()
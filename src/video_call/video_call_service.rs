@@ -357,11 +357,9 @@ impl VideoCallService {
         }
 
         // Calculate duration if call was active
-        let duration_seconds = if let Some(started_at) = call.started_at {
-            Some((chrono::Utc::now() - started_at).num_seconds() as i32)
-        } else {
-            None
-        };
+        let duration_seconds = call
+            .started_at
+            .map(|started_at| (chrono::Utc::now() - started_at).num_seconds() as i32);
 
         // End the call in DB
         let call = self.repo.end_call(call_id, duration_seconds).await?;
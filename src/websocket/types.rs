@@ -2,6 +2,8 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::task::task_models::TaskEvent;
+
 /// Server-to-client WebSocket messages (signaling only).
 ///
 /// These messages handle call control signaling (ringing, accepted, ended, etc.)
@@ -21,9 +23,17 @@ pub enum WsMessage {
     UserStatus(UserStatusPayload),
 
     // ── Tasks ─────────────────────────────────────────────────────────────────
-    TaskUpdated(TaskUpdatedPayload),
+    /// Sent to everyone with access to a task whenever it changes.
+    TaskEvent(Box<TaskEvent>),
     TaskShared(TaskSharedPayload),
     TaskMemberRemoved(TaskMemberRemovedPayload),
+    /// Sent to a user when they are assigned to or unassigned from a task.
+    TaskAssigned(TaskAssignmentPayload),
+    TaskUnassigned(TaskAssignmentPayload),
+    /// Sent to a user when they are @mentioned in a task comment.
+    CommentMention(CommentMentionPayload),
+    /// Sent to everyone with access to a task when a checklist item is ticked or unticked.
+    ChecklistItemToggled(ChecklistItemToggledPayload),
 
     // ── Call signaling (control plane only — no media) ────────────────────────
     /// Sent to the receiver when a new call is initiated.
@@ -80,15 +90,6 @@ pub struct UserStatusPayload {
     pub is_online: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
-pub struct TaskUpdatedPayload {
-    pub task_id: Uuid,
-    pub updated_by: Uuid,
-    pub field: String,
-    pub old_value: Option<String>,
-    pub new_value: String,
-}
-
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct TaskSharedPayload {
     pub task_id: Uuid,
@@ -104,6 +105,37 @@ pub struct TaskMemberRemovedPayload {
     pub removed_by: Uuid,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TaskAssignmentPayload {
+    pub task_id: Uuid,
+    pub task_title: String,
+    pub changed_by: Uuid,
+    pub changed_by_username: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CommentMentionPayload {
+    pub task_id: Uuid,
+    pub task_title: String,
+    pub comment_id: Uuid,
+    pub mentioned_by: Uuid,
+    pub mentioned_by_username: String,
+    pub content_preview: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ChecklistItemToggledPayload {
+    pub task_id: Uuid,
+    pub item_id: Uuid,
+    pub is_checked: bool,
+    pub toggled_by: Uuid,
+    pub toggled_by_username: String,
+    /// Checked items on the task afterwards
+    pub checked: i64,
+    /// Items on the task
+    pub total: i64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct MessageDeliveredPayload {
     pub message_id: Uuid,